@@ -0,0 +1,468 @@
+//! Generic categorical color naming systems.
+//!
+//! A category system partitions Munsell space into named regions. Regions are
+//! either value/chroma polygons spanning a hue range (the ISCC-NBS model) or
+//! convex polyhedra in Munsell Cartesian space (the Centore overlay model).
+//!
+//! The [`CategorySystem`] trait abstracts over both so that any scheme can be
+//! used with [`ColorClassifier`](crate::ColorClassifier):
+//!
+//! - [`IsccNbsClassifier`] implements it for the 267 ISCC-NBS categories
+//! - [`SemanticOverlayRegistry`] implements it for the Centore overlays
+//! - [`CustomCategorySystem`] holds user-defined categories, such as an
+//!   in-house naming scheme for product catalogues
+//!
+//! # Example
+//!
+//! ```rust
+//! use munsellspace::category_system::{Category, CategoryRegion, CategorySystem, CustomCategorySystem};
+//! use munsellspace::{ColorClassifier, ColorModifier};
+//! use geo::polygon;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut catalogue = CustomCategorySystem::new("catalogue");
+//! catalogue.add_category(Category::new(
+//!     1,
+//!     "signal red",
+//!     ColorModifier::None,
+//!     vec![CategoryRegion::polygon(
+//!         "1R",
+//!         "10R",
+//!         polygon![(x: 8.0, y: 3.0), (x: 30.0, y: 3.0), (x: 30.0, y: 6.0), (x: 8.0, y: 6.0)],
+//!     )],
+//! ));
+//!
+//! let classifier = ColorClassifier::new()?;
+//! let matches = classifier.categorize_munsell_notation("5R 4/14", &catalogue)?;
+//! assert_eq!(matches[0].name, "signal red");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::color_names::ColorModifier;
+use crate::constants::{get_achromatic_color_number, is_achromatic_hue};
+use crate::error::Result;
+use crate::iscc::IsccNbsClassifier;
+use crate::semantic_overlay::{parse_hue_to_number, ConvexPolyhedron, SemanticOverlayRegistry};
+use crate::types::MunsellColor;
+use geo::Intersects;
+use std::collections::BTreeMap;
+
+/// Geometric region of a category in Munsell space.
+#[derive(Debug, Clone)]
+pub enum CategoryRegion {
+    /// Value/chroma polygon (`x = chroma`, `y = value`) applying to hues
+    /// after `hue_start` up to and including `hue_end`, clockwise.
+    Polygon {
+        /// First hue of the range (exclusive), e.g. "1R"
+        hue_start: String,
+        /// Last hue of the range (inclusive), e.g. "7R"
+        hue_end: String,
+        /// Region in the value/chroma plane
+        polygon: geo::Polygon<f64>,
+    },
+    /// Convex polyhedron in Munsell Cartesian space.
+    Polyhedron(ConvexPolyhedron),
+}
+
+impl CategoryRegion {
+    /// Create a polygon region spanning a hue range.
+    pub fn polygon(hue_start: &str, hue_end: &str, polygon: geo::Polygon<f64>) -> Self {
+        Self::Polygon {
+            hue_start: hue_start.to_string(),
+            hue_end: hue_end.to_string(),
+            polygon,
+        }
+    }
+
+    /// Test if a Munsell color lies inside this region.
+    ///
+    /// Polygon boundaries are inclusive. Neutral colors match a polygon region
+    /// whenever the polygon covers chroma 0 at their value, since hue is
+    /// undefined at zero chroma.
+    pub fn contains(&self, color: &MunsellColor) -> bool {
+        match self {
+            Self::Polygon { hue_start, hue_end, polygon } => {
+                let chroma = color.chroma.unwrap_or(0.0);
+                let point = geo::Point::new(chroma, color.value);
+                if !polygon.intersects(&point) {
+                    return false;
+                }
+                if color.is_neutral() || chroma == 0.0 {
+                    return true;
+                }
+                match (
+                    color.hue.as_deref().and_then(parse_hue_to_number),
+                    parse_hue_to_number(hue_start),
+                    parse_hue_to_number(hue_end),
+                ) {
+                    (Some(hue), Some(start), Some(end)) => hue_in_range(hue, start, end),
+                    _ => false,
+                }
+            }
+            Self::Polyhedron(polyhedron) => color
+                .to_munsell_spec()
+                .map(|spec| polyhedron.contains_point(&spec.to_cartesian()))
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// Test if a hue number (0-40) lies in the clockwise range (start, end].
+fn hue_in_range(hue: f64, start: f64, end: f64) -> bool {
+    let span = (end - start).rem_euclid(40.0);
+    let offset = (hue - start).rem_euclid(40.0);
+    if span == 0.0 {
+        // Identical endpoints denote the full hue circle
+        return true;
+    }
+    offset > 0.0 && offset <= span
+}
+
+/// A named category with its regions in Munsell space.
+#[derive(Debug, Clone)]
+pub struct Category {
+    /// Identifier, unique within the system (e.g. the ISCC-NBS color number)
+    pub id: u32,
+    /// Base color name (e.g. "red", "teal", "signal red")
+    pub name: String,
+    /// Modifier applied to the base name
+    pub modifier: ColorModifier,
+    /// Regions making up the category; a color matches if any region contains it
+    pub regions: Vec<CategoryRegion>,
+}
+
+impl Category {
+    /// Create a new category.
+    pub fn new(id: u32, name: &str, modifier: ColorModifier, regions: Vec<CategoryRegion>) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            modifier,
+            regions,
+        }
+    }
+
+    /// Test if a Munsell color lies inside any of the category's regions.
+    pub fn contains(&self, color: &MunsellColor) -> bool {
+        self.regions.iter().any(|r| r.contains(color))
+    }
+
+    /// Get the lightweight match label for this category.
+    pub fn to_match(&self) -> CategoryMatch {
+        CategoryMatch {
+            id: self.id,
+            name: self.name.clone(),
+            modifier: self.modifier,
+        }
+    }
+}
+
+/// A category matched by a color, without its region geometry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryMatch {
+    /// Category identifier within its system
+    pub id: u32,
+    /// Base color name
+    pub name: String,
+    /// Modifier applied to the base name
+    pub modifier: ColorModifier,
+}
+
+impl CategoryMatch {
+    /// Full descriptor combining modifier and name (e.g. "vivid red").
+    pub fn descriptor(&self) -> String {
+        self.modifier.format(&self.name)
+    }
+}
+
+/// A categorical color naming system over Munsell space.
+///
+/// Implementors define a set of [`Category`] values and decide which of them
+/// contain a given color. Use with
+/// [`ColorClassifier::categorize_srgb`](crate::ColorClassifier::categorize_srgb)
+/// to name colors in any supported input format.
+pub trait CategorySystem: Send + Sync {
+    /// Name of the system (e.g. "ISCC-NBS").
+    fn system_name(&self) -> &str;
+
+    /// All categories defined by the system, ordered by id.
+    fn categories(&self) -> Vec<Category>;
+
+    /// All categories containing the color, ordered by id.
+    fn categorize(&self, color: &MunsellColor) -> Result<Vec<CategoryMatch>>;
+
+    /// The first category containing the color, if any.
+    fn best_category(&self, color: &MunsellColor) -> Result<Option<CategoryMatch>> {
+        Ok(self.categorize(color)?.into_iter().next())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Custom category systems
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// A category system defined entirely by user-supplied categories.
+#[derive(Debug, Clone)]
+pub struct CustomCategorySystem {
+    name: String,
+    categories: Vec<Category>,
+}
+
+impl CustomCategorySystem {
+    /// Create an empty category system.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            categories: Vec::new(),
+        }
+    }
+
+    /// Add a category, replacing any existing category with the same id.
+    pub fn add_category(&mut self, category: Category) {
+        self.categories.retain(|c| c.id != category.id);
+        self.categories.push(category);
+        self.categories.sort_by_key(|c| c.id);
+    }
+
+    /// Number of categories in the system.
+    pub fn len(&self) -> usize {
+        self.categories.len()
+    }
+
+    /// Check if the system has no categories.
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty()
+    }
+}
+
+impl CategorySystem for CustomCategorySystem {
+    fn system_name(&self) -> &str {
+        &self.name
+    }
+
+    fn categories(&self) -> Vec<Category> {
+        self.categories.clone()
+    }
+
+    fn categorize(&self, color: &MunsellColor) -> Result<Vec<CategoryMatch>> {
+        Ok(self
+            .categories
+            .iter()
+            .filter(|c| c.contains(color))
+            .map(Category::to_match)
+            .collect())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Built-in implementations
+// ═══════════════════════════════════════════════════════════════════════════════
+
+impl CategorySystem for IsccNbsClassifier {
+    fn system_name(&self) -> &str {
+        "ISCC-NBS"
+    }
+
+    fn categories(&self) -> Vec<Category> {
+        let mut categories: BTreeMap<u16, Category> = BTreeMap::new();
+        let mut seen = std::collections::HashSet::new();
+
+        for polygon in self.wedge_system.wedge_containers.values().flatten() {
+            let key = (
+                polygon.color_number,
+                polygon.polygon_group,
+                polygon.hue_range.clone(),
+            );
+            if !seen.insert(key) {
+                continue;
+            }
+            let category = categories
+                .entry(polygon.color_number)
+                .or_insert_with(|| self.iscc_category(polygon.color_number));
+            category.regions.push(CategoryRegion::polygon(
+                &polygon.hue_range.0,
+                &polygon.hue_range.1,
+                polygon.polygon.clone(),
+            ));
+        }
+
+        categories.into_values().collect()
+    }
+
+    fn categorize(&self, color: &MunsellColor) -> Result<Vec<CategoryMatch>> {
+        let numbers = match (&color.hue, color.chroma) {
+            (Some(hue), Some(chroma)) if !is_achromatic_hue(hue) => {
+                self.find_all_colors_at_point(hue, color.value, chroma)?
+            }
+            _ => get_achromatic_color_number(color.value).into_iter().collect(),
+        };
+
+        Ok(numbers
+            .into_iter()
+            .map(|n| self.iscc_category(n).to_match())
+            .collect())
+    }
+}
+
+impl IsccNbsClassifier {
+    /// Build a region-less category from ISCC-NBS metadata.
+    fn iscc_category(&self, color_number: u16) -> Category {
+        let metadata = self.color_metadata.get(&color_number);
+        Category {
+            id: color_number as u32,
+            name: metadata
+                .map(|m| m.iscc_nbs_color_name.clone())
+                .unwrap_or_default(),
+            modifier: metadata
+                .and_then(|m| m.iscc_nbs_formatter.as_deref())
+                .map(ColorModifier::from_formatter)
+                .unwrap_or(ColorModifier::None),
+            regions: Vec::new(),
+        }
+    }
+}
+
+impl CategorySystem for SemanticOverlayRegistry {
+    fn system_name(&self) -> &str {
+        "Centore"
+    }
+
+    fn categories(&self) -> Vec<Category> {
+        self.all()
+            .iter()
+            .enumerate()
+            .map(|(i, overlay)| {
+                Category::new(
+                    i as u32 + 1,
+                    overlay.name.as_str(),
+                    ColorModifier::None,
+                    vec![CategoryRegion::Polyhedron(overlay.polyhedron.clone())],
+                )
+            })
+            .collect()
+    }
+
+    fn categorize(&self, color: &MunsellColor) -> Result<Vec<CategoryMatch>> {
+        let Some(spec) = color.to_munsell_spec() else {
+            return Ok(Vec::new());
+        };
+
+        Ok(self
+            .all()
+            .iter()
+            .enumerate()
+            .filter(|(_, overlay)| overlay.contains(&spec))
+            .map(|(i, overlay)| CategoryMatch {
+                id: i as u32 + 1,
+                name: overlay.name.to_string(),
+                modifier: ColorModifier::None,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic_overlay_data::get_registry;
+    use geo::polygon;
+
+    #[test]
+    fn test_hue_in_range_wraparound() {
+        // 1R..7R
+        assert!(hue_in_range(2.0, 0.4, 2.8));
+        assert!(!hue_in_range(0.4, 0.4, 2.8));
+        assert!(hue_in_range(2.8, 0.4, 2.8));
+        // 5RP..5R crosses the 10RP/0 boundary
+        assert!(hue_in_range(39.0, 38.0, 2.0));
+        assert!(hue_in_range(1.0, 38.0, 2.0));
+        assert!(!hue_in_range(20.0, 38.0, 2.0));
+    }
+
+    #[test]
+    fn test_iscc_nbs_category_system() {
+        let iscc = IsccNbsClassifier::new().unwrap();
+        assert_eq!(iscc.system_name(), "ISCC-NBS");
+
+        let categories = iscc.categories();
+        assert_eq!(categories.len(), 267);
+        assert!(categories.iter().all(|c| !c.regions.is_empty() || c.id >= 263));
+
+        let red = MunsellColor::new_chromatic("5R".to_string(), 4.0, 14.0);
+        let matches = iscc.categorize(&red).unwrap();
+        assert_eq!(matches[0].id, 11);
+        assert_eq!(matches[0].descriptor(), "vivid red");
+
+        // Polygon regions agree with the classifier itself
+        let category = categories.iter().find(|c| c.id == 11).unwrap();
+        assert!(category.contains(&red));
+
+        let gray = MunsellColor::new_neutral(5.0);
+        let matches = iscc.categorize(&gray).unwrap();
+        assert_eq!(matches[0].id, 265);
+    }
+
+    #[test]
+    fn test_overlay_category_system() {
+        let registry = get_registry();
+        assert_eq!(registry.categories().len(), registry.len());
+
+        let aqua = MunsellColor::from_notation("7.4BG 6.2/3.4").unwrap();
+        let names: Vec<String> = registry
+            .categorize(&aqua)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        assert!(names.contains(&"aqua".to_string()));
+
+        let category = registry.categories().into_iter().find(|c| c.name == "aqua").unwrap();
+        assert!(category.contains(&aqua));
+    }
+
+    #[test]
+    fn test_custom_category_system() {
+        let mut system = CustomCategorySystem::new("catalogue");
+        assert!(system.is_empty());
+
+        system.add_category(Category::new(
+            2,
+            "stone",
+            ColorModifier::Light,
+            vec![CategoryRegion::polygon(
+                "10RP",
+                "10RP",
+                polygon![(x: 0.0, y: 6.0), (x: 2.0, y: 6.0), (x: 2.0, y: 9.0), (x: 0.0, y: 9.0)],
+            )],
+        ));
+        system.add_category(Category::new(
+            1,
+            "brick",
+            ColorModifier::None,
+            vec![CategoryRegion::polygon(
+                "10RP",
+                "5YR",
+                polygon![(x: 4.0, y: 2.0), (x: 12.0, y: 2.0), (x: 12.0, y: 5.0), (x: 4.0, y: 5.0)],
+            )],
+        ));
+        assert_eq!(system.len(), 2);
+        assert_eq!(system.categories()[0].name, "brick");
+
+        let brick = MunsellColor::new_chromatic("10R".to_string(), 4.0, 8.0);
+        let matches = system.categorize(&brick).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].name, "brick");
+
+        // Outside the hue range
+        let blue = MunsellColor::new_chromatic("5B".to_string(), 4.0, 8.0);
+        assert!(system.categorize(&blue).unwrap().is_empty());
+
+        // Full-circle region matches neutrals and any hue
+        let pale = MunsellColor::new_neutral(7.0);
+        let matches = system.categorize(&pale).unwrap();
+        assert_eq!(matches[0].descriptor(), "light stone");
+        let tinted = MunsellColor::new_chromatic("5PB".to_string(), 7.0, 1.0);
+        assert_eq!(system.best_category(&tinted).unwrap().unwrap().id, 2);
+    }
+}
@@ -28,6 +28,7 @@
 
 use super::modifier::ColorModifier;
 use crate::semantic_overlay::MunsellSpec;
+use crate::temperature::Temperature;
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // Formatting Options
@@ -86,12 +87,18 @@ pub enum OverlayMode {
 /// let default_opts = FormatOptions::default();
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct FormatOptions {
     /// Which base color naming system to use.
     pub base_colors: BaseColorSet,
 
     /// How to handle semantic overlay names.
     pub overlay_mode: OverlayMode,
+
+    /// Prefix the warm/cool qualifier (e.g. "warm", "slightly cool").
+    ///
+    /// Off by default. Neutral colors get no qualifier.
+    pub temperature: bool,
 }
 
 impl FormatOptions {
@@ -100,9 +107,18 @@ impl FormatOptions {
         Self {
             base_colors,
             overlay_mode,
+            temperature: false,
         }
     }
 
+    /// Enable or disable the temperature qualifier.
+    ///
+    /// Output: "warm light gray", "cool vivid red", etc.
+    pub fn with_temperature(mut self, temperature: bool) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
     /// Standard ISCC-NBS base colors with modifiers, no overlays.
     ///
     /// Output: "vivid red", "dark blue", "pale green", etc.
@@ -110,6 +126,7 @@ impl FormatOptions {
         Self {
             base_colors: BaseColorSet::Standard,
             overlay_mode: OverlayMode::Ignore,
+            temperature: false,
         }
     }
 
@@ -120,6 +137,7 @@ impl FormatOptions {
         Self {
             base_colors: BaseColorSet::Extended,
             overlay_mode: OverlayMode::Ignore,
+            temperature: false,
         }
     }
 
@@ -130,6 +148,7 @@ impl FormatOptions {
         Self {
             base_colors: BaseColorSet::Standard,
             overlay_mode: OverlayMode::Include,
+            temperature: false,
         }
     }
 
@@ -140,6 +159,7 @@ impl FormatOptions {
         Self {
             base_colors: BaseColorSet::Extended,
             overlay_mode: OverlayMode::Include,
+            temperature: false,
         }
     }
 }
@@ -149,6 +169,7 @@ impl Default for FormatOptions {
         Self {
             base_colors: BaseColorSet::Extended,
             overlay_mode: OverlayMode::Include,
+            temperature: false,
         }
     }
 }
@@ -189,6 +210,7 @@ impl Default for FormatOptions {
 /// # }
 /// ```
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ColorCharacterization {
     /// The Munsell specification (hue_number, value, chroma).
     pub munsell: MunsellSpec,
@@ -198,6 +220,10 @@ pub struct ColorCharacterization {
     /// ISCC-NBS category number (1-267).
     pub iscc_nbs_number: u16,
 
+    /// Whether the color lies on an edge shared by several ISCC-NBS
+    /// categories, so `iscc_nbs_number` was chosen by the overlap policy.
+    pub ambiguous: bool,
+
     /// Base color name from ISCC-NBS (one of 13: red, blue, green, etc.).
     pub iscc_base_color: String,
 
@@ -226,6 +252,11 @@ pub struct ColorCharacterization {
     ///
     /// Groups similar colors into broader categories.
     pub shade: String,
+
+    // ─── Temperature ───
+
+    /// Warm/cool temperature of the color within its hue family.
+    pub temperature: Temperature,
 }
 
 impl ColorCharacterization {
@@ -234,6 +265,7 @@ impl ColorCharacterization {
     /// The output string depends on:
     /// - `base_colors`: Whether to use Standard (29 ISCC-NBS names) or Extended (lime/teal/turquoise)
     /// - `overlay_mode`: Whether to include semantic overlay names
+    /// - `temperature`: Whether to prefix the warm/cool qualifier
     ///
     /// The ISCC-NBS modifier (e.g., "dark", "vivid", "pale") is ALWAYS applied.
     ///
@@ -268,7 +300,13 @@ impl ColorCharacterization {
         };
 
         // 2. Always apply modifier
-        self.modifier.format(color_name)
+        let description = self.modifier.format(color_name);
+
+        // 3. Optionally qualify by temperature
+        match self.temperature.qualifier() {
+            Some(qualifier) if options.temperature => format!("{} {}", qualifier, description),
+            _ => description,
+        }
     }
 
     /// Get the base color name without any modifier.
@@ -329,12 +367,17 @@ mod tests {
         ColorCharacterization {
             munsell: MunsellSpec::new(28.0, 3.0, 8.0), // A blue-ish hue
             iscc_nbs_number: 182,
+            ambiguous: false,
             iscc_base_color: base.to_string(),
             iscc_extended_name: extended.to_string(),
             modifier,
             semantic_matches: semantic_matches.into_iter().map(String::from).collect(),
             nearest_semantic: nearest.map(|(n, d)| (n.to_string(), d)),
             shade: base.to_string(), // Use base as default shade for tests
+            temperature: crate::temperature::temperature(
+                &MunsellSpec::new(28.0, 3.0, 8.0),
+                &crate::temperature::TemperatureConfig::default(),
+            ),
         }
     }
 
@@ -3,14 +3,17 @@
 //! The `ColorClassifier` provides a single entry point for classifying colors
 //! across all naming systems: ISCC-NBS standard, extended, and semantic overlays.
 
-use crate::error::{MunsellError, Result};
+use crate::category_system::{CategoryMatch, CategorySystem};
+use crate::error::{ErrorCode, ErrorInput, MunsellError, PipelineError, PipelineStage, Result};
 use crate::iscc::{ColorMetadata, IsccNbsClassifier};
-use crate::semantic_overlay::{parse_munsell_notation, MunsellSpec};
+use crate::screen_correction::ScreenCorrector;
+use crate::semantic_overlay::{parse_munsell_notation, MunsellSpec, OverlayContext, SemanticOverlayRegistry};
 use crate::types::MunsellColor;
-use crate::unified_cache::hex_to_rgb;
+use crate::unified_cache::{hex_to_rgb, naming_profile, CacheStats, CachedColorResult, UnifiedColorCache};
 use crate::MunsellConverter;
 
-use crate::semantic_overlay_data::get_registry;
+use crate::semantic_overlay_data::{get_registry, get_screen_registry};
+use crate::temperature::{temperature, Temperature, TemperatureConfig};
 
 use super::characterization::ColorCharacterization;
 use super::descriptor::ColorDescriptor;
@@ -45,7 +48,20 @@ use super::modifier::ColorModifier;
 /// ```
 pub struct ColorClassifier {
     converter: MunsellConverter,
-    iscc: IsccNbsClassifier,
+    pub(super) iscc: IsccNbsClassifier,
+    /// Custom overlay registry; the built-in Centore registry when `None`
+    overlays: Option<SemanticOverlayRegistry>,
+    /// Screen-referenced variant of the custom registry, built on demand
+    screen_overlays: Option<SemanticOverlayRegistry>,
+    /// Whether overlays are matched as physical or screen-referenced regions
+    overlay_context: OverlayContext,
+    /// Thresholds for warm/cool temperature labels
+    temperature_config: TemperatureConfig,
+    /// Optional shared cache of naming results
+    cache: Option<UnifiedColorCache>,
+    /// Identifies the naming settings, so classifiers that name colors
+    /// differently never share cached characterizations
+    cache_profile: u64,
 }
 
 impl ColorClassifier {
@@ -60,9 +76,175 @@ impl ColorClassifier {
         Ok(Self {
             converter: MunsellConverter::new()?,
             iscc: IsccNbsClassifier::new()?,
+            overlays: None,
+            screen_overlays: None,
+            overlay_context: OverlayContext::default(),
+            temperature_config: TemperatureConfig::default(),
+            cache: None,
+            cache_profile: 0,
         })
     }
 
+    /// Memoize naming results in a shared cache.
+    ///
+    /// Each cached color keeps its Munsell conversion, ISCC-NBS
+    /// classification and characterization, so a repeated color skips both
+    /// the solver and the polygon tests. The cache can be shared with other
+    /// classifiers and with [`MunsellConverter::with_cache`]; classifiers
+    /// with different overlays, overlay context or temperature thresholds
+    /// keep their characterizations apart.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use munsellspace::color_names::ColorClassifier;
+    /// use munsellspace::UnifiedColorCache;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cache = UnifiedColorCache::new();
+    /// let classifier = ColorClassifier::new()?.with_cache(cache.clone());
+    ///
+    /// for _ in 0..3 {
+    ///     classifier.classify_srgb([180, 80, 60])?;
+    /// }
+    /// let stats = classifier.cache_stats().unwrap();
+    /// assert_eq!((stats.hits, stats.misses), (2, 1));
+    ///
+    /// // The cache also answers plain lookups
+    /// assert!(cache.get(&[180, 80, 60]).unwrap().iscc_nbs.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_cache(mut self, cache: UnifiedColorCache) -> Self {
+        self.converter = self.converter.with_cache(cache.clone());
+        self.cache = Some(cache);
+        self
+    }
+
+    /// The shared cache, if any.
+    pub fn cache(&self) -> Option<&UnifiedColorCache> {
+        self.cache.as_ref()
+    }
+
+    /// Statistics of the shared cache, if any.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(UnifiedColorCache::stats)
+    }
+
+    /// Use custom thresholds for warm/cool temperature labels.
+    ///
+    /// Affects [`ColorCharacterization::temperature`] and descriptions made
+    /// with [`FormatOptions::with_temperature`](super::FormatOptions::with_temperature).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use munsellspace::color_names::{ColorClassifier, FormatOptions};
+    /// use munsellspace::TemperatureConfig;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = TemperatureConfig { warm_threshold: 0.4, ..TemperatureConfig::default() };
+    /// let classifier = ColorClassifier::new()?.with_temperature_config(config);
+    ///
+    /// let char = classifier.characterize_srgb([200, 60, 40])?;
+    /// println!("{}", char.describe(&FormatOptions::standard().with_temperature(true)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_temperature_config(mut self, config: TemperatureConfig) -> Self {
+        self.temperature_config = config;
+        self.refresh_cache_profile();
+        self
+    }
+
+    /// Use a custom semantic overlay registry instead of the built-in one.
+    ///
+    /// Combine with [`SemanticOverlayRegistry::merge`] to extend the Centore
+    /// overlays with runtime-loaded names, or pass a registry built only from
+    /// loaded data to replace them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use munsellspace::color_names::ColorClassifier;
+    /// use munsellspace::create_overlay_registry;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let registry = create_overlay_registry();
+    /// let classifier = ColorClassifier::new()?.with_overlay_registry(registry);
+    /// let _ = classifier.semantic_name([200, 150, 120])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_overlay_registry(mut self, registry: SemanticOverlayRegistry) -> Self {
+        self.overlays = Some(registry);
+        self.refresh_screen_overlays();
+        self.refresh_cache_profile();
+        self
+    }
+
+    /// Match semantic overlays as physical or screen-referenced regions.
+    ///
+    /// Centore's overlays describe physical surface colors, while Munsell
+    /// values converted from sRGB or hex are screen-referenced. With
+    /// [`OverlayContext::Screen`] colors are matched against the
+    /// screen-referenced variant of each overlay (see
+    /// [`SemanticOverlay::to_screen`](crate::SemanticOverlay::to_screen)).
+    /// The default, [`OverlayContext::Physical`], uses the regions as measured.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use munsellspace::color_names::ColorClassifier;
+    /// use munsellspace::OverlayContext;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = ColorClassifier::new()?.with_overlay_context(OverlayContext::Screen);
+    /// let matches = classifier.semantic_matches([255, 215, 0])?;
+    /// assert!(matches.iter().any(|name| name == "gold"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_overlay_context(mut self, context: OverlayContext) -> Self {
+        self.overlay_context = context;
+        self.refresh_screen_overlays();
+        self.refresh_cache_profile();
+        self
+    }
+
+    /// The context in which semantic overlays are matched.
+    pub fn overlay_context(&self) -> OverlayContext {
+        self.overlay_context
+    }
+
+    /// The semantic overlay registry used for naming.
+    ///
+    /// This is the screen-referenced variant of the registry when the overlay
+    /// context is [`OverlayContext::Screen`].
+    pub fn overlay_registry(&self) -> &SemanticOverlayRegistry {
+        match self.overlay_context {
+            OverlayContext::Physical => self.overlays.as_ref().unwrap_or_else(|| get_registry()),
+            OverlayContext::Screen => self
+                .screen_overlays
+                .as_ref()
+                .unwrap_or_else(|| get_screen_registry()),
+        }
+    }
+
+    /// Internal: build the screen variant of a custom registry when needed.
+    fn refresh_screen_overlays(&mut self) {
+        self.screen_overlays = match (self.overlay_context, &self.overlays) {
+            (OverlayContext::Screen, Some(custom)) => Some(custom.to_screen(&ScreenCorrector::new())),
+            _ => None,
+        };
+    }
+
+    /// Internal: pick the cache profile for the current naming settings.
+    fn refresh_cache_profile(&mut self) {
+        self.cache_profile =
+            naming_profile(self.overlays.as_ref(), self.overlay_context, &self.temperature_config);
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // Primary Classification Methods
     // ═══════════════════════════════════════════════════════════════════════════
@@ -90,8 +272,7 @@ impl ColorClassifier {
     /// # }
     /// ```
     pub fn classify_srgb(&self, rgb: [u8; 3]) -> Result<ColorDescriptor> {
-        let munsell = self.converter.srgb_to_munsell(rgb)?;
-        self.classify_munsell_color(&munsell)
+        Ok(self.characterize_srgb(rgb)?.into())
     }
 
     /// Classify a hex color string and return complete naming information.
@@ -134,8 +315,7 @@ impl ColorClassifier {
     /// # }
     /// ```
     pub fn classify_lab(&self, lab: [f64; 3]) -> Result<ColorDescriptor> {
-        let munsell = self.converter.lab_to_munsell(lab)?;
-        self.classify_munsell_color(&munsell)
+        Ok(self.characterize_lab(lab)?.into())
     }
 
     /// Classify a Munsell notation string and return complete naming information.
@@ -157,8 +337,7 @@ impl ColorClassifier {
     /// # }
     /// ```
     pub fn classify_munsell(&self, notation: &str) -> Result<ColorDescriptor> {
-        let munsell = MunsellColor::from_notation(notation)?;
-        self.classify_munsell_color(&munsell)
+        Ok(self.characterize_munsell_notation(notation)?.into())
     }
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -189,9 +368,36 @@ impl ColorClassifier {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// # Errors
+    /// Errors carry `rgb` as their input; see [`MunsellError::input`].
     pub fn characterize_srgb(&self, rgb: [u8; 3]) -> Result<ColorCharacterization> {
-        let munsell = self.converter.srgb_to_munsell(rgb)?;
-        self.characterize_munsell_color(&munsell)
+        // Conversion errors already name their stage; the rest come from
+        // classification
+        self.characterize_srgb_cached(rgb)
+            .map_err(|error| error.with_input(PipelineStage::PolygonTest, ErrorInput::Rgb(rgb)))
+    }
+
+    fn characterize_srgb_cached(&self, rgb: [u8; 3]) -> Result<ColorCharacterization> {
+        let Some(cache) = &self.cache else {
+            let munsell = self.converter.srgb_to_munsell(rgb)?;
+            return self.characterize_munsell_color(&munsell);
+        };
+
+        let munsell = match cache.get_characterization(&rgb, self.cache_profile) {
+            Ok(characterization) => return Ok(characterization),
+            Err(Some(munsell)) => munsell,
+            Err(None) => self.converter.srgb_to_munsell_uncached(rgb)?,
+        };
+        let (iscc_meta, characterization) = self.characterize_with_metadata(&munsell)?;
+
+        let result = CachedColorResult {
+            rgb,
+            munsell,
+            iscc_nbs: Some(iscc_meta),
+        };
+        cache.insert_characterization(result, self.cache_profile, &characterization);
+        Ok(characterization)
     }
 
     /// Characterize a hex color string and return objective facts.
@@ -220,9 +426,15 @@ impl ColorClassifier {
     /// # Arguments
     ///
     /// * `lab` - CIELAB color as [L*, a*, b*]
+    ///
+    /// # Errors
+    /// Errors carry `lab` as their input; see [`MunsellError::input`].
     pub fn characterize_lab(&self, lab: [f64; 3]) -> Result<ColorCharacterization> {
-        let munsell = self.converter.lab_to_munsell(lab)?;
-        self.characterize_munsell_color(&munsell)
+        self.converter
+            .lab_to_munsell(lab)
+            .map_err(|error| error.with_input(PipelineStage::Solver, ErrorInput::Lab(lab)))
+            .and_then(|munsell| self.characterize_munsell_color(&munsell))
+            .map_err(|error| error.with_input(PipelineStage::PolygonTest, ErrorInput::Lab(lab)))
     }
 
     /// Characterize a Munsell notation string and return objective facts.
@@ -247,23 +459,71 @@ impl ColorClassifier {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// # Errors
+    /// Errors carry `notation` as their input; see [`MunsellError::input`].
     pub fn characterize_munsell_notation(&self, notation: &str) -> Result<ColorCharacterization> {
         let munsell = MunsellColor::from_notation(notation)?;
-        self.characterize_munsell_color(&munsell)
+        self.characterize_munsell_color(&munsell).map_err(|error| {
+            error.with_input(PipelineStage::PolygonTest, ErrorInput::Notation(notation.to_string()))
+        })
     }
 
     /// Internal: characterize a MunsellColor and build ColorCharacterization.
-    #[allow(deprecated)] // Uses deprecated semantic overlay functions internally
     fn characterize_munsell_color(&self, munsell: &MunsellColor) -> Result<ColorCharacterization> {
+        Ok(self.characterize_with_metadata(munsell)?.1)
+    }
+
+    /// Internal: characterize a MunsellColor, keeping its ISCC-NBS metadata.
+    fn characterize_with_metadata(
+        &self,
+        munsell: &MunsellColor,
+    ) -> Result<(ColorMetadata, ColorCharacterization)> {
         // Get ISCC-NBS classification
-        let (iscc_number, iscc_meta) = self.get_iscc_classification(munsell)?;
+        let (iscc_number, iscc_meta, ambiguous) = self.get_iscc_classification(munsell)?;
+        let characterization =
+            self.build_characterization(munsell, iscc_number, &iscc_meta, ambiguous);
+        Ok((iscc_meta, characterization))
+    }
 
+    /// Internal: run the naming pipeline without the cache.
+    ///
+    /// Colors that convert but cannot be classified have no metadata or
+    /// characterization.
+    pub(crate) fn characterize_srgb_uncached(
+        &self,
+        rgb: [u8; 3],
+    ) -> Result<(MunsellColor, Option<(ColorMetadata, ColorCharacterization)>)> {
+        let munsell = self.converter.srgb_to_munsell_uncached(rgb)?;
+        let naming = self.characterize_with_metadata(&munsell).ok();
+        Ok((munsell, naming))
+    }
+
+    /// Internal: the profile under which this classifier caches
+    /// characterizations.
+    ///
+    /// Profiles 0 and 1 are the default naming settings in the physical and
+    /// screen overlay contexts; they are the same in every process.
+    pub(crate) fn cache_profile(&self) -> u64 {
+        self.cache_profile
+    }
+
+    /// Internal: add semantic overlay matches and temperature to an ISCC-NBS
+    /// classification.
+    #[allow(deprecated)] // Uses deprecated semantic overlay functions internally
+    fn build_characterization(
+        &self,
+        munsell: &MunsellColor,
+        iscc_number: u16,
+        iscc_meta: &ColorMetadata,
+        ambiguous: bool,
+    ) -> ColorCharacterization {
         // Convert to MunsellSpec for semantic overlay lookup
         let munsell_spec = self.munsell_color_to_spec(munsell);
 
         // Get semantic overlay matches
         let (semantic_matches, nearest) = if let Some(ref spec) = munsell_spec {
-            let registry = get_registry();
+            let registry = self.overlay_registry();
             let all_matches: Vec<String> =
                 registry.matching_overlays(spec).iter().map(|o| o.name.to_string()).collect();
             let nearest = registry.closest_overlay(spec).map(|(o, dist)| (o.name.to_string(), dist));
@@ -272,6 +532,27 @@ impl ColorClassifier {
             (vec![], None)
         };
 
+        Self::assemble_characterization(
+            munsell,
+            iscc_number,
+            iscc_meta,
+            ambiguous,
+            semantic_matches,
+            nearest,
+            &self.temperature_config,
+        )
+    }
+
+    /// Internal: combine classification results into a ColorCharacterization.
+    pub(crate) fn assemble_characterization(
+        munsell: &MunsellColor,
+        iscc_number: u16,
+        iscc_meta: &ColorMetadata,
+        ambiguous: bool,
+        semantic_matches: Vec<String>,
+        nearest: Option<(String, f64)>,
+        temperature_config: &TemperatureConfig,
+    ) -> ColorCharacterization {
         // Extract modifier from formatter
         let modifier = iscc_meta
             .iscc_nbs_formatter
@@ -279,40 +560,83 @@ impl ColorClassifier {
             .map(|f| ColorModifier::from_formatter(f))
             .unwrap_or(ColorModifier::None);
 
-        Ok(ColorCharacterization {
-            munsell: munsell_spec.unwrap_or_else(|| MunsellSpec::new(0.0, munsell.value, 0.0)),
+        let munsell_spec = parse_munsell_notation(&munsell.notation)
+            .unwrap_or_else(|| MunsellSpec::new(0.0, munsell.value, 0.0));
+        let temperature = temperature(&munsell_spec, temperature_config);
+
+        ColorCharacterization {
+            munsell: munsell_spec,
             iscc_nbs_number: iscc_number,
+            ambiguous,
             iscc_base_color: iscc_meta.iscc_nbs_color_name.clone(),
             iscc_extended_name: iscc_meta.alt_color_name.clone(),
             modifier,
             semantic_matches,
             nearest_semantic: nearest,
             shade: iscc_meta.color_shade.clone(),
-        })
+            temperature,
+        }
     }
 
     /// Internal: classify a MunsellColor and build the complete descriptor.
     ///
     /// Uses ColorCharacterization internally and converts to ColorDescriptor.
-    fn classify_munsell_color(&self, munsell: &MunsellColor) -> Result<ColorDescriptor> {
-        // Get the characterization first
-        let char = self.characterize_munsell_color(munsell)?;
-
-        // Convert to ColorDescriptor format
-        let semantic_name = char.semantic_matches.first().cloned();
-        let semantic_alternates: Vec<String> =
-            char.semantic_matches.into_iter().skip(1).collect();
-
-        Ok(ColorDescriptor {
-            iscc_nbs_number: char.iscc_nbs_number,
-            modifier: char.modifier,
-            standard_name: char.iscc_base_color,
-            extended_name: char.iscc_extended_name,
-            semantic_name,
-            semantic_alternates,
-            nearest_semantic: char.nearest_semantic,
-            shade: char.shade,
-        })
+    pub(crate) fn classify_munsell_color(&self, munsell: &MunsellColor) -> Result<ColorDescriptor> {
+        Ok(self.characterize_munsell_color(munsell)?.into())
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Category System Methods
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Categorize an sRGB color using any [`CategorySystem`].
+    ///
+    /// Converts the color to Munsell and returns all categories of `system`
+    /// that contain it, so ISCC-NBS, the semantic overlays, and custom naming
+    /// schemes can all be used through the same classifier.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use munsellspace::color_names::ColorClassifier;
+    /// use munsellspace::IsccNbsClassifier;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = ColorClassifier::new()?;
+    /// let iscc = IsccNbsClassifier::new()?;
+    /// for category in classifier.categorize_srgb([255, 0, 0], &iscc)? {
+    ///     println!("{}: {}", category.id, category.descriptor());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn categorize_srgb(
+        &self,
+        rgb: [u8; 3],
+        system: &dyn CategorySystem,
+    ) -> Result<Vec<CategoryMatch>> {
+        let munsell = self.converter.srgb_to_munsell(rgb)?;
+        system.categorize(&munsell)
+    }
+
+    /// Categorize a hex color string using any [`CategorySystem`].
+    pub fn categorize_hex(
+        &self,
+        hex: &str,
+        system: &dyn CategorySystem,
+    ) -> Result<Vec<CategoryMatch>> {
+        let rgb = hex_to_rgb(hex)?;
+        self.categorize_srgb(rgb, system)
+    }
+
+    /// Categorize a Munsell notation string using any [`CategorySystem`].
+    pub fn categorize_munsell_notation(
+        &self,
+        notation: &str,
+        system: &dyn CategorySystem,
+    ) -> Result<Vec<CategoryMatch>> {
+        let munsell = MunsellColor::from_notation(notation)?;
+        system.categorize(&munsell)
     }
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -339,7 +663,7 @@ impl ColorClassifier {
     pub fn semantic_name(&self, rgb: [u8; 3]) -> Result<Option<String>> {
         let munsell = self.converter.srgb_to_munsell(rgb)?;
         if let Some(spec) = self.munsell_color_to_spec(&munsell) {
-            let registry = get_registry();
+            let registry = self.overlay_registry();
             Ok(registry.best_match(&spec).map(|o| o.name.to_string()))
         } else {
             Ok(None)
@@ -367,7 +691,7 @@ impl ColorClassifier {
     pub fn semantic_matches(&self, rgb: [u8; 3]) -> Result<Vec<String>> {
         let munsell = self.converter.srgb_to_munsell(rgb)?;
         if let Some(spec) = self.munsell_color_to_spec(&munsell) {
-            let registry = get_registry();
+            let registry = self.overlay_registry();
             Ok(registry.matching_overlays(&spec)
                 .iter()
                 .map(|o| o.name.to_string())
@@ -377,6 +701,30 @@ impl ColorClassifier {
         }
     }
 
+    /// Get the warm/cool temperature of an sRGB color.
+    ///
+    /// Uses the classifier's [`TemperatureConfig`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use munsellspace::color_names::ColorClassifier;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = ColorClassifier::new()?;
+    /// let temperature = classifier.temperature_srgb([140, 130, 115])?;
+    /// println!("{} ({:.2})", temperature.label, temperature.score);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn temperature_srgb(&self, rgb: [u8; 3]) -> Result<Temperature> {
+        let munsell = self.converter.srgb_to_munsell(rgb)?;
+        let spec = self
+            .munsell_color_to_spec(&munsell)
+            .unwrap_or_else(|| MunsellSpec::neutral(munsell.value));
+        Ok(temperature(&spec, &self.temperature_config))
+    }
+
     /// Get all ISCC-NBS color numbers that contain a given sRGB color.
     ///
     /// Colors at boundaries may fall within multiple ISCC-NBS categories.
@@ -395,19 +743,17 @@ impl ColorClassifier {
     // Internal Helper Methods
     // ═══════════════════════════════════════════════════════════════════════════
 
-    /// Get ISCC-NBS classification including color number.
-    fn get_iscc_classification(&self, munsell: &MunsellColor) -> Result<(u16, ColorMetadata)> {
+    /// Get ISCC-NBS classification including color number, and whether
+    /// several categories claimed the color.
+    fn get_iscc_classification(&self, munsell: &MunsellColor) -> Result<(u16, ColorMetadata, bool)> {
         if let (Some(hue), Some(chroma)) = (&munsell.hue, munsell.chroma) {
-            // Use find_all_colors_at_point to get color number(s)
-            let color_numbers = self
+            // Resolve number and metadata together so overlaps cannot disagree
+            let (result, metadata) = self
                 .iscc
-                .find_all_colors_at_point(hue, munsell.value, chroma)?;
+                .classify_resolved_metadata(hue, munsell.value, chroma)?;
 
-            if let Some(&color_number) = color_numbers.first() {
-                // Get metadata for this color number
-                if let Some(metadata) = self.iscc.classify_munsell(hue, munsell.value, chroma)? {
-                    return Ok((color_number, metadata));
-                }
+            if let (Some(color_number), Some(metadata)) = (result.color_number(), metadata) {
+                return Ok((color_number, metadata, result.ambiguous));
             }
         }
 
@@ -423,15 +769,17 @@ impl ColorClassifier {
                 color_shade: neutral_name.to_string(),
             };
             let color_number = self.get_neutral_color_number(munsell.value);
-            return Ok((color_number, metadata));
+            return Ok((color_number, metadata, false));
         }
 
-        Err(MunsellError::ConversionError {
-            message: format!(
-                "Could not classify Munsell color: {}",
-                munsell.notation
-            ),
-        })
+        Err(MunsellError::Pipeline(Box::new(PipelineError {
+            code: ErrorCode::Unclassified,
+            stage: PipelineStage::PolygonTest,
+            // Callers record the input they were given
+            input: None,
+            message: "No ISCC-NBS category contains the color".to_string(),
+            solver: None,
+        })))
     }
 
     /// Get the color name for a neutral (achromatic) color based on value.
@@ -1,7 +1,7 @@
 //! Tests for the unified color classifier.
 
 use super::*;
-use crate::color_names::ColorModifier;
+use crate::color_names::{ColorModifier, FormatOptions, PhraseOverlay};
 
 fn classifier() -> ColorClassifier {
     ColorClassifier::new().expect("Failed to create classifier")
@@ -190,6 +190,21 @@ fn test_all_iscc_matches() {
     assert!(!matches.is_empty());
 }
 
+#[test]
+fn test_ambiguous_on_shared_edge() {
+    let c = classifier();
+
+    // 2.5YR 6.5/7.0 lies on an edge claimed by colors 39 and 53
+    let edge = c.characterize_munsell_notation("2.5YR 6.5/7.0").expect("Classification failed");
+    assert!(edge.ambiguous);
+    assert_eq!(edge.iscc_nbs_number, 39);
+    assert!(c.classify_munsell("2.5YR 6.5/7.0").expect("Classification failed").ambiguous);
+
+    let interior = c.characterize_munsell_notation("5R 4/10").expect("Classification failed");
+    assert!(!interior.ambiguous);
+    assert!(!c.characterize_munsell_notation("N 5/").expect("Classification failed").ambiguous);
+}
+
 #[test]
 fn test_display_trait() {
     let c = classifier();
@@ -199,3 +214,210 @@ fn test_display_trait() {
     let display = format!("{}", desc);
     assert_eq!(display, desc.standard_descriptor());
 }
+
+#[test]
+fn test_custom_overlay_registry_replaces_builtin() {
+    use crate::semantic_overlay::SemanticOverlayRegistry;
+
+    let rgb = [200, 100, 80];
+    assert!(!classifier().semantic_matches(rgb).unwrap().is_empty());
+
+    let empty = ColorClassifier::new()
+        .unwrap()
+        .with_overlay_registry(SemanticOverlayRegistry::new(Vec::new()));
+    assert!(empty.overlay_registry().is_empty());
+    assert!(empty.semantic_matches(rgb).unwrap().is_empty());
+    assert_eq!(empty.semantic_name(rgb).unwrap(), None);
+    assert!(empty.characterize_srgb(rgb).unwrap().nearest_semantic.is_none());
+}
+
+#[test]
+fn test_temperature_qualified_descriptors() {
+    let c = classifier();
+    let opts = FormatOptions::standard().with_temperature(true);
+
+    let warm_gray = c.characterize_munsell_notation("10YR 6/1").unwrap();
+    assert!(warm_gray.temperature.achromatic);
+    assert!(warm_gray.describe(&opts).starts_with("warm "), "{}", warm_gray.describe(&opts));
+    assert!(warm_gray.describe(&opts).ends_with("gray"), "{}", warm_gray.describe(&opts));
+
+    let cool_red = c.characterize_munsell_notation("1.5R 4/12").unwrap();
+    let plain = cool_red.describe(&FormatOptions::standard());
+    assert_eq!(cool_red.describe(&opts), format!("cool {}", plain));
+    assert_eq!(cool_red.describe(&opts), "cool strong red");
+
+    // Neutral colors and the default options add no qualifier
+    let gray = c.characterize_munsell_notation("N 5/").unwrap();
+    assert_eq!(gray.describe(&opts), gray.describe(&FormatOptions::standard()));
+    assert!(!cool_red.describe(&FormatOptions::default()).contains("cool"));
+}
+
+#[test]
+fn test_temperature_config_on_classifier() {
+    use crate::temperature::{TemperatureConfig, TemperatureLabel};
+
+    let strict = ColorClassifier::new().unwrap().with_temperature_config(TemperatureConfig {
+        neutral_threshold: 0.95,
+        warm_threshold: 0.99,
+        ..TemperatureConfig::default()
+    });
+    let char = strict.characterize_munsell_notation("1.5R 4/12").unwrap();
+    assert_eq!(char.temperature.label, TemperatureLabel::Neutral);
+
+    let t = classifier().temperature_srgb([128, 128, 128]).unwrap();
+    assert!(t.achromatic);
+    assert_eq!(t.label, TemperatureLabel::Neutral);
+}
+
+#[test]
+fn test_overlay_context_selects_regions() {
+    use crate::semantic_overlay::OverlayContext;
+    use crate::semantic_overlay_data::{get_registry, get_screen_registry};
+
+    let physical = classifier();
+    assert_eq!(physical.overlay_context(), OverlayContext::Physical);
+    assert!(std::ptr::eq(physical.overlay_registry(), get_registry()));
+
+    let screen = classifier().with_overlay_context(OverlayContext::Screen);
+    assert!(std::ptr::eq(screen.overlay_registry(), get_screen_registry()));
+
+    // Web gold is too light and saturated for the physical gold region
+    let gold = [255, 215, 0];
+    assert!(!physical.semantic_matches(gold).unwrap().contains(&"gold".to_string()));
+    assert!(screen.semantic_matches(gold).unwrap().contains(&"gold".to_string()));
+
+    // Custom registries get a screen variant too, in either builder order
+    let custom = crate::semantic_overlay_data::create_overlay_registry();
+    let a = classifier()
+        .with_overlay_registry(custom.clone())
+        .with_overlay_context(OverlayContext::Screen);
+    let b = classifier()
+        .with_overlay_context(OverlayContext::Screen)
+        .with_overlay_registry(custom);
+    for c in [&a, &b] {
+        let teal = c.overlay_registry().get("teal").unwrap();
+        assert_eq!(teal.centroid, get_screen_registry().get("teal").unwrap().centroid);
+    }
+}
+
+#[test]
+fn test_lookup_phrase() {
+    let c = classifier();
+
+    let lookup = c.lookup_phrase("Dark  Grayish-Blue").unwrap();
+    assert_eq!(lookup.phrase, "dark grayish blue");
+    assert!(lookup.is_exact());
+    assert_eq!(lookup.iscc_colors, vec![187]);
+    assert_eq!(c.lookup_phrase("vivid reddish orange").unwrap().iscc_colors, vec![34]);
+    assert_eq!(c.lookup_phrase("brownish pink").unwrap().iscc_colors, vec![33]);
+
+    // Overlay names combine with ISCC-NBS modifiers
+    let teal = c.lookup_phrase("light teal").unwrap();
+    assert_eq!(teal.iscc_colors, vec![172]);
+    assert!(teal.overlays.contains(&PhraseOverlay {
+        name: "teal".to_string(),
+        modifier: ColorModifier::Light,
+    }));
+
+    // Unknown words are reported and the rest of the phrase is used
+    let partial = c.lookup_phrase("light sky teal").unwrap();
+    assert_eq!(partial.matched, "light teal");
+    assert_eq!(partial.unrecognized, vec!["sky".to_string()]);
+    assert!(!partial.is_exact());
+
+    assert_eq!(c.lookup_phrase("grey").unwrap().matched, "gray");
+
+    let unknown = c.lookup_phrase("xyz").unwrap();
+    assert!(unknown.is_empty());
+    assert!(unknown.munsell.is_none());
+}
+
+#[test]
+fn test_lookup_phrase_representative_matches() {
+    let c = classifier();
+    for phrase in ["dark grayish blue", "light teal", "very dark red", "navy"] {
+        let lookup = c.lookup_phrase(phrase).unwrap();
+        let rgb = lookup.srgb.unwrap();
+        assert!(c.srgb_matches_phrase(rgb, &lookup).unwrap(), "{phrase}: {rgb:?}");
+    }
+
+    let lookup = c.lookup_phrase("dark grayish blue").unwrap();
+    assert!(!c.srgb_matches_phrase([255, 0, 0], &lookup).unwrap());
+}
+
+#[test]
+fn test_shared_cache_matches_uncached_results() {
+    let plain = classifier();
+    let cache = UnifiedColorCache::new();
+    let cached = ColorClassifier::new().unwrap().with_cache(cache.clone());
+
+    let colors = [[180, 80, 60], [0, 0, 128], [128, 128, 128], [255, 215, 0]];
+    for _ in 0..2 {
+        for rgb in colors {
+            let expected = plain.characterize_srgb(rgb).unwrap();
+            let actual = cached.characterize_srgb(rgb).unwrap();
+            assert_eq!(actual.iscc_nbs_number, expected.iscc_nbs_number);
+            assert_eq!(actual.semantic_matches, expected.semantic_matches);
+            assert_eq!(
+                cached.classify_srgb(rgb).unwrap().standard_descriptor(),
+                plain.classify_srgb(rgb).unwrap().standard_descriptor()
+            );
+        }
+    }
+
+    let stats = cached.cache_stats().unwrap();
+    assert_eq!(stats.misses, colors.len() as u64);
+    assert_eq!(stats.hits, 3 * colors.len() as u64);
+    assert_eq!(stats.current_size, colors.len());
+
+    // A converter sharing the cache reuses the classifier's conversions
+    let converter = MunsellConverter::new().unwrap().with_cache(cache.clone());
+    let munsell = converter.srgb_to_munsell([180, 80, 60]).unwrap();
+    assert_eq!(munsell, cache.get(&[180, 80, 60]).unwrap().munsell);
+    assert_eq!(converter.cache_stats().unwrap().misses, stats.misses);
+}
+
+#[test]
+fn test_shared_cache_separates_naming_settings() {
+    use crate::OverlayContext;
+
+    let cache = UnifiedColorCache::new();
+    let physical = ColorClassifier::new().unwrap().with_cache(cache.clone());
+    let screen = ColorClassifier::new()
+        .unwrap()
+        .with_overlay_context(OverlayContext::Screen)
+        .with_cache(cache.clone());
+    let gold = [255, 215, 0];
+
+    physical.characterize_srgb(gold).unwrap();
+    let matches = screen.semantic_matches(gold).unwrap();
+    assert!(matches.iter().any(|name| name == "gold"));
+    assert_eq!(
+        screen.characterize_srgb(gold).unwrap().semantic_matches,
+        ColorClassifier::new()
+            .unwrap()
+            .with_overlay_context(OverlayContext::Screen)
+            .characterize_srgb(gold)
+            .unwrap()
+            .semantic_matches
+    );
+
+    // Classifiers with default settings share characterizations
+    let other = ColorClassifier::new().unwrap().with_cache(cache.clone());
+    let hits = cache.stats().hits;
+    other.characterize_srgb(gold).unwrap();
+    assert_eq!(cache.stats().hits, hits + 1);
+
+    // So do classifiers with equal custom settings, but not different ones
+    let warm = |threshold| {
+        let config = TemperatureConfig { warm_threshold: threshold, ..TemperatureConfig::default() };
+        ColorClassifier::new().unwrap().with_temperature_config(config).with_cache(cache.clone())
+    };
+    warm(0.4).characterize_srgb(gold).unwrap();
+    let hits = cache.stats().hits;
+    warm(0.4).characterize_srgb(gold).unwrap();
+    warm(0.5).characterize_srgb(gold).unwrap();
+    assert_eq!(cache.stats().hits, hits + 1);
+    assert_eq!(warm(0.4).cache_profile(), warm(0.4).cache_profile());
+    assert!(warm(0.4).cache_profile() >= 2);
+}
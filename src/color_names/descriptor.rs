@@ -5,6 +5,7 @@
 //! - Extended/alternate names
 //! - Semantic overlay names (Centore 2020)
 
+use super::characterization::ColorCharacterization;
 use super::modifier::ColorModifier;
 
 /// Complete color naming information for any color point.
@@ -21,6 +22,7 @@ use super::modifier::ColorModifier;
 /// // This shows the structure:
 /// let desc = ColorDescriptor {
 ///     iscc_nbs_number: 15,
+///     ambiguous: false,
 ///     modifier: ColorModifier::Moderate,
 ///     standard_name: "red".to_string(),
 ///     extended_name: "red".to_string(),
@@ -39,6 +41,9 @@ pub struct ColorDescriptor {
     /// ISCC-NBS category number (1-267)
     pub iscc_nbs_number: u16,
 
+    /// Whether several ISCC-NBS categories claimed the color
+    pub ambiguous: bool,
+
     // ─── Modifier (shared across ALL naming systems) ───
     /// The color modifier (e.g., Vivid, Pale, Dark)
     pub modifier: ColorModifier,
@@ -79,6 +84,7 @@ impl ColorDescriptor {
     ///
     /// let desc = ColorDescriptor {
     ///     iscc_nbs_number: 11,
+    ///     ambiguous: false,
     ///     modifier: ColorModifier::Vivid,
     ///     standard_name: "red".to_string(),
     ///     extended_name: "red".to_string(),
@@ -106,6 +112,7 @@ impl ColorDescriptor {
     ///
     /// let desc = ColorDescriptor {
     ///     iscc_nbs_number: 115,
+    ///     ambiguous: false,
     ///     modifier: ColorModifier::Vivid,
     ///     standard_name: "yellow green".to_string(),
     ///     extended_name: "lime".to_string(),
@@ -133,6 +140,7 @@ impl ColorDescriptor {
     ///
     /// let desc = ColorDescriptor {
     ///     iscc_nbs_number: 43,
+    ///     ambiguous: false,
     ///     modifier: ColorModifier::Moderate,
     ///     standard_name: "reddish brown".to_string(),
     ///     extended_name: "reddish brown".to_string(),
@@ -194,6 +202,25 @@ impl ColorDescriptor {
     }
 }
 
+impl From<ColorCharacterization> for ColorDescriptor {
+    fn from(char: ColorCharacterization) -> Self {
+        let mut semantic_matches = char.semantic_matches.into_iter();
+        let semantic_name = semantic_matches.next();
+
+        Self {
+            iscc_nbs_number: char.iscc_nbs_number,
+            ambiguous: char.ambiguous,
+            modifier: char.modifier,
+            standard_name: char.iscc_base_color,
+            extended_name: char.iscc_extended_name,
+            semantic_name,
+            semantic_alternates: semantic_matches.collect(),
+            nearest_semantic: char.nearest_semantic,
+            shade: char.shade,
+        }
+    }
+}
+
 impl std::fmt::Display for ColorDescriptor {
     /// Displays the standard ISCC-NBS descriptor.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -208,6 +235,7 @@ mod tests {
     fn sample_descriptor() -> ColorDescriptor {
         ColorDescriptor {
             iscc_nbs_number: 43,
+            ambiguous: false,
             modifier: ColorModifier::Moderate,
             standard_name: "reddish brown".to_string(),
             extended_name: "reddish brown".to_string(),
@@ -312,6 +340,7 @@ mod tests {
     fn test_compound_modifier_in_descriptor() {
         let desc = ColorDescriptor {
             iscc_nbs_number: 22,
+            ambiguous: false,
             modifier: ColorModifier::IshGray,
             standard_name: "red".to_string(),
             extended_name: "red".to_string(),
@@ -328,6 +357,7 @@ mod tests {
     fn test_no_modifier() {
         let desc = ColorDescriptor {
             iscc_nbs_number: 263,
+            ambiguous: false,
             modifier: ColorModifier::None,
             standard_name: "white".to_string(),
             extended_name: "white".to_string(),
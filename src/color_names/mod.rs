@@ -73,6 +73,7 @@ mod characterization;
 mod classifier;
 mod descriptor;
 mod modifier;
+mod phrase;
 mod registry;
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -84,6 +85,7 @@ pub use characterization::{BaseColorSet, ColorCharacterization, FormatOptions, O
 pub use classifier::ColorClassifier;
 pub use descriptor::ColorDescriptor;
 pub use modifier::ColorModifier;
+pub use phrase::{PhraseLookup, PhraseOverlay};
 
 // Registry functions (only base names, not internal -ish forms)
 pub use registry::{color_name_count, is_known_color, known_color_names};
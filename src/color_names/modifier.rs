@@ -80,6 +80,33 @@ pub enum ColorModifier {
 }
 
 impl ColorModifier {
+    /// Every modifier, in declaration order.
+    pub const ALL: [ColorModifier; 23] = [
+        Self::Vivid,
+        Self::Brilliant,
+        Self::Strong,
+        Self::Deep,
+        Self::VeryDeep,
+        Self::Light,
+        Self::Moderate,
+        Self::Medium,
+        Self::Dark,
+        Self::VeryDark,
+        Self::VeryLight,
+        Self::Pale,
+        Self::VeryPale,
+        Self::Grayish,
+        Self::DarkGrayish,
+        Self::Blackish,
+        Self::Brownish,
+        Self::IshWhite,
+        Self::IshGray,
+        Self::IshBlack,
+        Self::LightIshGray,
+        Self::DarkIshGray,
+        Self::None,
+    ];
+
     /// Parse a ColorModifier from an ISCC-NBS formatter string.
     ///
     /// The formatter string comes from the ISCC-NBS data and contains
@@ -94,40 +121,59 @@ impl ColorModifier {
     /// assert_eq!(ColorModifier::from_formatter("{1} gray"), ColorModifier::IshGray);
     /// ```
     pub fn from_formatter(formatter: &str) -> Self {
-        match formatter {
+        Self::ALL
+            .into_iter()
+            .find(|modifier| modifier.formatter() == formatter)
+            .unwrap_or(Self::None)
+    }
+
+    /// The ISCC-NBS formatter string for this modifier.
+    ///
+    /// This is the inverse of [`from_formatter`](Self::from_formatter).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use munsellspace::color_names::ColorModifier;
+    ///
+    /// assert_eq!(ColorModifier::Vivid.formatter(), "vivid {0}");
+    /// assert_eq!(ColorModifier::IshGray.formatter(), "{1} gray");
+    /// assert_eq!(ColorModifier::None.formatter(), "{0}");
+    /// ```
+    pub fn formatter(&self) -> &'static str {
+        match self {
             // High saturation
-            "vivid {0}" => Self::Vivid,
-            "brilliant {0}" => Self::Brilliant,
-            "strong {0}" => Self::Strong,
-            "deep {0}" => Self::Deep,
-            "very deep {0}" => Self::VeryDeep,
+            Self::Vivid => "vivid {0}",
+            Self::Brilliant => "brilliant {0}",
+            Self::Strong => "strong {0}",
+            Self::Deep => "deep {0}",
+            Self::VeryDeep => "very deep {0}",
 
             // Medium
-            "light {0}" => Self::Light,
-            "moderate {0}" => Self::Moderate,
-            "medium {0}" => Self::Medium,
-            "dark {0}" => Self::Dark,
-            "very dark {0}" => Self::VeryDark,
-            "very light {0}" => Self::VeryLight,
+            Self::Light => "light {0}",
+            Self::Moderate => "moderate {0}",
+            Self::Medium => "medium {0}",
+            Self::Dark => "dark {0}",
+            Self::VeryDark => "very dark {0}",
+            Self::VeryLight => "very light {0}",
 
             // Low saturation
-            "pale {0}" => Self::Pale,
-            "very pale {0}" => Self::VeryPale,
-            "grayish {0}" => Self::Grayish,
-            "dark grayish {0}" => Self::DarkGrayish,
-            "blackish {0}" => Self::Blackish,
-            "brownish {0}" => Self::Brownish,
+            Self::Pale => "pale {0}",
+            Self::VeryPale => "very pale {0}",
+            Self::Grayish => "grayish {0}",
+            Self::DarkGrayish => "dark grayish {0}",
+            Self::Blackish => "blackish {0}",
+            Self::Brownish => "brownish {0}",
 
             // Compound (use -ish form)
-            "{1} white" => Self::IshWhite,
-            "{1} gray" => Self::IshGray,
-            "{1} black" => Self::IshBlack,
-            "light {1} gray" => Self::LightIshGray,
-            "dark {1} gray" => Self::DarkIshGray,
-
-            // No modifier or unrecognized
-            "{0}" | "" => Self::None,
-            _ => Self::None,
+            Self::IshWhite => "{1} white",
+            Self::IshGray => "{1} gray",
+            Self::IshBlack => "{1} black",
+            Self::LightIshGray => "light {1} gray",
+            Self::DarkIshGray => "dark {1} gray",
+
+            // No modifier
+            Self::None => "{0}",
         }
     }
 
@@ -345,6 +391,13 @@ mod tests {
         assert_eq!(ColorModifier::from_formatter(""), ColorModifier::None);
     }
 
+    #[test]
+    fn test_formatter_round_trip() {
+        for modifier in ColorModifier::ALL {
+            assert_eq!(ColorModifier::from_formatter(modifier.formatter()), modifier);
+        }
+    }
+
     #[test]
     fn test_as_str() {
         assert_eq!(ColorModifier::Vivid.as_str(), "vivid");
@@ -0,0 +1,488 @@
+//! Lookup of descriptive color phrases
+//!
+//! Turns phrases such as "dark grayish blue", "vivid reddish orange",
+//! "light teal" or "brownish pink" into the ISCC-NBS categories and semantic
+//! overlay regions they name. A phrase is matched against the same
+//! vocabulary the classifier uses to describe colors:
+//!
+//! - ISCC-NBS descriptors, standard and extended ("light greenish blue",
+//!   "light teal"), including the `-ish` compound forms ("reddish gray")
+//! - An overlay name with any ISCC-NBS modifier ("light teal", "rosy gray"),
+//!   which names the colors inside the overlay carrying that modifier
+//! - A bare ISCC-NBS color name ("reddish orange"), which names every
+//!   category of that color
+//!
+//! Words that do not fit any of these are reported as unrecognized, and the
+//! longest recognizable part of the phrase is used.
+
+use std::collections::HashSet;
+
+use geo::{BoundingRect, Intersects, Point};
+
+use crate::constants::{construct_overlay_descriptor, ACHROMATIC_BOUNDARIES};
+use crate::error::Result;
+use crate::iscc::ColorMetadata;
+use crate::reverse_conversion::ReverseConverter;
+use crate::semantic_overlay::{
+    hue_number_to_string, parse_hue_to_number, MunsellCartesian, MunsellSpec,
+};
+
+use super::characterization::ColorCharacterization;
+use super::classifier::ColorClassifier;
+use super::modifier::ColorModifier;
+
+/// Grid spacing, in Munsell units, used to sample named regions.
+const SAMPLE_STEP: f64 = 0.5;
+
+/// Highest chroma sampled in open-ended ISCC-NBS polygons.
+const MAX_SAMPLE_CHROMA: f64 = 20.0;
+
+/// Number of candidates tried when picking a representative color.
+const MAX_REPRESENTATIVE_CHECKS: usize = 32;
+
+/// An overlay region named by a phrase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhraseOverlay {
+    /// Overlay name as registered
+    pub name: String,
+    /// Modifier a color must carry to match; [`ColorModifier::None`] for the
+    /// whole region
+    pub modifier: ColorModifier,
+}
+
+/// Result of looking up a descriptive color phrase.
+///
+/// Created by [`ColorClassifier::lookup_phrase`].
+#[derive(Debug, Clone)]
+pub struct PhraseLookup {
+    /// Normalized phrase: lowercase, single-spaced, with "grey" spelled "gray"
+    pub phrase: String,
+    /// Part of the phrase used for the match (empty when nothing matched)
+    pub matched: String,
+    /// ISCC-NBS color numbers named by the phrase, ascending
+    pub iscc_colors: Vec<u16>,
+    /// Semantic overlay regions named by the phrase
+    pub overlays: Vec<PhraseOverlay>,
+    /// Words of the phrase that were not used
+    pub unrecognized: Vec<String>,
+    /// Representative Munsell color of the named region, chosen where
+    /// possible so that its sRGB rendering [`matches`](Self::matches)
+    pub munsell: Option<MunsellSpec>,
+    /// sRGB rendering of the representative color
+    pub srgb: Option<[u8; 3]>,
+}
+
+impl PhraseLookup {
+    /// Whether the phrase named no category or region.
+    pub fn is_empty(&self) -> bool {
+        self.iscc_colors.is_empty() && self.overlays.is_empty()
+    }
+
+    /// Whether the whole phrase was recognized.
+    pub fn is_exact(&self) -> bool {
+        !self.is_empty() && self.unrecognized.is_empty()
+    }
+
+    /// Test whether a characterized color is in the named region.
+    ///
+    /// A color matches if its ISCC-NBS category is one of
+    /// [`iscc_colors`](Self::iscc_colors), or if it lies inside one of the
+    /// [`overlays`](Self::overlays) with the required modifier.
+    pub fn matches(&self, color: &ColorCharacterization) -> bool {
+        if self.iscc_colors.contains(&color.iscc_nbs_number) {
+            return true;
+        }
+
+        self.overlays.iter().any(|overlay| {
+            color
+                .semantic_matches
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&overlay.name))
+                && (overlay.modifier == ColorModifier::None || overlay.modifier == color.modifier)
+        })
+    }
+}
+
+impl ColorClassifier {
+    /// Look up a descriptive color phrase.
+    ///
+    /// Returns the ISCC-NBS categories and overlay regions named by the phrase,
+    /// a representative Munsell and sRGB color, and the words that could not
+    /// be used. Overlays come from the classifier's
+    /// [`overlay_registry`](Self::overlay_registry), so custom overlays and
+    /// the overlay context apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reverse converter cannot be created.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use munsellspace::color_names::ColorClassifier;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = ColorClassifier::new()?;
+    ///
+    /// let lookup = classifier.lookup_phrase("dark grayish blue")?;
+    /// assert_eq!(lookup.iscc_colors, vec![187]);
+    /// assert!(lookup.srgb.is_some());
+    ///
+    /// let lookup = classifier.lookup_phrase("light sky teal")?;
+    /// assert_eq!(lookup.matched, "light teal");
+    /// assert_eq!(lookup.unrecognized, vec!["sky"]);
+    /// assert!(lookup.overlays.iter().any(|o| o.name == "teal"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lookup_phrase(&self, phrase: &str) -> Result<PhraseLookup> {
+        let tokens = normalize(phrase);
+        let mut lookup = PhraseLookup {
+            phrase: tokens.join(" "),
+            matched: String::new(),
+            iscc_colors: Vec::new(),
+            overlays: Vec::new(),
+            unrecognized: Vec::new(),
+            munsell: None,
+            srgb: None,
+        };
+
+        let Some((start, end)) = self.longest_match(&tokens) else {
+            lookup.unrecognized = tokens;
+            return Ok(lookup);
+        };
+
+        // A modifier elsewhere before the head may still apply to it
+        let mut head = tokens[start..end].join(" ");
+        let mut used = vec![false; tokens.len()];
+        used[start..end].fill(true);
+        if let Some(modifier) = modifier_before(&tokens[..start]) {
+            let modified = format!("{} {}", tokens[modifier.clone()].join(" "), head);
+            if !self.match_exact(&modified).is_empty() {
+                head = modified;
+                used[modifier].fill(true);
+            }
+        }
+        lookup.unrecognized = tokens
+            .iter()
+            .zip(&used)
+            .filter(|(_, used)| !**used)
+            .map(|(token, _)| token.clone())
+            .collect();
+
+        let (iscc_colors, overlays) = self.match_exact(&head).into_parts();
+        lookup.matched = head;
+        lookup.iscc_colors = iscc_colors;
+        lookup.overlays = overlays;
+
+        // Use the first candidate whose sRGB rendering classifies back into
+        // the named region
+        let reverse = ReverseConverter::new()?;
+        let candidates =
+            self.representative_candidates(&lookup.iscc_colors, &lookup.overlays, &reverse);
+        let chosen = candidates
+            .iter()
+            .take(MAX_REPRESENTATIVE_CHECKS)
+            .map(|spec| (*spec, spec.display_srgb(&reverse)))
+            .find(|(_, rgb)| self.srgb_matches_phrase(*rgb, &lookup).unwrap_or(false))
+            .or_else(|| candidates.first().map(|spec| (*spec, spec.display_srgb(&reverse))));
+        if let Some((spec, rgb)) = chosen {
+            lookup.munsell = Some(spec);
+            lookup.srgb = Some(rgb);
+        }
+
+        Ok(lookup)
+    }
+
+    /// Test whether an sRGB color is in the region named by a phrase lookup.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use munsellspace::color_names::ColorClassifier;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = ColorClassifier::new()?;
+    /// let lookup = classifier.lookup_phrase("vivid reddish orange")?;
+    /// assert!(classifier.srgb_matches_phrase(lookup.srgb.unwrap(), &lookup)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn srgb_matches_phrase(&self, rgb: [u8; 3], lookup: &PhraseLookup) -> Result<bool> {
+        Ok(lookup.matches(&self.characterize_srgb(rgb)?))
+    }
+
+    /// Internal: find the longest (then rightmost) token span naming something.
+    fn longest_match(&self, tokens: &[String]) -> Option<(usize, usize)> {
+        for len in (1..=tokens.len()).rev() {
+            for start in (0..=tokens.len() - len).rev() {
+                let span = tokens[start..start + len].join(" ");
+                if !self.match_exact(&span).is_empty() {
+                    return Some((start, start + len));
+                }
+            }
+        }
+        None
+    }
+
+    /// Internal: categories and regions named exactly by a normalized phrase.
+    fn match_exact(&self, phrase: &str) -> Matches {
+        let mut matches = Matches::default();
+
+        for (&number, metadata) in &self.iscc.color_metadata {
+            if names_iscc_color(metadata, phrase) {
+                matches.iscc_colors.push(number);
+            }
+        }
+        matches.iscc_colors.sort_unstable();
+
+        for overlay in self.overlay_registry().all() {
+            let name = overlay.name.to_lowercase();
+            for modifier in ColorModifier::ALL {
+                if construct_overlay_descriptor(modifier.formatter(), &name) == phrase
+                    || modifier.format(&name) == phrase
+                {
+                    matches.overlays.push(PhraseOverlay {
+                        name: overlay.name.clone(),
+                        modifier,
+                    });
+                    break;
+                }
+            }
+        }
+
+        matches
+    }
+
+    /// Internal: candidate representative colors, best first.
+    ///
+    /// The region sampled is an unmodified overlay (centered on its focal
+    /// color), else a single ISCC-NBS category, else a modified overlay, else
+    /// all named categories. Samples outside the renotation data are dropped
+    /// and the rest are ordered by distance from the center of the region.
+    fn representative_candidates(
+        &self,
+        iscc_colors: &[u16],
+        overlays: &[PhraseOverlay],
+        reverse: &ReverseConverter,
+    ) -> Vec<MunsellSpec> {
+        let registry = self.overlay_registry();
+        let unmodified = overlays
+            .iter()
+            .find(|o| o.modifier == ColorModifier::None)
+            .and_then(|o| registry.get(&o.name));
+
+        let (focal, mut samples) = if let Some(overlay) = unmodified {
+            (Some(overlay.centroid), self.overlay_samples(&overlay.name, None))
+        } else if let [number] = iscc_colors {
+            (None, self.iscc_samples(*number))
+        } else if let Some(samples) = overlays
+            .iter()
+            .map(|o| self.overlay_samples(&o.name, Some(o.modifier)))
+            .find(|s| !s.is_empty())
+        {
+            (None, samples)
+        } else {
+            (None, iscc_colors.iter().flat_map(|&n| self.iscc_samples(n)).collect())
+        };
+
+        samples.retain(|spec| spec.to_srgb(reverse).is_some());
+        let Some(center) = focal.or_else(|| nearest_to_mean(&samples)) else {
+            return Vec::new();
+        };
+
+        let center_point = center.to_cartesian();
+        samples.sort_by(|a, b| {
+            a.to_cartesian()
+                .distance(&center_point)
+                .total_cmp(&b.to_cartesian().distance(&center_point))
+        });
+        samples.insert(0, center);
+        samples
+    }
+
+    /// Internal: grid samples of an ISCC-NBS category on the middle hue of
+    /// each of its polygons.
+    fn iscc_samples(&self, color_number: u16) -> Vec<MunsellSpec> {
+        let mut samples = Vec::new();
+
+        if let Some(&(lower, upper, _, _)) = ACHROMATIC_BOUNDARIES
+            .iter()
+            .find(|(_, _, number, _)| *number == color_number)
+        {
+            let mut value = lower + SAMPLE_STEP / 2.0;
+            while value < upper {
+                samples.push(MunsellSpec::neutral(value));
+                value += SAMPLE_STEP;
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let polygons = self
+            .iscc
+            .wedge_system
+            .wedge_containers
+            .values()
+            .flatten()
+            .filter(|p| p.color_number == color_number)
+            .filter(|p| seen.insert((p.polygon_group, p.hue_range.clone())));
+
+        for polygon in polygons {
+            let (Some(start), Some(end)) = (
+                parse_hue_to_number(&polygon.hue_range.0),
+                parse_hue_to_number(&polygon.hue_range.1),
+            ) else {
+                continue;
+            };
+            let hue = (start + (end - start).rem_euclid(40.0) / 2.0).rem_euclid(40.0);
+            let Some(bounds) = polygon.polygon.bounding_rect() else {
+                continue;
+            };
+
+            // x is chroma and y is value in the polygon plane
+            let mut chroma = bounds.min().x.max(SAMPLE_STEP);
+            while chroma <= bounds.max().x.min(MAX_SAMPLE_CHROMA) {
+                let mut value = bounds.min().y;
+                while value <= bounds.max().y {
+                    if polygon.polygon.intersects(&Point::new(chroma, value)) {
+                        samples.push(MunsellSpec::new(hue, value, chroma));
+                    }
+                    value += SAMPLE_STEP;
+                }
+                chroma += 2.0 * SAMPLE_STEP;
+            }
+        }
+
+        samples
+    }
+
+    /// Internal: grid samples inside an overlay, optionally restricted to the
+    /// colors carrying an ISCC-NBS modifier.
+    fn overlay_samples(&self, name: &str, modifier: Option<ColorModifier>) -> Vec<MunsellSpec> {
+        let Some(overlay) = self.overlay_registry().get(name) else {
+            return Vec::new();
+        };
+        let Some((min, max)) = overlay.polyhedron.bounding_box() else {
+            return Vec::new();
+        };
+
+        let steps = |lo: f64, hi: f64| ((hi - lo) / SAMPLE_STEP).floor() as usize;
+        let mut samples = Vec::new();
+        for i in 0..=steps(min.x, max.x) {
+            for j in 0..=steps(min.y, max.y) {
+                for k in 0..=steps(min.z, max.z) {
+                    let point = MunsellCartesian::new(
+                        min.x + i as f64 * SAMPLE_STEP,
+                        min.y + j as f64 * SAMPLE_STEP,
+                        min.z + k as f64 * SAMPLE_STEP,
+                    );
+                    if !overlay.polyhedron.contains_point(&point) {
+                        continue;
+                    }
+                    let spec = MunsellSpec::from_cartesian(&point);
+                    if modifier.is_none() || self.iscc_modifier(&spec) == modifier {
+                        samples.push(spec);
+                    }
+                }
+            }
+        }
+
+        samples
+    }
+
+    /// Internal: ISCC-NBS modifier of a chromatic Munsell color.
+    fn iscc_modifier(&self, spec: &MunsellSpec) -> Option<ColorModifier> {
+        let (hue, _) = hue_number_to_string(spec.hue_number);
+        let (_, metadata) = self
+            .iscc
+            .classify_resolved_metadata(&hue, spec.value, spec.chroma)
+            .ok()?;
+        let formatter = metadata?.iscc_nbs_formatter?;
+        Some(ColorModifier::from_formatter(&formatter))
+    }
+}
+
+/// Categories and regions named by a phrase.
+#[derive(Debug, Default)]
+struct Matches {
+    iscc_colors: Vec<u16>,
+    overlays: Vec<PhraseOverlay>,
+}
+
+impl Matches {
+    fn is_empty(&self) -> bool {
+        self.iscc_colors.is_empty() && self.overlays.is_empty()
+    }
+
+    fn into_parts(self) -> (Vec<u16>, Vec<PhraseOverlay>) {
+        (self.iscc_colors, self.overlays)
+    }
+}
+
+/// Lowercase, split on whitespace, hyphens and commas, and unify spelling.
+fn normalize(phrase: &str) -> Vec<String> {
+    phrase
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '-' || c == ',' || c == '_')
+        .filter(|t| !t.is_empty())
+        .map(|t| match t {
+            "grey" => "gray".to_string(),
+            "greyish" => "grayish".to_string(),
+            other => other.to_string(),
+        })
+        .collect()
+}
+
+/// Whether a phrase is a descriptor or a bare color name of a category.
+///
+/// Bare names exclude the `-ish` compound categories: "pink" names the pinks,
+/// not "pinkish white".
+fn names_iscc_color(metadata: &ColorMetadata, phrase: &str) -> bool {
+    if metadata.iscc_nbs_descriptor() == phrase || metadata.alt_color_descriptor() == phrase {
+        return true;
+    }
+    let compound = metadata
+        .iscc_nbs_formatter
+        .as_deref()
+        .is_some_and(|f| ColorModifier::from_formatter(f).is_compound());
+    !compound && (metadata.iscc_nbs_color_name == phrase || metadata.alt_color_name == phrase)
+}
+
+/// Span of the simple modifier closest to the end of `tokens`, if any.
+fn modifier_before(tokens: &[String]) -> Option<std::ops::Range<usize>> {
+    let is_modifier = |words: &[String]| {
+        let candidate = format!("{} {{0}}", words.join(" "));
+        ColorModifier::from_formatter(&candidate) != ColorModifier::None
+    };
+
+    (0..tokens.len()).rev().find_map(|end| {
+        // Prefer two-word modifiers such as "very dark"
+        if end >= 1 && is_modifier(&tokens[end - 1..=end]) {
+            Some(end - 1..end + 1)
+        } else if is_modifier(&tokens[end..=end]) {
+            Some(end..end + 1)
+        } else {
+            None
+        }
+    })
+}
+
+/// The color closest to the mean of a set, in Munsell Cartesian space.
+fn nearest_to_mean(specs: &[MunsellSpec]) -> Option<MunsellSpec> {
+    if specs.is_empty() {
+        return None;
+    }
+    let points: Vec<MunsellCartesian> = specs.iter().map(MunsellSpec::to_cartesian).collect();
+    let n = points.len() as f64;
+    let mean = MunsellCartesian::new(
+        points.iter().map(|p| p.x).sum::<f64>() / n,
+        points.iter().map(|p| p.y).sum::<f64>() / n,
+        points.iter().map(|p| p.z).sum::<f64>() / n,
+    );
+
+    specs
+        .iter()
+        .zip(&points)
+        .min_by(|a, b| a.1.distance(&mean).total_cmp(&b.1.distance(&mean)))
+        .map(|(spec, _)| *spec)
+}
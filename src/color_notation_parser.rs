@@ -2,6 +2,7 @@
 //! Line-by-line port with exact behavior matching
 
 use crate::error::{MunsellError, Result};
+use crate::types::notation::GleyNotation;
 use regex::Regex;
 use std::collections::HashMap;
 use lazy_static::lazy_static;
@@ -65,6 +66,11 @@ lazy_static! {
 /// 
 /// Returns [hue, value, chroma, code] where NaN indicates grey/neutral
 pub fn parse_munsell_colour(munsell_colour: &str) -> Result<[f64; 4]> {
+    // Not in Python: soil chart gley notation ("GLEY1 5/10Y") names an ordinary chip
+    if let Some(gley) = GleyNotation::parse(munsell_colour)? {
+        return parse_munsell_colour(&gley.to_munsell_notation());
+    }
+
     // Python line: match = re.match(MUNSELL_GRAY_PATTERN, munsell_colour, flags=re.IGNORECASE)
     if let Some(captures) = MUNSELL_GRAY_PATTERN.captures(munsell_colour) {
         // Python: return tstack([np.nan, match.group("value"), np.nan, np.nan])
@@ -1,9 +1,33 @@
 //! Color space transformation functions (sRGB, XYZ, xyY, Lab, LCHab).
 
-use crate::constants::{BRADFORD_MATRIX, BRADFORD_MATRIX_INV, ILLUMINANT_D65_XYZ, ILLUMINANT_C_XYZ};
+use crate::constants::{
+    BRADFORD_MATRIX, BRADFORD_MATRIX_INV, ILLUMINANT_D65_XYZ, ILLUMINANT_C_XYZ,
+    SRGB_GAMMA_EXPONENT, SRGB_GAMMA_OFFSET, SRGB_GAMMA_SCALE, SRGB_GAMMA_THRESHOLD,
+    SRGB_LINEAR_FACTOR,
+};
 
 use super::MunsellConverter;
 
+/// Decode a gamma-encoded sRGB component in `[0, 1]` to linear light.
+#[inline]
+pub(crate) fn srgb_to_linear(c: f64) -> f64 {
+    if c <= SRGB_GAMMA_THRESHOLD {
+        c / SRGB_LINEAR_FACTOR
+    } else {
+        ((c + SRGB_GAMMA_OFFSET) / SRGB_GAMMA_SCALE).powf(SRGB_GAMMA_EXPONENT)
+    }
+}
+
+/// Encode linear light in `[0, 1]` as a gamma-encoded sRGB component.
+#[inline]
+pub(crate) fn linear_to_srgb(c: f64) -> f64 {
+    if c <= SRGB_GAMMA_THRESHOLD / SRGB_LINEAR_FACTOR {
+        c * SRGB_LINEAR_FACTOR
+    } else {
+        SRGB_GAMMA_SCALE * c.powf(1.0 / SRGB_GAMMA_EXPONENT) - SRGB_GAMMA_OFFSET
+    }
+}
+
 impl MunsellConverter {
     /// Apply sRGB gamma correction to convert to linear RGB.
     #[inline]
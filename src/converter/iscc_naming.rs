@@ -1,7 +1,7 @@
 //! Deprecated ISCC-NBS naming methods on MunsellConverter.
 //!
 //! These methods delegate to `IsccNbsClassifier` / `ColorClassifier` and will be
-//! removed in v2.0.0.
+//! removed in v3.0.0.
 
 use crate::error::{MunsellError, Result};
 use crate::types::{MunsellColor, IsccNbsName, IsccNbsPolygon};
@@ -23,7 +23,7 @@ impl MunsellConverter {
     /// for unified color naming including semantic overlays.
     #[deprecated(
         since = "1.2.3",
-        note = "Use ColorClassifier::classify_srgb() for unified color naming. This method will be removed in v2.0.0."
+        note = "Use ColorClassifier::classify_srgb() for unified color naming. This method will be removed in v3.0.0."
     )]
     pub fn srgb_to_color_name(&self, rgb: [u8; 3]) -> Result<IsccNbsName> {
         let munsell = self.srgb_to_munsell(rgb)?;
@@ -38,7 +38,7 @@ impl MunsellConverter {
     /// for unified color naming including semantic overlays.
     #[deprecated(
         since = "1.2.3",
-        note = "Use ColorClassifier::classify_srgb() for unified color naming. This method will be removed in v2.0.0."
+        note = "Use ColorClassifier::classify_srgb() for unified color naming. This method will be removed in v3.0.0."
     )]
     pub fn munsell_to_iscc_nbs_name(&self, munsell: &MunsellColor) -> Result<IsccNbsName> {
         use crate::IsccNbsClassifier;
@@ -73,7 +73,7 @@ impl MunsellConverter {
     /// for ISCC-NBS classification.
     #[deprecated(
         since = "1.2.3",
-        note = "Always returns empty. Use ColorClassifier for ISCC-NBS classification. Will be removed in v2.0.0."
+        note = "Always returns empty. Use ColorClassifier for ISCC-NBS classification. Will be removed in v3.0.0."
     )]
     pub fn get_iscc_nbs_polygons(&self) -> &[IsccNbsPolygon] {
         &self.iscc_nbs_polygons
@@ -82,11 +82,12 @@ impl MunsellConverter {
     /// Find ISCC-NBS color by name or partial match.
     ///
     /// # Deprecated
-    /// This method always returns empty results. Use [`ColorClassifier`](crate::ColorClassifier)
-    /// for ISCC-NBS classification.
+    /// This method always returns empty results. Use
+    /// [`ColorClassifier::lookup_phrase`](crate::ColorClassifier::lookup_phrase) to look up colors
+    /// by name.
     #[deprecated(
         since = "1.2.3",
-        note = "Always returns empty. Use ColorClassifier for ISCC-NBS classification. Will be removed in v2.0.0."
+        note = "Always returns empty. Use ColorClassifier for ISCC-NBS classification. Will be removed in v3.0.0."
     )]
     pub fn find_colors_by_name(&self, query: &str) -> Vec<&IsccNbsPolygon> {
         let query_lower = query.to_lowercase();
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::Result;
 use crate::types::{MunsellColor, IsccNbsPolygon};
+use crate::unified_cache::{CacheStats, UnifiedColorCache};
 
 mod color_space;
 mod munsell_notation;
@@ -17,6 +18,8 @@ mod iscc_naming;
 mod tests;
 
 use reference_data::{ReferenceEntry, MunsellReferencePoint};
+pub(crate) use color_space::{linear_to_srgb, srgb_to_linear};
+pub(crate) use reference_data::REFERENCE_CSV;
 
 /// High-precision sRGB to Munsell color space converter.
 ///
@@ -61,6 +64,8 @@ pub struct MunsellConverter {
     reference_points: Arc<Vec<MunsellReferencePoint>>,
     /// Phase 3: ISCC-NBS color naming polygons
     iscc_nbs_polygons: Arc<Vec<IsccNbsPolygon>>,
+    /// Optional shared cache of conversion results
+    cache: Option<UnifiedColorCache>,
 }
 
 impl MunsellConverter {
@@ -92,9 +97,45 @@ impl MunsellConverter {
             reference_map: Arc::new(reference_map),
             reference_points: Arc::new(reference_points),
             iscc_nbs_polygons: Arc::new(iscc_nbs_polygons),
+            cache: None,
         })
     }
 
+    /// Memoize conversions in a shared cache.
+    ///
+    /// The same cache can be given to several converters and classifiers,
+    /// including across threads; see [`ColorClassifier::with_cache`](crate::ColorClassifier::with_cache).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::{MunsellConverter, UnifiedColorCache};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let converter = MunsellConverter::new()?.with_cache(UnifiedColorCache::new());
+    /// let first = converter.srgb_to_munsell([120, 60, 200])?;
+    /// let second = converter.srgb_to_munsell([120, 60, 200])?;
+    /// assert_eq!(first, second);
+    ///
+    /// let stats = converter.cache_stats().unwrap();
+    /// assert_eq!((stats.hits, stats.misses), (1, 1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_cache(mut self, cache: UnifiedColorCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// The shared cache, if any.
+    pub fn cache(&self) -> Option<&UnifiedColorCache> {
+        self.cache.as_ref()
+    }
+
+    /// Statistics of the shared cache, if any.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(UnifiedColorCache::stats)
+    }
+
     /// Convert a single sRGB color to Munsell notation.
     ///
     /// Uses mathematical color space transformation algorithms reverse-engineered
@@ -130,7 +171,27 @@ impl MunsellConverter {
             return MunsellColor::from_notation(notation);
         }
 
-        // Step 2: Algorithmic conversion for non-reference colors
+        // Step 2: Results memoized in the shared cache
+        let Some(cache) = &self.cache else {
+            return self.algorithmic_srgb_to_munsell(rgb);
+        };
+        if let Some(munsell) = cache.get_munsell(&rgb) {
+            return Ok(munsell);
+        }
+
+        // Step 3: Algorithmic conversion for non-reference colors
+        let munsell = self.algorithmic_srgb_to_munsell(rgb)?;
+        cache.insert_munsell(rgb, munsell.clone());
+        Ok(munsell)
+    }
+
+    /// Internal: convert without consulting the shared cache, for callers
+    /// that have already looked the color up.
+    pub(crate) fn srgb_to_munsell_uncached(&self, rgb: [u8; 3]) -> Result<MunsellColor> {
+        self.validate_rgb(rgb)?;
+        if let Some(notation) = self.reference_map.get(&rgb) {
+            return MunsellColor::from_notation(notation);
+        }
         self.algorithmic_srgb_to_munsell(rgb)
     }
 
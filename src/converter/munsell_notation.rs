@@ -1,6 +1,6 @@
 //! Munsell notation calculation from color space coordinates.
 
-use crate::error::Result;
+use crate::error::{ErrorInput, PipelineStage, Result};
 use crate::types::MunsellColor;
 
 use super::MunsellConverter;
@@ -38,6 +38,7 @@ impl MunsellConverter {
 
         // Step 6: Convert xyY -> Munsell using sophisticated spatial interpolation
         self.xyy_to_munsell_iterative(xyy)
+            .map_err(|error| error.with_input(PipelineStage::Solver, ErrorInput::Rgb(rgb)))
     }
 
     /// Convert xyY to Munsell using scientific algorithms.
@@ -68,10 +68,13 @@ impl TempConverter {
     }
 }
 
+/// Embedded sRGB to Munsell reference dataset.
+pub(crate) const REFERENCE_CSV: &str = include_str!("../../tests/data/srgb-to-munsell.csv");
+
 impl MunsellConverter {
     /// Load reference data from embedded CSV dataset.
     pub(super) fn load_reference_data() -> Result<Vec<ReferenceEntry>> {
-        let csv_data = include_str!("../../tests/data/srgb-to-munsell.csv");
+        let csv_data = REFERENCE_CSV;
 
         let mut reference_data = Vec::new();
         let mut csv_reader = csv::Reader::from_reader(csv_data.as_bytes());
@@ -44,6 +44,9 @@ impl MunsellConverter {
     ///
     /// Bypasses the HashMap lookup and tests only the mathematical conversion
     /// pipeline, giving a true measurement of algorithmic accuracy.
+    ///
+    /// For error distributions, CIEDE2000 differences and worst cases, or to
+    /// validate against other datasets, see [`crate::validation`].
     pub fn validate_algorithmic_accuracy(&self) -> Result<AccuracyStats> {
         let mut exact_matches = 0;
         let mut close_matches = 0;
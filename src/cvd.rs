@@ -0,0 +1,484 @@
+//! Color vision deficiency simulation.
+//!
+//! Simulates how colors appear with protan, deutan and tritan deficiencies
+//! using the model of Machado, Oliveira and Fernandes (2009), "A
+//! Physiologically-based Model for Simulation of Color Vision Deficiency".
+//! Each deficiency is a 3x3 matrix applied to linear sRGB.
+//!
+//! Severity runs from 0 (normal vision) to 1 (dichromacy: protanopia,
+//! deuteranopia, tritanopia). Machado tabulates a matrix per 0.1 of severity;
+//! severities between two steps interpolate linearly between their matrices.
+//!
+//! [`ColorClassifier::characterize_srgb_cvd`] names a color as seen with the
+//! deficiency, and [`ColorClassifier::cvd_confusable_pairs`] flags palette
+//! colors that become hard to tell apart.
+//!
+//! # Example
+//!
+//! ```rust
+//! use munsellspace::cvd::CvdSimulation;
+//!
+//! let protanopia = CvdSimulation::protanopia();
+//! let red = [220, 30, 30];
+//! let seen = protanopia.simulate(red);
+//!
+//! // Red loses most of its red-green contrast
+//! assert!(seen[0].abs_diff(seen[1]) < red[0].abs_diff(red[1]));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::color_names::{ColorCharacterization, ColorClassifier, FormatOptions};
+use crate::converter::{linear_to_srgb, srgb_to_linear};
+use crate::error::Result;
+
+/// Severity steps in Machado's tables, one per 0.1.
+const SEVERITY_STEPS: usize = 10;
+
+/// Machado et al. (2009) protanomaly matrices on linear sRGB, for severity
+/// 0.0, 0.1, ..., 1.0. The last one is protanopia.
+const PROTAN: [[[f64; 3]; 3]; SEVERITY_STEPS + 1] = [
+    [
+        [1.000000, 0.000000, 0.000000],
+        [0.000000, 1.000000, 0.000000],
+        [0.000000, 0.000000, 1.000000],
+    ],
+    [
+        [0.856167, 0.182038, -0.038205],
+        [0.029342, 0.955115, 0.015544],
+        [-0.002880, -0.001563, 1.004443],
+    ],
+    [
+        [0.734766, 0.334872, -0.069637],
+        [0.051840, 0.919198, 0.028963],
+        [-0.004928, -0.004209, 1.009137],
+    ],
+    [
+        [0.630323, 0.465641, -0.095964],
+        [0.069181, 0.890046, 0.040773],
+        [-0.006308, -0.007724, 1.014032],
+    ],
+    [
+        [0.539009, 0.579343, -0.118352],
+        [0.082546, 0.866121, 0.051332],
+        [-0.007136, -0.011959, 1.019095],
+    ],
+    [
+        [0.458064, 0.679578, -0.137642],
+        [0.092785, 0.846313, 0.060902],
+        [-0.007494, -0.016807, 1.024301],
+    ],
+    [
+        [0.385450, 0.769005, -0.154455],
+        [0.100526, 0.829802, 0.069673],
+        [-0.007442, -0.022190, 1.029632],
+    ],
+    [
+        [0.319627, 0.849633, -0.169261],
+        [0.106241, 0.815969, 0.077790],
+        [-0.007025, -0.028051, 1.035076],
+    ],
+    [
+        [0.259411, 0.923008, -0.182420],
+        [0.110296, 0.804340, 0.085364],
+        [-0.006276, -0.034346, 1.040622],
+    ],
+    [
+        [0.203876, 0.990338, -0.194214],
+        [0.112975, 0.794542, 0.092483],
+        [-0.005222, -0.041043, 1.046265],
+    ],
+    [
+        [0.152286, 1.052583, -0.204868],
+        [0.114503, 0.786281, 0.099216],
+        [-0.003882, -0.048116, 1.051998],
+    ],
+];
+
+/// Machado et al. (2009) deuteranomaly matrices on linear sRGB, for severity
+/// 0.0, 0.1, ..., 1.0. The last one is deuteranopia.
+const DEUTAN: [[[f64; 3]; 3]; SEVERITY_STEPS + 1] = [
+    [
+        [1.000000, 0.000000, 0.000000],
+        [0.000000, 1.000000, 0.000000],
+        [0.000000, 0.000000, 1.000000],
+    ],
+    [
+        [0.866435, 0.177704, -0.044139],
+        [0.049567, 0.939063, 0.011370],
+        [-0.003453, 0.007233, 0.996220],
+    ],
+    [
+        [0.760729, 0.319078, -0.079807],
+        [0.090568, 0.889315, 0.020117],
+        [-0.006027, 0.013325, 0.992702],
+    ],
+    [
+        [0.675425, 0.433850, -0.109275],
+        [0.125303, 0.847755, 0.026942],
+        [-0.007950, 0.018572, 0.989378],
+    ],
+    [
+        [0.605511, 0.528560, -0.134071],
+        [0.155318, 0.812366, 0.032316],
+        [-0.009376, 0.023176, 0.986200],
+    ],
+    [
+        [0.547494, 0.607765, -0.155259],
+        [0.181692, 0.781742, 0.036566],
+        [-0.010410, 0.027275, 0.983136],
+    ],
+    [
+        [0.498864, 0.674741, -0.173604],
+        [0.205199, 0.754872, 0.039929],
+        [-0.011131, 0.030969, 0.980162],
+    ],
+    [
+        [0.457771, 0.731899, -0.189670],
+        [0.226409, 0.731012, 0.042579],
+        [-0.011595, 0.034333, 0.977261],
+    ],
+    [
+        [0.422823, 0.781057, -0.203881],
+        [0.245752, 0.709602, 0.044646],
+        [-0.011843, 0.037423, 0.974421],
+    ],
+    [
+        [0.392952, 0.823610, -0.216562],
+        [0.263559, 0.690210, 0.046232],
+        [-0.011910, 0.040281, 0.971630],
+    ],
+    [
+        [0.367322, 0.860646, -0.227968],
+        [0.280085, 0.672501, 0.047413],
+        [-0.011820, 0.042940, 0.968881],
+    ],
+];
+
+/// Machado et al. (2009) tritanomaly matrices on linear sRGB, for severity
+/// 0.0, 0.1, ..., 1.0. The last one is tritanopia.
+const TRITAN: [[[f64; 3]; 3]; SEVERITY_STEPS + 1] = [
+    [
+        [1.000000, 0.000000, 0.000000],
+        [0.000000, 1.000000, 0.000000],
+        [0.000000, 0.000000, 1.000000],
+    ],
+    [
+        [0.926670, 0.092514, -0.019184],
+        [0.021191, 0.964503, 0.014306],
+        [0.008437, 0.054813, 0.936750],
+    ],
+    [
+        [0.895720, 0.133330, -0.029050],
+        [0.029997, 0.945400, 0.024603],
+        [0.013027, 0.104707, 0.882266],
+    ],
+    [
+        [0.905871, 0.127791, -0.033662],
+        [0.026856, 0.941251, 0.031893],
+        [0.013410, 0.148296, 0.838294],
+    ],
+    [
+        [0.948035, 0.089490, -0.037526],
+        [0.014364, 0.946792, 0.038844],
+        [0.010853, 0.193991, 0.795156],
+    ],
+    [
+        [1.017277, 0.027029, -0.044306],
+        [-0.006113, 0.958479, 0.047634],
+        [0.006379, 0.248708, 0.744913],
+    ],
+    [
+        [1.104996, -0.046633, -0.058363],
+        [-0.032137, 0.971635, 0.060503],
+        [0.001336, 0.317922, 0.680742],
+    ],
+    [
+        [1.193214, -0.109812, -0.083402],
+        [-0.058496, 0.979410, 0.079086],
+        [-0.002346, 0.403492, 0.598854],
+    ],
+    [
+        [1.257728, -0.139648, -0.118081],
+        [-0.078003, 0.975409, 0.102594],
+        [-0.003316, 0.501214, 0.502102],
+    ],
+    [
+        [1.278864, -0.125333, -0.153531],
+        [-0.084748, 0.957674, 0.127074],
+        [-0.000989, 0.601151, 0.399838],
+    ],
+    [
+        [1.255528, -0.076749, -0.178779],
+        [-0.078411, 0.930809, 0.147602],
+        [0.004733, 0.691367, 0.303900],
+    ],
+];
+
+/// Type of color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Deficiency {
+    /// Missing or anomalous long-wavelength (L) cones
+    Protan,
+    /// Missing or anomalous medium-wavelength (M) cones
+    Deutan,
+    /// Missing or anomalous short-wavelength (S) cones
+    Tritan,
+}
+
+/// A deficiency at a given severity.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CvdSimulation {
+    /// Type of deficiency
+    pub deficiency: Deficiency,
+    /// Severity from 0 (normal vision) to 1 (dichromacy)
+    pub severity: f64,
+}
+
+impl CvdSimulation {
+    /// Create a simulation; severity is clamped to `[0, 1]`.
+    pub fn new(deficiency: Deficiency, severity: f64) -> Self {
+        Self {
+            deficiency,
+            severity: severity.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Full protanopia.
+    pub fn protanopia() -> Self {
+        Self::new(Deficiency::Protan, 1.0)
+    }
+
+    /// Full deuteranopia.
+    pub fn deuteranopia() -> Self {
+        Self::new(Deficiency::Deutan, 1.0)
+    }
+
+    /// Full tritanopia.
+    pub fn tritanopia() -> Self {
+        Self::new(Deficiency::Tritan, 1.0)
+    }
+
+    /// Simulation matrix on linear sRGB.
+    pub fn matrix(&self) -> [[f64; 3]; 3] {
+        let table = match self.deficiency {
+            Deficiency::Protan => &PROTAN,
+            Deficiency::Deutan => &DEUTAN,
+            Deficiency::Tritan => &TRITAN,
+        };
+        let position = self.severity.clamp(0.0, 1.0) * SEVERITY_STEPS as f64;
+        let step = (position.floor() as usize).min(SEVERITY_STEPS - 1);
+        let t = position - step as f64;
+        let (lower, upper) = (&table[step], &table[step + 1]);
+        let mut matrix = [[0.0; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, m) in row.iter_mut().enumerate() {
+                *m = lower[i][j] + t * (upper[i][j] - lower[i][j]);
+            }
+        }
+        matrix
+    }
+
+    /// Simulate how an sRGB color is seen.
+    pub fn simulate(&self, rgb: [u8; 3]) -> [u8; 3] {
+        let matrix = self.matrix();
+        let linear = rgb.map(|c| srgb_to_linear(c as f64 / 255.0));
+        let mut out = [0u8; 3];
+        for (c, row) in out.iter_mut().zip(&matrix) {
+            let v = row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2];
+            *c = (linear_to_srgb(v.clamp(0.0, 1.0)) * 255.0).round() as u8;
+        }
+        out
+    }
+}
+
+/// Naming of a color with and without a color vision deficiency.
+#[derive(Debug, Clone)]
+pub struct CvdNaming {
+    /// Simulation applied
+    pub simulation: CvdSimulation,
+    /// Input color
+    pub original_rgb: [u8; 3],
+    /// Color as seen with the deficiency
+    pub simulated_rgb: [u8; 3],
+    /// Characterization of the input color
+    pub original: ColorCharacterization,
+    /// Characterization of the simulated color
+    pub simulated: ColorCharacterization,
+}
+
+impl CvdNaming {
+    /// ISCC-NBS descriptor of the input color, such as "strong red".
+    pub fn original_name(&self) -> String {
+        self.original.describe(&FormatOptions::standard())
+    }
+
+    /// ISCC-NBS descriptor of the simulated color.
+    pub fn simulated_name(&self) -> String {
+        self.simulated.describe(&FormatOptions::standard())
+    }
+
+    /// Whether the deficiency moves the color to another ISCC-NBS category.
+    pub fn name_changed(&self) -> bool {
+        self.original.iscc_nbs_number != self.simulated.iscc_nbs_number
+    }
+}
+
+/// Two palette colors that are hard to tell apart with a deficiency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfusablePair {
+    /// Index of the first color in the palette
+    pub first: usize,
+    /// Index of the second color in the palette
+    pub second: usize,
+    /// Munsell distance between the simulated colors
+    pub simulated_distance: f64,
+    /// Munsell distance between the original colors
+    pub original_distance: f64,
+}
+
+impl ColorClassifier {
+    /// Characterize a color as seen with a color vision deficiency.
+    ///
+    /// # Errors
+    /// Returns an error if either the original or the simulated color cannot
+    /// be characterized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::ColorClassifier;
+    /// use munsellspace::cvd::CvdSimulation;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = ColorClassifier::new()?;
+    /// let naming = classifier.characterize_srgb_cvd([40, 160, 60], &CvdSimulation::deuteranopia())?;
+    ///
+    /// println!("{} looks {}", naming.original_name(), naming.simulated_name());
+    /// assert!(naming.name_changed());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn characterize_srgb_cvd(
+        &self,
+        rgb: [u8; 3],
+        simulation: &CvdSimulation,
+    ) -> Result<CvdNaming> {
+        let simulated_rgb = simulation.simulate(rgb);
+        Ok(CvdNaming {
+            simulation: *simulation,
+            original_rgb: rgb,
+            simulated_rgb,
+            original: self.characterize_srgb(rgb)?,
+            simulated: self.characterize_srgb(simulated_rgb)?,
+        })
+    }
+
+    /// Find palette colors whose simulated Munsell difference is below
+    /// `threshold`.
+    ///
+    /// Differences are Euclidean distances in Munsell Cartesian space, where
+    /// one unit is one step of value or chroma. Pairs are listed by
+    /// increasing simulated distance.
+    ///
+    /// # Errors
+    /// Returns an error if a color cannot be characterized.
+    pub fn cvd_confusable_pairs(
+        &self,
+        palette: &[[u8; 3]],
+        simulation: &CvdSimulation,
+        threshold: f64,
+    ) -> Result<Vec<ConfusablePair>> {
+        let namings = palette
+            .iter()
+            .map(|&rgb| self.characterize_srgb_cvd(rgb, simulation))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut pairs = Vec::new();
+        for (i, a) in namings.iter().enumerate() {
+            for (j, b) in namings.iter().enumerate().skip(i + 1) {
+                let simulated_distance = a.simulated.munsell.distance_from(&b.simulated.munsell);
+                if simulated_distance < threshold {
+                    pairs.push(ConfusablePair {
+                        first: i,
+                        second: j,
+                        simulated_distance,
+                        original_distance: a.original.munsell.distance_from(&b.original.munsell),
+                    });
+                }
+            }
+        }
+        pairs.sort_by(|a, b| a.simulated_distance.total_cmp(&b.simulated_distance));
+        Ok(pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_and_neutrals() {
+        let none = CvdSimulation::new(Deficiency::Protan, 0.0);
+        assert_eq!(none.simulate([200, 40, 90]), [200, 40, 90]);
+        assert_eq!(CvdSimulation::new(Deficiency::Tritan, 3.0).severity, 1.0);
+
+        // Machado matrix rows sum to one, so grays are unchanged
+        for simulation in [
+            CvdSimulation::protanopia(),
+            CvdSimulation::deuteranopia(),
+            CvdSimulation::tritanopia(),
+        ] {
+            for level in [0, 64, 128, 255] {
+                let seen = simulation.simulate([level; 3]);
+                assert!(seen.iter().all(|&c| c.abs_diff(level) <= 1), "{:?}", seen);
+            }
+        }
+
+        // Partial severity lies between normal vision and dichromacy
+        let red = [220, 30, 30];
+        let full = CvdSimulation::protanopia().simulate(red);
+        let half = CvdSimulation::new(Deficiency::Protan, 0.5).simulate(red);
+        assert!(half[0] < red[0] && half[0] > full[0]);
+    }
+
+    #[test]
+    fn test_partial_severity_matches_published_tables() {
+        // Machado et al. (2009), deuteranomaly at severity 0.5 and 0.6
+        let half = [
+            [0.547494, 0.607765, -0.155259],
+            [0.181692, 0.781742, 0.036566],
+            [-0.010410, 0.027275, 0.983136],
+        ];
+        let six_tenths = [
+            [0.498864, 0.674741, -0.173604],
+            [0.205199, 0.754872, 0.039929],
+            [-0.011131, 0.030969, 0.980162],
+        ];
+        assert_eq!(CvdSimulation::new(Deficiency::Deutan, 0.5).matrix(), half);
+
+        let between = CvdSimulation::new(Deficiency::Deutan, 0.55).matrix();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = (half[i][j] + six_tenths[i][j]) / 2.0;
+                assert!((between[i][j] - expected).abs() < 1e-9, "{:?}", between);
+            }
+        }
+    }
+
+    #[test]
+    fn test_confusable_pairs() {
+        let classifier = ColorClassifier::new().unwrap();
+        // Red and green of similar lightness collapse for deuteranopes;
+        // blue stays distinct
+        let palette = [[200, 60, 40], [90, 130, 40], [40, 60, 200]];
+        let deutan = CvdSimulation::deuteranopia();
+
+        let pairs = classifier.cvd_confusable_pairs(&palette, &deutan, 3.0).unwrap();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].first, pairs[0].second), (0, 1));
+        assert!(pairs[0].original_distance > pairs[0].simulated_distance);
+
+        let normal = CvdSimulation::new(Deficiency::Deutan, 0.0);
+        assert!(classifier.cvd_confusable_pairs(&palette, &normal, 3.0).unwrap().is_empty());
+    }
+}
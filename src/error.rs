@@ -1,4 +1,30 @@
 //! Error types for MunsellSpace conversion operations.
+//!
+//! Every [`MunsellError`] has a stable, machine-readable [`ErrorCode`]. Errors
+//! raised inside the conversion pipeline are [`MunsellError::Pipeline`] and
+//! also say at which [`PipelineStage`] they occurred, which input failed,
+//! and, for iterative solvers, how far the solver got. Services can map
+//! [`ErrorCode::is_input_error`] to client errors and the rest to server
+//! errors.
+//!
+//! # Example
+//!
+//! ```rust
+//! use munsellspace::error::{ErrorCode, ErrorInput, PipelineStage};
+//! use munsellspace::ColorClassifier;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let classifier = ColorClassifier::new()?;
+//! let error = classifier.characterize_munsell_notation("5XY 4/10").unwrap_err();
+//!
+//! assert_eq!(error.code(), ErrorCode::InvalidNotation);
+//! assert_eq!(error.code().as_str(), "invalid_notation");
+//! assert!(error.code().is_input_error());
+//! assert_eq!(error.stage(), Some(PipelineStage::NotationParsing));
+//! assert_eq!(error.input(), Some(ErrorInput::Notation("5XY 4/10".to_string())));
+//! # Ok(())
+//! # }
+//! ```
 
 use std::fmt;
 
@@ -6,7 +32,10 @@ use std::fmt;
 pub type Result<T> = std::result::Result<T, MunsellError>;
 
 /// Comprehensive error type for Munsell color space conversion operations.
+///
+/// Variants, codes and stages may be added in minor releases.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum MunsellError {
     /// Invalid RGB values (components not in 0-255 range).
     InvalidRgb {
@@ -64,6 +93,285 @@ pub enum MunsellError {
     
     /// Feature not yet implemented.
     NotImplemented(String),
+
+    /// Failure inside the conversion pipeline, with its stage, input and
+    /// solver state.
+    Pipeline(Box<PipelineError>),
+}
+
+/// Stable, machine-readable error code.
+///
+/// Codes never change meaning between releases; see [`as_str`](Self::as_str)
+/// for their string form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// RGB input is invalid
+    InvalidRgb,
+    /// Color lies outside the gamut of the target space
+    OutOfGamut,
+    /// Munsell notation cannot be parsed
+    InvalidNotation,
+    /// Munsell specification is outside the valid or renotated range
+    InvalidMunsellColor,
+    /// Embedded or loaded reference data is invalid
+    ReferenceData,
+    /// Reading or writing a file failed
+    Io,
+    /// An iterative solver did not converge
+    ConvergenceFailed,
+    /// Interpolating the renotation data failed
+    InterpolationFailed,
+    /// A color could not be placed in any ISCC-NBS category
+    Unclassified,
+    /// Any other conversion failure
+    ConversionFailed,
+    /// The operation is not implemented
+    NotImplemented,
+}
+
+impl ErrorCode {
+    /// String form of the code, such as `"convergence_failed"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::InvalidRgb => "invalid_rgb",
+            ErrorCode::OutOfGamut => "out_of_gamut",
+            ErrorCode::InvalidNotation => "invalid_notation",
+            ErrorCode::InvalidMunsellColor => "invalid_munsell_color",
+            ErrorCode::ReferenceData => "reference_data",
+            ErrorCode::Io => "io",
+            ErrorCode::ConvergenceFailed => "convergence_failed",
+            ErrorCode::InterpolationFailed => "interpolation_failed",
+            ErrorCode::Unclassified => "unclassified",
+            ErrorCode::ConversionFailed => "conversion_failed",
+            ErrorCode::NotImplemented => "not_implemented",
+        }
+    }
+
+    /// Whether the error is caused by the caller's input rather than by the
+    /// library or its data.
+    pub fn is_input_error(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::InvalidRgb
+                | ErrorCode::OutOfGamut
+                | ErrorCode::InvalidNotation
+                | ErrorCode::InvalidMunsellColor
+        )
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Stage of the conversion pipeline.
+///
+/// Every 8-bit sRGB color is valid and gamma-decodes to linear RGB in
+/// `[0, 1]`, so the converters never report [`PipelineStage::Linearization`]
+/// for `[u8; 3]` input; the stage is reserved for inputs that can fail to
+/// decode, such as floating-point or wide-gamut RGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PipelineStage {
+    /// Checking the input values
+    InputValidation,
+    /// Gamma-decoding RGB input to linear RGB
+    Linearization,
+    /// Parsing Munsell notation
+    NotationParsing,
+    /// Adapting between illuminants
+    ChromaticAdaptation,
+    /// Iteratively solving xyY for a Munsell specification
+    Solver,
+    /// Looking up or interpolating the Munsell renotation data
+    RenotationLookup,
+    /// Testing ISCC-NBS polygons
+    PolygonTest,
+    /// Loading reference data
+    ReferenceData,
+    /// Reading or writing files
+    Io,
+}
+
+impl PipelineStage {
+    /// String form of the stage, such as `"renotation_lookup"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PipelineStage::InputValidation => "input_validation",
+            PipelineStage::Linearization => "linearization",
+            PipelineStage::NotationParsing => "notation_parsing",
+            PipelineStage::ChromaticAdaptation => "chromatic_adaptation",
+            PipelineStage::Solver => "solver",
+            PipelineStage::RenotationLookup => "renotation_lookup",
+            PipelineStage::PolygonTest => "polygon_test",
+            PipelineStage::ReferenceData => "reference_data",
+            PipelineStage::Io => "io",
+        }
+    }
+}
+
+impl fmt::Display for PipelineStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Input that an operation failed on.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorInput {
+    /// sRGB color
+    Rgb([u8; 3]),
+    /// CIE xyY color
+    Xyy([f64; 3]),
+    /// CIELAB color
+    Lab([f64; 3]),
+    /// Munsell notation
+    Notation(String),
+}
+
+impl fmt::Display for ErrorInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorInput::Rgb(rgb) => write!(f, "RGB {:?}", rgb),
+            ErrorInput::Xyy([x, y, big_y]) => write!(f, "xyY [{:.6}, {:.6}, {:.6}]", x, y, big_y),
+            ErrorInput::Lab([l, a, b]) => write!(f, "Lab [{:.3}, {:.3}, {:.3}]", l, a, b),
+            ErrorInput::Notation(notation) => write!(f, "'{}'", notation),
+        }
+    }
+}
+
+/// State of an iterative solver when it stopped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverContext {
+    /// Iterations run
+    pub iterations: usize,
+    /// Iteration limit
+    pub max_iterations: usize,
+    /// Residual after the last iteration
+    pub last_residual: f64,
+    /// Residual below which the solver converges
+    pub tolerance: f64,
+}
+
+/// Details of a [`MunsellError::Pipeline`] error.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipelineError {
+    /// Machine-readable code
+    pub code: ErrorCode,
+    /// Stage that failed
+    pub stage: PipelineStage,
+    /// Input of the failing call, when known: the caller's RGB, xyY, Lab or
+    /// notation
+    pub input: Option<ErrorInput>,
+    /// What went wrong
+    pub message: String,
+    /// Solver state, for solver failures
+    pub solver: Option<SolverContext>,
+}
+
+impl MunsellError {
+    /// Create a pipeline error.
+    pub fn pipeline(code: ErrorCode, stage: PipelineStage, message: impl Into<String>) -> Self {
+        MunsellError::Pipeline(Box::new(PipelineError {
+            code,
+            stage,
+            input: None,
+            message: message.into(),
+            solver: None,
+        }))
+    }
+
+    /// Stable, machine-readable code of the error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            MunsellError::InvalidRgb { .. } => ErrorCode::InvalidRgb,
+            MunsellError::OutOfGamut { .. } => ErrorCode::OutOfGamut,
+            MunsellError::InvalidNotation { .. } => ErrorCode::InvalidNotation,
+            MunsellError::ReferenceDataError { .. } => ErrorCode::ReferenceData,
+            MunsellError::ConversionError { .. } => ErrorCode::ConversionFailed,
+            MunsellError::IoError { .. } => ErrorCode::Io,
+            MunsellError::ConvergenceFailed => ErrorCode::ConvergenceFailed,
+            MunsellError::InterpolationError { .. } => ErrorCode::InterpolationFailed,
+            MunsellError::InvalidMunsellColor(_) => ErrorCode::InvalidMunsellColor,
+            MunsellError::NotImplemented(_) => ErrorCode::NotImplemented,
+            MunsellError::Pipeline(error) => error.code,
+        }
+    }
+
+    /// Pipeline stage at which the error occurred, when known.
+    pub fn stage(&self) -> Option<PipelineStage> {
+        match self {
+            MunsellError::InvalidRgb { .. } => Some(PipelineStage::InputValidation),
+            MunsellError::InvalidNotation { .. } => Some(PipelineStage::NotationParsing),
+            MunsellError::ReferenceDataError { .. } => Some(PipelineStage::ReferenceData),
+            MunsellError::IoError { .. } => Some(PipelineStage::Io),
+            MunsellError::ConvergenceFailed => Some(PipelineStage::Solver),
+            MunsellError::InterpolationError { .. } => Some(PipelineStage::RenotationLookup),
+            MunsellError::Pipeline(error) => Some(error.stage),
+            _ => None,
+        }
+    }
+
+    /// Input that the operation failed on, when known.
+    pub fn input(&self) -> Option<ErrorInput> {
+        match self {
+            MunsellError::InvalidRgb { rgb, .. } | MunsellError::OutOfGamut { rgb, .. } => {
+                Some(ErrorInput::Rgb(*rgb))
+            }
+            MunsellError::InvalidNotation { notation, .. } => {
+                Some(ErrorInput::Notation(notation.clone()))
+            }
+            MunsellError::Pipeline(error) => error.input.clone(),
+            _ => None,
+        }
+    }
+
+    /// State of the solver, for solver failures.
+    pub fn solver_context(&self) -> Option<&SolverContext> {
+        match self {
+            MunsellError::Pipeline(error) => error.solver.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Internal: attach solver state to a pipeline error.
+    pub(crate) fn with_solver(mut self, solver: SolverContext) -> Self {
+        if let MunsellError::Pipeline(error) = &mut self {
+            error.solver = Some(solver);
+        }
+        self
+    }
+
+    /// Internal: record the input of the failing call.
+    ///
+    /// Pipeline errors keep their stage and take the input unless they
+    /// already name one; conversion, interpolation and renotation errors
+    /// become pipeline errors at their own stage, or at `stage` when it is
+    /// unknown. Errors that already name their input are unchanged.
+    pub(crate) fn with_input(self, stage: PipelineStage, input: ErrorInput) -> Self {
+        let message = match self {
+            MunsellError::Pipeline(mut error) => {
+                error.input.get_or_insert(input);
+                return MunsellError::Pipeline(error);
+            }
+            MunsellError::ConversionError { ref message }
+            | MunsellError::InterpolationError { ref message }
+            | MunsellError::InvalidMunsellColor(ref message) => message.clone(),
+            MunsellError::ConvergenceFailed => self.to_string(),
+            _ => return self,
+        };
+        MunsellError::Pipeline(Box::new(PipelineError {
+            code: self.code(),
+            stage: self.stage().unwrap_or(stage),
+            input: Some(input),
+            message,
+            solver: None,
+        }))
+    }
 }
 
 impl fmt::Display for MunsellError {
@@ -99,6 +407,21 @@ impl fmt::Display for MunsellError {
             MunsellError::NotImplemented(message) => {
                 write!(f, "Not implemented: {}", message)
             }
+            MunsellError::Pipeline(error) => {
+                write!(f, "{} failed", error.stage)?;
+                if let Some(input) = &error.input {
+                    write!(f, " for {}", input)?;
+                }
+                write!(f, ": {}", error.message)?;
+                if let Some(solver) = &error.solver {
+                    write!(
+                        f,
+                        " ({} of {} iterations, residual {:.3e}, tolerance {:.1e})",
+                        solver.iterations, solver.max_iterations, solver.last_residual, solver.tolerance
+                    )?;
+                }
+                write!(f, " [{}]", error.code)
+            }
         }
     }
 }
@@ -305,6 +628,80 @@ mod tests {
         let _: &dyn std::error::Error = &error;
     }
 
+    #[test]
+    fn test_error_codes_and_context() {
+        let rgb = MunsellError::InvalidRgb {
+            rgb: [1, 2, 3],
+            reason: "test".to_string(),
+        };
+        assert_eq!(rgb.code(), ErrorCode::InvalidRgb);
+        assert!(rgb.code().is_input_error());
+        assert_eq!(rgb.stage(), Some(PipelineStage::InputValidation));
+        assert_eq!(rgb.input(), Some(ErrorInput::Rgb([1, 2, 3])));
+        assert_eq!(rgb.clone().with_input(PipelineStage::Solver, ErrorInput::Rgb([0; 3])), rgb);
+
+        // Legacy errors gain their input and a stage
+        let lookup = MunsellError::InterpolationError {
+            message: "no data".to_string(),
+        };
+        let error = lookup.with_input(PipelineStage::Solver, ErrorInput::Xyy([0.3, 0.3, 0.2]));
+        assert_eq!(error.code(), ErrorCode::InterpolationFailed);
+        assert!(!error.code().is_input_error());
+        assert_eq!(error.stage(), Some(PipelineStage::RenotationLookup));
+        assert_eq!(
+            error.to_string(),
+            "renotation_lookup failed for xyY [0.300000, 0.300000, 0.200000]: no data \
+             [interpolation_failed]"
+        );
+        assert_eq!(PipelineStage::Linearization.to_string(), "linearization");
+    }
+
+    #[test]
+    fn test_with_input_keeps_recorded_context() {
+        let solver = MunsellError::pipeline(
+            ErrorCode::ConvergenceFailed,
+            PipelineStage::Solver,
+            "no convergence",
+        )
+        .with_input(PipelineStage::Solver, ErrorInput::Xyy([0.7, 0.2, 0.5]));
+
+        // An outer caller does not overwrite the stage or input
+        let error = solver.clone().with_input(PipelineStage::PolygonTest, ErrorInput::Rgb([1, 2, 3]));
+        assert_eq!(error, solver);
+        assert_eq!(error.stage(), Some(PipelineStage::Solver));
+        assert_eq!(error.input(), Some(ErrorInput::Xyy([0.7, 0.2, 0.5])));
+
+        // Errors without an input take the caller's
+        let unclassified = MunsellError::pipeline(ErrorCode::Unclassified, PipelineStage::PolygonTest, "none")
+            .with_input(PipelineStage::Solver, ErrorInput::Rgb([1, 2, 3]));
+        assert_eq!(unclassified.stage(), Some(PipelineStage::PolygonTest));
+        assert_eq!(unclassified.input(), Some(ErrorInput::Rgb([1, 2, 3])));
+    }
+
+    #[test]
+    fn test_pipeline_error_display() {
+        let error = MunsellError::pipeline(
+            ErrorCode::ConvergenceFailed,
+            PipelineStage::Solver,
+            "no convergence",
+        )
+        .with_solver(SolverContext {
+            iterations: 64,
+            max_iterations: 64,
+            last_residual: 2.5e-5,
+            tolerance: 1e-7,
+        })
+        .with_input(PipelineStage::Solver, ErrorInput::Rgb([10, 20, 30]));
+
+        assert_eq!(error.code().as_str(), "convergence_failed");
+        assert_eq!(error.solver_context().unwrap().iterations, 64);
+        assert_eq!(
+            error.to_string(),
+            "solver failed for RGB [10, 20, 30]: no convergence \
+             (64 of 64 iterations, residual 2.500e-5, tolerance 1.0e-7) [convergence_failed]"
+        );
+    }
+
     #[test]
     fn test_result_type_alias() {
         fn test_function() -> Result<i32> {
@@ -0,0 +1,38 @@
+//! sRGB gamut tests and chroma fitting for Munsell specifications.
+
+use crate::reverse_conversion::ReverseConverter;
+use crate::semantic_overlay::MunsellSpec;
+
+/// Bisection iterations when searching for the largest in-gamut chroma.
+const GAMUT_SEARCH_ITERATIONS: usize = 24;
+
+/// Whether a color lies inside the sRGB gamut.
+pub(crate) fn in_srgb_gamut(spec: MunsellSpec, reverse: &ReverseConverter) -> bool {
+    reverse
+        .is_in_srgb_gamut(&spec.to_specification())
+        .unwrap_or(false)
+}
+
+/// The color at the highest in-gamut chroma not above its own, rounded down
+/// to 0.1 chroma, or `None` if its neutral is outside the gamut.
+pub(crate) fn fit_chroma_to_srgb(spec: MunsellSpec, reverse: &ReverseConverter) -> Option<MunsellSpec> {
+    let at = |chroma: f64| MunsellSpec::new(spec.hue_number, spec.value, chroma);
+    if in_srgb_gamut(spec, reverse) {
+        return Some(spec);
+    }
+    if !in_srgb_gamut(at(0.0), reverse) {
+        return None;
+    }
+
+    let (mut low, mut high) = (0.0, spec.chroma);
+    for _ in 0..GAMUT_SEARCH_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        if in_srgb_gamut(at(mid), reverse) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    // Round down so the rounded notation stays inside the gamut
+    Some(at((low * 10.0).floor() / 10.0))
+}
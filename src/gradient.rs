@@ -0,0 +1,298 @@
+//! Perceptual gradients interpolated in Munsell space.
+//!
+//! Interpolating sRGB components passes through muddy, desaturated colors,
+//! and HSL hue is not perceptually spaced. A [`MunsellGradient`] interpolates
+//! hue, value and chroma between [`MunsellColor`] stops instead:
+//!
+//! - Hue takes the shortest way around the 100-step hue circle, crossing
+//!   family boundaries (10R to 1YR) continuously. Stops exactly opposite each
+//!   other go toward increasing hue.
+//! - A neutral stop has no hue, so the segment keeps the hue of its chromatic
+//!   end while the chroma fades to zero.
+//! - Value and chroma follow an [`Easing`] curve, linear by default.
+//!
+//! Swatches outside the sRGB gamut have their chroma lowered until they fit.
+//!
+//! # Example
+//!
+//! ```rust
+//! use munsellspace::MunsellColor;
+//! use munsellspace::gradient::MunsellGradient;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let stops = [
+//!     MunsellColor::from_notation("5R 4.0/10.0")?,
+//!     MunsellColor::from_notation("5Y 8.0/10.0")?,
+//! ];
+//! let gradient = MunsellGradient::new(&stops)?;
+//!
+//! let swatches = gradient.swatches(5);
+//! assert_eq!(swatches.len(), 5);
+//! assert_eq!(swatches[2].munsell.hue.as_deref(), Some("5YR"));
+//! assert!(swatches.iter().all(|s| s.hex.starts_with('#')));
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{MunsellError, Result};
+use crate::gamut::{fit_chroma_to_srgb, in_srgb_gamut};
+use crate::reverse_conversion::ReverseConverter;
+use crate::semantic_overlay::MunsellSpec;
+use crate::types::MunsellColor;
+
+/// Easing curve applied to the interpolation parameter of each segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Easing {
+    /// Constant rate of change
+    #[default]
+    Linear,
+    /// Slow start (quadratic)
+    EaseIn,
+    /// Slow end (quadratic)
+    EaseOut,
+    /// Slow start and end (cubic smoothstep)
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map `t` in `[0, 1]` onto the curve; the ends are fixed.
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// Options for [`MunsellGradient`].
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct GradientOptions {
+    /// Easing of value between stops (default linear)
+    pub value_easing: Easing,
+    /// Easing of chroma between stops (default linear)
+    pub chroma_easing: Easing,
+    /// Keep swatches that fall outside the sRGB gamut instead of lowering
+    /// their chroma (default false)
+    pub skip_gamut_mapping: bool,
+}
+
+/// One color of a gradient.
+#[derive(Debug, Clone)]
+pub struct GradientSwatch {
+    /// Position along the gradient, from 0 at the first stop to 1 at the last
+    pub position: f64,
+    /// Munsell color, after gamut mapping
+    pub munsell: MunsellColor,
+    /// sRGB color
+    pub srgb: [u8; 3],
+    /// Hexadecimal sRGB color, such as `#C0392B`
+    pub hex: String,
+    /// Whether the Munsell color lies inside the sRGB gamut
+    pub in_gamut: bool,
+}
+
+/// Gradient through two or more evenly spaced Munsell stops.
+pub struct MunsellGradient {
+    stops: Vec<MunsellSpec>,
+    options: GradientOptions,
+    reverse: ReverseConverter,
+}
+
+impl MunsellGradient {
+    /// Create a gradient with default options.
+    ///
+    /// # Errors
+    /// Returns an error with fewer than two stops, if a stop cannot be read,
+    /// or if the reverse converter cannot be created.
+    pub fn new(stops: &[MunsellColor]) -> Result<Self> {
+        if stops.len() < 2 {
+            return Err(MunsellError::InvalidMunsellColor(format!(
+                "A gradient needs at least two stops, got {}",
+                stops.len()
+            )));
+        }
+
+        let stops = stops
+            .iter()
+            .map(|stop| {
+                stop.to_munsell_spec().ok_or_else(|| {
+                    MunsellError::InvalidMunsellColor(format!("Cannot read stop {}", stop.notation))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            stops,
+            options: GradientOptions::default(),
+            reverse: ReverseConverter::new()?,
+        })
+    }
+
+    /// Use different options.
+    pub fn with_options(mut self, options: GradientOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Options in use.
+    pub fn options(&self) -> &GradientOptions {
+        &self.options
+    }
+
+    /// Interpolated color at `position` in `[0, 1]`, before gamut mapping.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::MunsellColor;
+    /// use munsellspace::gradient::MunsellGradient;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // The short way from 5RP to 5R crosses 10RP
+    /// let stops = [
+    ///     MunsellColor::from_notation("5RP 5.0/8.0")?,
+    ///     MunsellColor::from_notation("5R 5.0/8.0")?,
+    /// ];
+    /// let gradient = MunsellGradient::new(&stops)?;
+    /// assert_eq!(gradient.at(0.5).hue.as_deref(), Some("10RP"));
+    ///
+    /// // Toward a neutral stop the hue is kept and chroma fades
+    /// let stops = [
+    ///     MunsellColor::from_notation("5B 5.0/8.0")?,
+    ///     MunsellColor::new_neutral(5.0),
+    /// ];
+    /// let gradient = MunsellGradient::new(&stops)?;
+    /// assert_eq!(gradient.at(0.5).notation, "5B 5.0/4.0");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn at(&self, position: f64) -> MunsellColor {
+        self.spec_at(position).to_munsell_color()
+    }
+
+    /// Sample `count` evenly spaced swatches, including both ends.
+    pub fn swatches(&self, count: usize) -> Vec<GradientSwatch> {
+        (0..count)
+            .map(|i| {
+                let position = if count > 1 {
+                    i as f64 / (count - 1) as f64
+                } else {
+                    0.0
+                };
+                self.swatch(position)
+            })
+            .collect()
+    }
+
+    /// Internal: interpolated specification, rounded to notation precision.
+    fn spec_at(&self, position: f64) -> MunsellSpec {
+        let segments = self.stops.len() - 1;
+        let scaled = position.clamp(0.0, 1.0) * segments as f64;
+        let index = (scaled.floor() as usize).min(segments - 1);
+        let t = scaled - index as f64;
+        let (from, to) = (self.stops[index], self.stops[index + 1]);
+
+        let value_t = self.options.value_easing.apply(t);
+        let chroma_t = self.options.chroma_easing.apply(t);
+        let value = from.value + (to.value - from.value) * value_t;
+        let chroma = from.chroma + (to.chroma - from.chroma) * chroma_t;
+
+        // A neutral end takes the hue of the other end
+        let hue_number = match (from.chroma > 0.0, to.chroma > 0.0) {
+            (true, true) => {
+                let delta = (to.hue_number - from.hue_number + 20.0).rem_euclid(40.0) - 20.0;
+                let delta = if delta == -20.0 { 20.0 } else { delta };
+                from.hue_number + delta * t
+            }
+            (true, false) => from.hue_number,
+            (false, true) => to.hue_number,
+            (false, false) => 0.0,
+        };
+
+        MunsellSpec::new(hue_number.rem_euclid(40.0), value, chroma).rounded()
+    }
+
+    /// Internal: gamut-mapped swatch at a position.
+    fn swatch(&self, position: f64) -> GradientSwatch {
+        let spec = self.spec_at(position);
+        let (spec, in_gamut) = if in_srgb_gamut(spec, &self.reverse) {
+            (spec, true)
+        } else if self.options.skip_gamut_mapping {
+            (spec, false)
+        } else {
+            match fit_chroma_to_srgb(spec, &self.reverse) {
+                Some(fitted) => (fitted, true),
+                None => (spec, false),
+            }
+        };
+
+        let srgb = spec.display_srgb(&self.reverse);
+        GradientSwatch {
+            position,
+            munsell: spec.to_munsell_color(),
+            srgb,
+            hex: format!("#{:02X}{:02X}{:02X}", srgb[0], srgb[1], srgb[2]),
+            in_gamut,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient(stops: &[&str]) -> MunsellGradient {
+        let stops: Vec<_> = stops
+            .iter()
+            .map(|s| MunsellColor::from_notation(s).unwrap())
+            .collect();
+        MunsellGradient::new(&stops).unwrap()
+    }
+
+    #[test]
+    fn test_shortest_hue_path() {
+        // 5B and 5Y are 40 steps apart through green, 60 through purple
+        let g = gradient(&["5B 5.0/6.0", "5Y 5.0/6.0"]);
+        assert_eq!(g.at(0.5).hue.as_deref(), Some("5G"));
+
+        let g = gradient(&["5Y 5.0/6.0", "5B 5.0/6.0"]);
+        assert_eq!(g.at(0.5).hue.as_deref(), Some("5G"));
+
+        // Family boundary: 8R to 2YR passes 10R
+        let g = gradient(&["8R 5.0/6.0", "2YR 5.0/6.0"]);
+        assert_eq!(g.at(0.5).hue.as_deref(), Some("10R"));
+        assert_eq!(g.at(0.75).hue.as_deref(), Some("1YR"));
+    }
+
+    #[test]
+    fn test_neutral_stops_and_multiple_segments() {
+        let g = gradient(&["N 2.0/", "5R 5.0/8.0", "N 8.0/"]);
+        assert_eq!(g.at(0.0).notation, "N 2.0/");
+        assert_eq!(g.at(0.25).notation, "5R 3.5/4.0");
+        assert_eq!(g.at(0.5).notation, "5R 5.0/8.0");
+        assert_eq!(g.at(1.0).notation, "N 8.0/");
+
+        assert!(MunsellGradient::new(&[MunsellColor::new_neutral(5.0)]).is_err());
+    }
+
+    #[test]
+    fn test_easing_and_gamut_mapping() {
+        let options = GradientOptions {
+            value_easing: Easing::EaseIn,
+            ..GradientOptions::default()
+        };
+        let g = gradient(&["5PB 2.0/6.0", "5PB 8.0/6.0"]).with_options(options);
+        assert_eq!(g.at(0.5).value, 3.5);
+        assert_eq!(g.at(0.5).chroma, Some(6.0));
+
+        // Vivid blue-green is outside sRGB; mapped swatches lower chroma
+        let g = gradient(&["5BG 5.0/14.0", "5G 5.0/14.0"]);
+        let swatches = g.swatches(3);
+        assert!(swatches.iter().all(|s| s.in_gamut));
+        assert!(swatches.iter().all(|s| s.munsell.chroma.unwrap() < 14.0));
+    }
+}
@@ -0,0 +1,400 @@
+//! Color harmonies on the Munsell hue circle.
+//!
+//! Schemes are built by rotating a base color around the Munsell hue circle,
+//! which has 100 hue steps (10 per family): a complement is 50 steps, or five
+//! hue families, away. Because Munsell hue, value and chroma are perceptually
+//! spaced, rotating the hue while keeping value and chroma gives colors of
+//! matching lightness and strength, something HSL rotation does not.
+//!
+//! Generated colors can leave the sRGB gamut. With
+//! [`HarmonyOptions::fit_gamut`] each swatch is brought back into the gamut by
+//! lowering its chroma, or by moving its value when chroma is held.
+//!
+//! # Example
+//!
+//! ```rust
+//! use munsellspace::MunsellColor;
+//! use munsellspace::harmony::{HarmonyGenerator, HarmonyScheme};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let generator = HarmonyGenerator::new()?;
+//! let base = MunsellColor::from_notation("5R 5.0/10.0")?;
+//!
+//! let swatches = generator.generate(&base, HarmonyScheme::Complementary)?;
+//! assert_eq!(swatches[1].munsell.hue.as_deref(), Some("5BG"));
+//! assert!(swatches.iter().all(|s| s.in_gamut));
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{MunsellError, Result};
+use crate::gamut::{fit_chroma_to_srgb, in_srgb_gamut};
+use crate::iscc::{ColorMetadata, IsccNbsClassifier};
+use crate::reverse_conversion::ReverseConverter;
+use crate::semantic_overlay::MunsellSpec;
+use crate::types::MunsellColor;
+
+/// Hue steps around the Munsell hue circle.
+const HUE_STEPS: f64 = 100.0;
+
+/// Hue numbers (the 0-40 scale of [`MunsellSpec`]) per hue step.
+const HUE_NUMBER_PER_STEP: f64 = 0.4;
+
+/// Value increment when searching for an in-gamut value.
+const VALUE_SEARCH_STEP: f64 = 0.1;
+
+/// Harmony scheme to generate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HarmonyScheme {
+    /// The base and the hue opposite it (50 hue steps, five families away)
+    Complementary,
+    /// The base and the two hues either side of its complement
+    SplitComplementary,
+    /// The base and its neighbors either side on the hue circle
+    Analogous,
+    /// Three hues evenly spaced around the circle
+    Triadic,
+    /// Four hues evenly spaced around the circle
+    Tetradic,
+    /// The base hue and chroma at evenly spaced values
+    MonochromaticValue,
+    /// The base hue and value at evenly spaced chromas up to the base chroma
+    MonochromaticChroma,
+}
+
+impl HarmonyScheme {
+    /// Whether the scheme rotates the hue, which needs a chromatic base.
+    pub fn is_hue_based(&self) -> bool {
+        !matches!(
+            self,
+            HarmonyScheme::MonochromaticValue | HarmonyScheme::MonochromaticChroma
+        )
+    }
+}
+
+/// Options for [`HarmonyGenerator`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HarmonyOptions {
+    /// Hue steps between the base and its analogous neighbors (default 10.0,
+    /// one hue family)
+    pub analogous_spread: f64,
+    /// Hue steps between the complement and each split-complementary hue
+    /// (default 10.0)
+    pub split_spread: f64,
+    /// Number of swatches in monochromatic scales (default 5)
+    pub scale_steps: usize,
+    /// Lowest and highest value of monochromatic value scales (default 2.0 to 9.0)
+    pub value_range: (f64, f64),
+    /// Keep the base value when fitting into the sRGB gamut (default true)
+    pub hold_value: bool,
+    /// Keep the base chroma when fitting into the sRGB gamut (default false)
+    pub hold_chroma: bool,
+    /// Bring every swatch inside the sRGB gamut (default true)
+    pub fit_gamut: bool,
+    /// Look up the ISCC-NBS category of every swatch (default true)
+    pub iscc_names: bool,
+}
+
+impl Default for HarmonyOptions {
+    fn default() -> Self {
+        Self {
+            analogous_spread: 10.0,
+            split_spread: 10.0,
+            scale_steps: 5,
+            value_range: (2.0, 9.0),
+            hold_value: true,
+            hold_chroma: false,
+            fit_gamut: true,
+            iscc_names: true,
+        }
+    }
+}
+
+/// One color of a harmony.
+#[derive(Debug, Clone)]
+pub struct HarmonySwatch {
+    /// Munsell color of the swatch, after gamut fitting
+    pub munsell: MunsellColor,
+    /// sRGB color, clipped if the swatch is outside the gamut; `None` if the
+    /// color is outside the renotation data
+    pub srgb: Option<[u8; 3]>,
+    /// Whether the Munsell color lies inside the sRGB gamut
+    pub in_gamut: bool,
+    /// ISCC-NBS category, when [`HarmonyOptions::iscc_names`] is set
+    pub iscc_nbs: Option<ColorMetadata>,
+}
+
+impl HarmonySwatch {
+    /// ISCC-NBS descriptor such as "vivid red", if the category is known.
+    pub fn iscc_nbs_name(&self) -> Option<String> {
+        self.iscc_nbs.as_ref().map(|m| m.iscc_nbs_descriptor())
+    }
+}
+
+/// Generator of Munsell color harmonies.
+pub struct HarmonyGenerator {
+    reverse: ReverseConverter,
+    classifier: IsccNbsClassifier,
+    options: HarmonyOptions,
+}
+
+impl HarmonyGenerator {
+    /// Create a generator with default options.
+    ///
+    /// # Errors
+    /// Returns an error if the reverse converter or the ISCC-NBS classifier
+    /// cannot be created.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            reverse: ReverseConverter::new()?,
+            classifier: IsccNbsClassifier::new()?,
+            options: HarmonyOptions::default(),
+        })
+    }
+
+    /// Use different options.
+    pub fn with_options(mut self, options: HarmonyOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Options in use.
+    pub fn options(&self) -> &HarmonyOptions {
+        &self.options
+    }
+
+    /// Generate a harmony scheme from a base color.
+    ///
+    /// Hue schemes start with the base color; monochromatic scales run from
+    /// dark to light or from weak to strong.
+    ///
+    /// # Errors
+    /// Returns an error for hue schemes and chroma scales of a neutral base,
+    /// which has no hue, if the base cannot be read, or if ISCC-NBS
+    /// classification fails.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::MunsellColor;
+    /// use munsellspace::harmony::{HarmonyGenerator, HarmonyScheme};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let generator = HarmonyGenerator::new()?;
+    /// let base = MunsellColor::from_notation("5PB 4.0/8.0")?;
+    ///
+    /// let scale = generator.generate(&base, HarmonyScheme::MonochromaticValue)?;
+    /// assert_eq!(scale.len(), 5);
+    /// assert!(scale.windows(2).all(|w| w[0].munsell.value < w[1].munsell.value));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn generate(
+        &self,
+        base: &MunsellColor,
+        scheme: HarmonyScheme,
+    ) -> Result<Vec<HarmonySwatch>> {
+        let spec = base.to_munsell_spec().ok_or_else(|| {
+            MunsellError::InvalidMunsellColor(format!("Cannot read base color {}", base.notation))
+        })?;
+        if base.is_neutral() && scheme != HarmonyScheme::MonochromaticValue {
+            return Err(MunsellError::InvalidMunsellColor(format!(
+                "{:?} harmony needs a chromatic base color, got {}",
+                scheme, base.notation
+            )));
+        }
+
+        let options = &self.options;
+        let rotations: &[f64] = match scheme {
+            HarmonyScheme::Complementary => &[0.0, 50.0],
+            HarmonyScheme::SplitComplementary => {
+                &[0.0, 50.0 - options.split_spread, 50.0 + options.split_spread]
+            }
+            HarmonyScheme::Analogous => &[0.0, -options.analogous_spread, options.analogous_spread],
+            HarmonyScheme::Triadic => &[0.0, HUE_STEPS / 3.0, 2.0 * HUE_STEPS / 3.0],
+            HarmonyScheme::Tetradic => &[0.0, 25.0, 50.0, 75.0],
+            HarmonyScheme::MonochromaticValue => {
+                let (low, high) = options.value_range;
+                return (0..options.scale_steps)
+                    .map(|i| {
+                        let value = low + (high - low) * fraction(i, options.scale_steps);
+                        let spec = MunsellSpec::new(spec.hue_number, value, spec.chroma);
+                        self.swatch(spec, true, options.hold_chroma)
+                    })
+                    .collect();
+            }
+            HarmonyScheme::MonochromaticChroma => {
+                return (1..=options.scale_steps)
+                    .map(|i| {
+                        let chroma = spec.chroma * i as f64 / options.scale_steps as f64;
+                        let spec = MunsellSpec::new(spec.hue_number, spec.value, chroma);
+                        self.swatch(spec, options.hold_value, true)
+                    })
+                    .collect();
+            }
+        };
+
+        rotations
+            .iter()
+            .map(|steps| {
+                let hue_number = (spec.hue_number + steps * HUE_NUMBER_PER_STEP).rem_euclid(40.0);
+                let rotated = MunsellSpec::new(hue_number, spec.value, spec.chroma);
+                self.swatch(rotated, options.hold_value, options.hold_chroma)
+            })
+            .collect()
+    }
+
+    /// Internal: build a swatch, fitting it into the gamut if requested.
+    fn swatch(
+        &self,
+        spec: MunsellSpec,
+        hold_value: bool,
+        hold_chroma: bool,
+    ) -> Result<HarmonySwatch> {
+        let spec = spec.rounded();
+        let mut in_gamut = in_srgb_gamut(spec, &self.reverse);
+        let mut fitted = spec;
+        if self.options.fit_gamut && !in_gamut {
+            let candidate = if !hold_chroma {
+                fit_chroma_to_srgb(spec, &self.reverse)
+            } else if !hold_value {
+                self.fit_value(spec)
+            } else {
+                None
+            };
+            if let Some(candidate) = candidate {
+                fitted = candidate;
+                in_gamut = true;
+            }
+        }
+
+        let munsell = fitted.to_munsell_color();
+        let iscc_nbs = if self.options.iscc_names {
+            match &munsell.hue {
+                Some(hue) => self.classifier.classify_munsell(hue, fitted.value, fitted.chroma)?,
+                None => self.classifier.classify_munsell("N", fitted.value, 0.0)?,
+            }
+        } else {
+            None
+        };
+
+        Ok(HarmonySwatch {
+            munsell,
+            srgb: fitted.to_srgb(&self.reverse),
+            in_gamut,
+            iscc_nbs,
+        })
+    }
+
+    /// Internal: the color at the in-gamut value nearest its own.
+    fn fit_value(&self, spec: MunsellSpec) -> Option<MunsellSpec> {
+        let max_offset = (10.0 / VALUE_SEARCH_STEP) as usize;
+        (1..=max_offset)
+            .flat_map(|i| {
+                let offset = i as f64 * VALUE_SEARCH_STEP;
+                [spec.value - offset, spec.value + offset]
+            })
+            .filter(|value| (0.0..=10.0).contains(value))
+            .map(|value| MunsellSpec::new(spec.hue_number, value, spec.chroma).rounded())
+            .find(|candidate| in_srgb_gamut(*candidate, &self.reverse))
+    }
+}
+
+/// Position of step `i` of `steps` in `[0, 1]`.
+fn fraction(i: usize, steps: usize) -> f64 {
+    if steps > 1 {
+        i as f64 / (steps - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generator() -> HarmonyGenerator {
+        HarmonyGenerator::new().unwrap()
+    }
+
+    fn base(notation: &str) -> MunsellColor {
+        MunsellColor::from_notation(notation).unwrap()
+    }
+
+    fn hues(swatches: &[HarmonySwatch]) -> Vec<String> {
+        swatches.iter().map(|s| s.munsell.hue.clone().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_hue_schemes() {
+        let g = generator();
+        let red = base("5R 5.0/6.0");
+
+        assert_eq!(hues(&g.generate(&red, HarmonyScheme::Complementary).unwrap()), ["5R", "5BG"]);
+        assert_eq!(
+            hues(&g.generate(&red, HarmonyScheme::SplitComplementary).unwrap()),
+            ["5R", "5G", "5B"]
+        );
+        assert_eq!(
+            hues(&g.generate(&red, HarmonyScheme::Analogous).unwrap()),
+            ["5R", "5RP", "5YR"]
+        );
+        assert_eq!(
+            hues(&g.generate(&red, HarmonyScheme::Tetradic).unwrap()),
+            ["5R", "10Y", "5BG", "10PB"]
+        );
+        assert_eq!(g.generate(&red, HarmonyScheme::Triadic).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_gamut_fitting() {
+        let g = generator();
+        let vivid = base("5R 5.0/14.0");
+
+        // The complement of a vivid red cannot keep its chroma on screen
+        let swatches = g.generate(&vivid, HarmonyScheme::Complementary).unwrap();
+        assert!(swatches.iter().all(|s| s.in_gamut));
+        assert_eq!(swatches[1].munsell.value, 5.0);
+        assert!(swatches[1].munsell.chroma.unwrap() < 14.0);
+
+        // Holding chroma moves the value instead
+        let options = HarmonyOptions {
+            hold_chroma: true,
+            hold_value: false,
+            ..HarmonyOptions::default()
+        };
+        let held = generator().with_options(options);
+        let swatches = held.generate(&base("5R 5.0/10.0"), HarmonyScheme::Complementary).unwrap();
+        assert_eq!(swatches[1].munsell.chroma, Some(10.0));
+        assert!(swatches[1].in_gamut);
+
+        // Without fitting the out-of-gamut complement is reported
+        let options = HarmonyOptions {
+            fit_gamut: false,
+            ..HarmonyOptions::default()
+        };
+        let raw = generator().with_options(options);
+        let swatches = raw.generate(&vivid, HarmonyScheme::Complementary).unwrap();
+        assert!(!swatches[1].in_gamut);
+        assert_eq!(swatches[1].munsell.chroma, Some(14.0));
+    }
+
+    #[test]
+    fn test_monochromatic_scales_and_names() {
+        let g = generator();
+        let blue = base("5PB 4.0/8.0");
+
+        let chroma = g.generate(&blue, HarmonyScheme::MonochromaticChroma).unwrap();
+        let chromas: Vec<f64> = chroma.iter().map(|s| s.munsell.chroma.unwrap()).collect();
+        assert_eq!(chromas, [1.6, 3.2, 4.8, 6.4, 8.0]);
+        assert!(chroma.iter().all(|s| s.munsell.value == 4.0));
+        assert!(chroma.iter().all(|s| s.iscc_nbs_name().is_some()));
+
+        // A gray base only has a value scale
+        let gray = MunsellColor::new_neutral(5.0);
+        let scale = g.generate(&gray, HarmonyScheme::MonochromaticValue).unwrap();
+        assert!(scale.iter().all(|s| s.munsell.is_neutral()));
+        assert!(g.generate(&gray, HarmonyScheme::Triadic).is_err());
+    }
+}
@@ -0,0 +1,637 @@
+//! Color analysis of raw image buffers and palettes.
+//!
+//! [`ColorAnalyzer`] reads raw 8-bit pixel buffers described by an
+//! [`ImageBuffer`] (width, height, row stride and channel order), counts the
+//! unique colors and converts each of them once, in parallel, with
+//! [`MunsellConverter`] and [`IsccNbsClassifier`]. Conversions are kept in a
+//! [`UnifiedColorCache`], so colors shared between images are converted once.
+//!
+//! The resulting [`ColorAnalysis`] weights every color by its pixel count
+//! and reports:
+//!
+//! - the share of each Munsell hue family, with neutrals as `"N"`
+//! - value and chroma histograms and means
+//! - the dominant ISCC-NBS categories
+//! - the share of pixels inside each semantic overlay
+//! - the top named colors, using the best-matching overlay name and the
+//!   ISCC-NBS descriptor otherwise
+//!
+//! # Example
+//!
+//! ```rust
+//! use munsellspace::image_analysis::{ChannelOrder, ColorAnalyzer, ImageBuffer};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // 2x1 RGBA image: one red pixel and one transparent pixel
+//! let pixels = [200, 30, 40, 255, 0, 0, 0, 0];
+//! let image = ImageBuffer::new(&pixels, 2, 1, ChannelOrder::Rgba);
+//!
+//! let analysis = ColorAnalyzer::new()?.analyze_image(&image)?;
+//! assert_eq!(analysis.total_pixels, 1);
+//! assert_eq!(analysis.hue_families[0].label, "R");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use rayon::prelude::*;
+
+use crate::converter::MunsellConverter;
+use crate::error::{MunsellError, Result};
+use crate::iscc::{ColorMetadata, IsccNbsClassifier};
+use crate::semantic_overlay::{MunsellSpec, SemanticOverlayRegistry};
+use crate::semantic_overlay_data::get_registry;
+use crate::types::MunsellColor;
+use crate::unified_cache::{CachedColorResult, UnifiedColorCache};
+
+/// Default capacity of the analyzer's conversion cache.
+const DEFAULT_CACHE_CAPACITY: usize = 4096;
+
+/// Width of the value histogram bins.
+const VALUE_BIN_WIDTH: f64 = 1.0;
+
+/// Order of the 8-bit channels of a pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChannelOrder {
+    /// Red, green, blue
+    Rgb,
+    /// Blue, green, red
+    Bgr,
+    /// Red, green, blue, alpha
+    Rgba,
+    /// Blue, green, red, alpha
+    Bgra,
+    /// Alpha, red, green, blue
+    Argb,
+}
+
+impl ChannelOrder {
+    /// Bytes per pixel.
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            ChannelOrder::Rgb | ChannelOrder::Bgr => 3,
+            ChannelOrder::Rgba | ChannelOrder::Bgra | ChannelOrder::Argb => 4,
+        }
+    }
+
+    /// Read the RGB color and alpha of one pixel.
+    fn read(&self, px: &[u8]) -> ([u8; 3], u8) {
+        match self {
+            ChannelOrder::Rgb => ([px[0], px[1], px[2]], u8::MAX),
+            ChannelOrder::Bgr => ([px[2], px[1], px[0]], u8::MAX),
+            ChannelOrder::Rgba => ([px[0], px[1], px[2]], px[3]),
+            ChannelOrder::Bgra => ([px[2], px[1], px[0]], px[3]),
+            ChannelOrder::Argb => ([px[1], px[2], px[3]], px[0]),
+        }
+    }
+}
+
+/// Borrowed raw image in 8-bit sRGB.
+#[derive(Debug, Clone, Copy)]
+pub struct ImageBuffer<'a> {
+    /// Pixel bytes, row by row
+    pub data: &'a [u8],
+    /// Width in pixels
+    pub width: usize,
+    /// Height in pixels
+    pub height: usize,
+    /// Bytes from the start of one row to the start of the next
+    pub stride: usize,
+    /// Channel order of each pixel
+    pub order: ChannelOrder,
+}
+
+impl<'a> ImageBuffer<'a> {
+    /// Describe a buffer whose rows are tightly packed.
+    pub fn new(data: &'a [u8], width: usize, height: usize, order: ChannelOrder) -> Self {
+        Self {
+            data,
+            width,
+            height,
+            stride: width * order.bytes_per_pixel(),
+            order,
+        }
+    }
+
+    /// Use a row stride larger than the packed row, for padded buffers.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        self.stride = stride;
+        self
+    }
+
+    /// Internal: check the layout fits in the data.
+    fn validate(&self) -> Result<()> {
+        let row = self.width * self.order.bytes_per_pixel();
+        if self.stride < row {
+            return Err(MunsellError::ConversionError {
+                message: format!("Stride {} is shorter than a row of {} bytes", self.stride, row),
+            });
+        }
+        let needed = match self.height {
+            0 => 0,
+            h => (h - 1) * self.stride + row,
+        };
+        if self.data.len() < needed {
+            return Err(MunsellError::ConversionError {
+                message: format!(
+                    "Buffer of {} bytes is too small for a {}x{} image with stride {}",
+                    self.data.len(),
+                    self.width,
+                    self.height,
+                    self.stride
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Options for [`ColorAnalyzer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnalysisOptions {
+    /// Number of ISCC-NBS categories and named colors reported (default 10)
+    pub top_n: usize,
+    /// Width of the chroma histogram bins (default 2.0)
+    pub chroma_bin_width: f64,
+    /// Pixels with alpha at or below this are ignored (default 0, so only
+    /// fully transparent pixels are skipped)
+    pub alpha_threshold: u8,
+}
+
+impl Default for AnalysisOptions {
+    fn default() -> Self {
+        Self {
+            top_n: 10,
+            chroma_bin_width: 2.0,
+            alpha_threshold: 0,
+        }
+    }
+}
+
+/// Pixel count and share of a labelled group of colors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelShare {
+    /// Hue family, overlay or color name
+    pub label: String,
+    /// Number of pixels
+    pub pixels: usize,
+    /// Fraction of the converted pixels
+    pub share: f64,
+}
+
+/// Pixel count and share of an ISCC-NBS category.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryShare {
+    /// ISCC-NBS color number (1-267)
+    pub iscc_nbs_number: u16,
+    /// ISCC-NBS descriptor, such as "dark grayish blue"
+    pub descriptor: String,
+    /// Number of pixels
+    pub pixels: usize,
+    /// Fraction of the converted pixels
+    pub share: f64,
+}
+
+/// Histogram of pixel counts over equal-width bins starting at zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// Width of every bin
+    pub bin_width: f64,
+    /// Pixel count per bin; bin `i` covers `[i * bin_width, (i + 1) * bin_width)`
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    fn new(bin_width: f64) -> Self {
+        Self {
+            bin_width,
+            counts: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, x: f64, pixels: usize, last_bin: Option<usize>) {
+        let mut bin = (x.max(0.0) / self.bin_width).floor() as usize;
+        if let Some(last) = last_bin {
+            bin = bin.min(last);
+        }
+        if self.counts.len() <= bin {
+            self.counts.resize(bin + 1, 0);
+        }
+        self.counts[bin] += pixels;
+    }
+}
+
+/// Color analysis of an image or palette.
+#[derive(Debug, Clone)]
+pub struct ColorAnalysis {
+    /// Pixels analyzed, excluding transparent ones
+    pub total_pixels: usize,
+    /// Distinct colors among them
+    pub unique_colors: usize,
+    /// Pixels whose color could not be converted; excluded from the shares
+    pub failed_pixels: usize,
+    /// Share of each hue family (`"N"` for neutrals), largest first
+    pub hue_families: Vec<LabelShare>,
+    /// Munsell value histogram with unit bins from 0 to 10
+    pub value_histogram: Histogram,
+    /// Munsell chroma histogram
+    pub chroma_histogram: Histogram,
+    /// Pixel-weighted mean Munsell value
+    pub mean_value: f64,
+    /// Pixel-weighted mean Munsell chroma
+    pub mean_chroma: f64,
+    /// Most frequent ISCC-NBS categories, largest first
+    pub iscc_categories: Vec<CategoryShare>,
+    /// Share of pixels inside each overlay that holds any, largest first.
+    /// Overlays overlap, so shares need not sum to one.
+    pub overlay_coverage: Vec<LabelShare>,
+    /// Most frequent color names, largest first
+    pub named_colors: Vec<LabelShare>,
+}
+
+/// Analyzer of image and palette colors.
+pub struct ColorAnalyzer {
+    converter: MunsellConverter,
+    classifier: IsccNbsClassifier,
+    custom_overlays: Option<SemanticOverlayRegistry>,
+    cache: UnifiedColorCache,
+    options: AnalysisOptions,
+}
+
+/// Internal: everything known about one unique color.
+struct AnalyzedColor {
+    munsell: MunsellColor,
+    iscc_nbs: Option<ColorMetadata>,
+    iscc_nbs_number: Option<u16>,
+    /// Indices of the overlays containing the color
+    overlays: Vec<usize>,
+    /// Best-matching overlay index
+    best_overlay: Option<usize>,
+}
+
+impl ColorAnalyzer {
+    /// Create an analyzer with the built-in overlays and default options.
+    ///
+    /// # Errors
+    /// Returns an error if the converter or the ISCC-NBS classifier cannot be
+    /// created.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            converter: MunsellConverter::new()?,
+            classifier: IsccNbsClassifier::new()?,
+            custom_overlays: None,
+            cache: UnifiedColorCache::with_capacity(DEFAULT_CACHE_CAPACITY),
+            options: AnalysisOptions::default(),
+        })
+    }
+
+    /// Use different options.
+    pub fn with_options(mut self, options: AnalysisOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Use a custom overlay registry for coverage and names.
+    ///
+    /// The built-in overlays describe physical colors; pass
+    /// [`get_screen_registry`](crate::semantic_overlay_data::get_screen_registry)
+    /// to match screen images against the screen-referenced regions.
+    pub fn with_overlay_registry(mut self, registry: SemanticOverlayRegistry) -> Self {
+        self.custom_overlays = Some(registry);
+        self
+    }
+
+    /// Use a conversion cache, for example one shared with other analyzers.
+    pub fn with_cache(mut self, cache: UnifiedColorCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Overlay registry in use.
+    pub fn overlay_registry(&self) -> &SemanticOverlayRegistry {
+        self.custom_overlays.as_ref().unwrap_or_else(|| get_registry())
+    }
+
+    /// Conversion cache in use.
+    pub fn cache(&self) -> &UnifiedColorCache {
+        &self.cache
+    }
+
+    /// Analyze a raw image.
+    ///
+    /// # Errors
+    /// Returns an error if the buffer is too small for its described layout.
+    pub fn analyze_image(&self, image: &ImageBuffer<'_>) -> Result<ColorAnalysis> {
+        image.validate()?;
+        let bytes = image.order.bytes_per_pixel();
+
+        let mut counts: HashMap<[u8; 3], usize> = HashMap::new();
+        for y in 0..image.height {
+            let row = &image.data[y * image.stride..y * image.stride + image.width * bytes];
+            for px in row.chunks_exact(bytes) {
+                let (rgb, alpha) = image.order.read(px);
+                if alpha > self.options.alpha_threshold {
+                    *counts.entry(rgb).or_insert(0) += 1;
+                }
+            }
+        }
+
+        Ok(self.analyze_counts(counts))
+    }
+
+    /// Analyze a palette, counting each entry as one pixel.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::image_analysis::ColorAnalyzer;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let analyzer = ColorAnalyzer::new()?;
+    /// let analysis = analyzer.analyze_palette(&[[30, 90, 100], [30, 90, 100], [128, 128, 128]]);
+    ///
+    /// assert_eq!(analysis.unique_colors, 2);
+    /// assert!(analysis.overlay_coverage.iter().any(|o| o.label == "teal"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn analyze_palette(&self, colors: &[[u8; 3]]) -> ColorAnalysis {
+        let mut counts: HashMap<[u8; 3], usize> = HashMap::new();
+        for &rgb in colors {
+            *counts.entry(rgb).or_insert(0) += 1;
+        }
+        self.analyze_counts(counts)
+    }
+
+    /// Analyze colors with their pixel counts.
+    pub fn analyze_counts(&self, counts: HashMap<[u8; 3], usize>) -> ColorAnalysis {
+        let registry = self.overlay_registry();
+        let colors: Vec<([u8; 3], usize)> = counts.into_iter().collect();
+        let analyzed: Vec<(Option<AnalyzedColor>, usize)> = colors
+            .par_iter()
+            .map(|&(rgb, pixels)| (self.analyze_color(rgb, registry), pixels))
+            .collect();
+
+        let total_pixels: usize = colors.iter().map(|(_, pixels)| pixels).sum();
+        let failed_pixels: usize = analyzed
+            .iter()
+            .filter(|(color, _)| color.is_none())
+            .map(|(_, pixels)| pixels)
+            .sum();
+        let converted = total_pixels - failed_pixels;
+
+        let mut families: HashMap<String, usize> = HashMap::new();
+        let mut categories: HashMap<u16, (String, usize)> = HashMap::new();
+        let mut overlays: HashMap<usize, usize> = HashMap::new();
+        let mut names: HashMap<String, usize> = HashMap::new();
+        let mut value_histogram = Histogram::new(VALUE_BIN_WIDTH);
+        let mut chroma_histogram = Histogram::new(self.options.chroma_bin_width);
+        let (mut value_sum, mut chroma_sum) = (0.0, 0.0);
+
+        for (color, pixels) in &analyzed {
+            let Some(color) = color else {
+                continue;
+            };
+            let pixels = *pixels;
+            let chroma = color.munsell.chroma.unwrap_or(0.0);
+
+            let family = color.munsell.hue_family().unwrap_or_else(|| "N".to_string());
+            *families.entry(family).or_insert(0) += pixels;
+            // Value 10 belongs to the last unit bin
+            value_histogram.add(color.munsell.value, pixels, Some(9));
+            chroma_histogram.add(chroma, pixels, None);
+            value_sum += color.munsell.value * pixels as f64;
+            chroma_sum += chroma * pixels as f64;
+
+            if let (Some(number), Some(metadata)) = (color.iscc_nbs_number, &color.iscc_nbs) {
+                categories
+                    .entry(number)
+                    .or_insert_with(|| (metadata.iscc_nbs_descriptor(), 0))
+                    .1 += pixels;
+            }
+            for &index in &color.overlays {
+                *overlays.entry(index).or_insert(0) += pixels;
+            }
+
+            let name = match (color.best_overlay, &color.iscc_nbs) {
+                (Some(index), _) => Some(registry.all()[index].name.clone()),
+                (None, Some(metadata)) => Some(metadata.iscc_nbs_descriptor()),
+                (None, None) => None,
+            };
+            if let Some(name) = name {
+                *names.entry(name).or_insert(0) += pixels;
+            }
+        }
+
+        let share = |pixels: usize| {
+            if converted > 0 {
+                pixels as f64 / converted as f64
+            } else {
+                0.0
+            }
+        };
+        let label_shares = |counts: HashMap<String, usize>, limit: usize| {
+            let mut shares: Vec<LabelShare> = counts
+                .into_iter()
+                .map(|(label, pixels)| LabelShare {
+                    label,
+                    pixels,
+                    share: share(pixels),
+                })
+                .collect();
+            shares.sort_by(|a, b| b.pixels.cmp(&a.pixels).then_with(|| a.label.cmp(&b.label)));
+            shares.truncate(limit);
+            shares
+        };
+
+        let mut iscc_categories: Vec<CategoryShare> = categories
+            .into_iter()
+            .map(|(iscc_nbs_number, (descriptor, pixels))| CategoryShare {
+                iscc_nbs_number,
+                descriptor,
+                pixels,
+                share: share(pixels),
+            })
+            .collect();
+        iscc_categories.sort_by(|a, b| {
+            b.pixels
+                .cmp(&a.pixels)
+                .then_with(|| a.iscc_nbs_number.cmp(&b.iscc_nbs_number))
+        });
+        iscc_categories.truncate(self.options.top_n);
+
+        let overlay_counts = overlays
+            .into_iter()
+            .map(|(index, pixels)| (registry.all()[index].name.clone(), pixels))
+            .collect();
+
+        ColorAnalysis {
+            total_pixels,
+            unique_colors: colors.len(),
+            failed_pixels,
+            hue_families: label_shares(families, usize::MAX),
+            value_histogram,
+            chroma_histogram,
+            mean_value: if converted > 0 { value_sum / converted as f64 } else { 0.0 },
+            mean_chroma: if converted > 0 { chroma_sum / converted as f64 } else { 0.0 },
+            iscc_categories,
+            overlay_coverage: label_shares(overlay_counts, usize::MAX),
+            named_colors: label_shares(names, self.options.top_n),
+        }
+    }
+
+    /// Internal: ISCC-NBS color number of a Munsell color.
+    ///
+    /// Numbers are not part of the shared cache, so they are classified on
+    /// every lookup.
+    fn color_number(&self, munsell: &MunsellColor) -> Option<u16> {
+        let (hue, chroma) = match (&munsell.hue, munsell.chroma) {
+            (Some(hue), Some(chroma)) => (hue.as_str(), chroma),
+            _ => ("N", 0.0),
+        };
+        self.classifier
+            .classify_munsell_number(hue, munsell.value, chroma)
+            .ok()
+            .flatten()
+    }
+
+    /// Internal: convert and classify one color, or `None` if it fails.
+    fn analyze_color(
+        &self,
+        rgb: [u8; 3],
+        registry: &SemanticOverlayRegistry,
+    ) -> Option<AnalyzedColor> {
+        let (cached, iscc_nbs_number) = match self.cache.get(&rgb) {
+            Some(cached) => {
+                let number = self.color_number(&cached.munsell);
+                (cached, number)
+            }
+            None => {
+                let munsell = self.converter.srgb_to_munsell(rgb).ok()?;
+                let number = self.color_number(&munsell);
+                let iscc_nbs =
+                    number.and_then(|number| self.classifier.color_metadata.get(&number).cloned());
+                let result = CachedColorResult {
+                    rgb,
+                    munsell,
+                    iscc_nbs,
+                };
+                self.cache.insert(rgb, result.clone());
+                (result, number)
+            }
+        };
+
+        let spec = cached
+            .munsell
+            .to_munsell_spec()
+            .unwrap_or_else(|| MunsellSpec::neutral(cached.munsell.value));
+        let overlays: Vec<usize> = registry
+            .all()
+            .iter()
+            .enumerate()
+            .filter(|(_, overlay)| overlay.contains(&spec))
+            .map(|(index, _)| index)
+            .collect();
+        let best_overlay = overlays.iter().copied().min_by(|&a, &b| {
+            let all = registry.all();
+            all[a]
+                .distance_to_centroid(&spec)
+                .total_cmp(&all[b].distance_to_centroid(&spec))
+        });
+
+        Some(AnalyzedColor {
+            munsell: cached.munsell,
+            iscc_nbs: cached.iscc_nbs,
+            iscc_nbs_number,
+            overlays,
+            best_overlay,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_orders_and_stride() {
+        let analyzer = ColorAnalyzer::new().unwrap();
+
+        // 1x2 BGR image with 2 bytes of row padding
+        let data = [40, 30, 200, 0, 0, 200, 30, 40, 0, 0];
+        let image = ImageBuffer::new(&data, 1, 2, ChannelOrder::Bgr).with_stride(5);
+        let analysis = analyzer.analyze_image(&image).unwrap();
+        assert_eq!(analysis.total_pixels, 2);
+        assert_eq!(analysis.unique_colors, 2);
+        let families: Vec<&str> = analysis.hue_families.iter().map(|f| f.label.as_str()).collect();
+        assert!(families.contains(&"R"));
+        assert!(families.contains(&"PB"));
+
+        // ARGB with a transparent pixel
+        let data = [255, 200, 30, 40, 0, 0, 0, 255];
+        let image = ImageBuffer::new(&data, 2, 1, ChannelOrder::Argb);
+        assert_eq!(analyzer.analyze_image(&image).unwrap().total_pixels, 1);
+
+        // Too short for the layout
+        let image = ImageBuffer::new(&data, 3, 1, ChannelOrder::Rgba);
+        assert!(analyzer.analyze_image(&image).is_err());
+    }
+
+    #[test]
+    fn test_weighted_report() {
+        let analyzer = ColorAnalyzer::new().unwrap();
+        let mut counts = HashMap::new();
+        counts.insert([30, 90, 100], 3);
+        counts.insert([128, 128, 128], 1);
+        let analysis = analyzer.analyze_counts(counts);
+
+        assert_eq!(analysis.total_pixels, 4);
+        assert_eq!(analysis.failed_pixels, 0);
+        assert_eq!(analysis.iscc_categories.len(), 2);
+        assert_eq!(analysis.iscc_categories[0].pixels, 3);
+        assert!((analysis.iscc_categories[0].share - 0.75).abs() < 1e-12);
+        assert_eq!(analysis.named_colors[0].label, "teal");
+        assert_eq!(analysis.value_histogram.counts.iter().sum::<usize>(), 4);
+        assert_eq!(analysis.chroma_histogram.counts.iter().sum::<usize>(), 4);
+        assert!(analysis.mean_value > 4.0 && analysis.mean_value < 6.0);
+
+        // Each unique color is converted once and cached
+        assert_eq!(analyzer.cache().len(), 2);
+    }
+
+    #[test]
+    fn test_categories_keyed_by_color_number() {
+        // #247 and #255 are both "strong purplish pink"
+        let analyzer = ColorAnalyzer::new().unwrap();
+        let analysis = analyzer.analyze_palette(&[[144, 32, 104], [216, 136, 200], [216, 136, 200]]);
+
+        let numbers: Vec<u16> = analysis.iscc_categories.iter().map(|c| c.iscc_nbs_number).collect();
+        assert_eq!(numbers, vec![247, 255]);
+        assert_eq!(analysis.iscc_categories[0].descriptor, analysis.iscc_categories[1].descriptor);
+        assert_eq!(analysis.iscc_categories[1].pixels, 1);
+    }
+
+    #[test]
+    fn test_dominant_colors_and_shares() {
+        let analyzer = ColorAnalyzer::new().unwrap();
+        let mut counts = HashMap::new();
+        counts.insert([144, 32, 104], 5);
+        counts.insert([30, 90, 100], 3);
+        counts.insert([216, 136, 200], 3);
+        counts.insert([128, 128, 128], 1);
+        let analysis = analyzer.analyze_counts(counts);
+
+        // Largest first; equal counts in color number order
+        let pixels: Vec<usize> = analysis.iscc_categories.iter().map(|c| c.pixels).collect();
+        assert_eq!(pixels, vec![5, 3, 3, 1]);
+        assert_eq!(analysis.iscc_categories[0].iscc_nbs_number, 255);
+        assert!(analysis.iscc_categories[1].iscc_nbs_number < analysis.iscc_categories[2].iscc_nbs_number);
+
+        // Categories and hue families partition the pixels
+        let total: f64 = analysis.iscc_categories.iter().map(|c| c.share).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+        let total: f64 = analysis.hue_families.iter().map(|f| f.share).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+        assert!((analysis.iscc_categories[0].share - 5.0 / 12.0).abs() < 1e-12);
+    }
+}
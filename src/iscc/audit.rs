@@ -0,0 +1,509 @@
+//! Coverage and overlap audit of the ISCC-NBS polygon data.
+//!
+//! The audit samples the Munsell solid on a regular grid and classifies every
+//! sample with [`IsccNbsClassifier::find_all_colors_at_point`]. It reports
+//! points claimed by no color (gaps), points claimed by several colors
+//! (overlaps), and points where adjacent hue wedges disagree.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::Write;
+
+use geo::Contains;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::MunsellError;
+use super::classifier::IsccNbsClassifier;
+use super::policy::OverlapPolicy;
+
+/// Sampling resolution for a coverage audit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditConfig {
+    /// Step between sampled values (0 to 10 inclusive)
+    pub value_step: f64,
+    /// Step between sampled chromas (0 to `max_chroma` inclusive)
+    pub chroma_step: f64,
+    /// Highest chroma sampled
+    pub max_chroma: f64,
+    /// Hue samples per wedge, spaced evenly and offset from wedge boundaries
+    pub hues_per_wedge: usize,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            value_step: 0.1,
+            chroma_step: 0.2,
+            max_chroma: 20.0,
+            hues_per_wedge: 1,
+        }
+    }
+}
+
+/// A sampled Munsell point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditPoint {
+    /// Munsell hue (e.g. "4.5R")
+    pub hue: String,
+    /// Munsell value
+    pub value: f64,
+    /// Munsell chroma
+    pub chroma: f64,
+}
+
+/// A sampled point claimed by more than one color.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverlapPoint {
+    /// The sampled point
+    pub point: AuditPoint,
+    /// All color numbers containing the point
+    pub colors: Vec<u16>,
+    /// Whether the point lies strictly inside two or more polygons,
+    /// rather than only on shared edges
+    pub interior: bool,
+}
+
+/// Differing classifications on either side of a wedge boundary.
+///
+/// Only points claimed by a polygon whose hue range spans the boundary are
+/// compared; categories that start or end at the boundary are expected to
+/// change there. Aggregated over all sampled (value, chroma) points with the
+/// same outcome.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WedgeDisagreement {
+    /// Wedge before the boundary
+    pub wedge_a: String,
+    /// Wedge after the boundary
+    pub wedge_b: String,
+    /// Colors found in `wedge_a`
+    pub colors_a: Vec<u16>,
+    /// Colors found in `wedge_b`
+    pub colors_b: Vec<u16>,
+    /// Number of sampled (value, chroma) points with this outcome
+    pub points: usize,
+    /// One example (value, chroma) point
+    pub example: (f64, f64),
+}
+
+/// Results of a coverage and overlap audit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoverageAudit {
+    /// Configuration used for sampling
+    pub config: AuditConfig,
+    /// Total number of points classified
+    pub points_tested: usize,
+    /// Points claimed by no color
+    pub unclassified: Vec<AuditPoint>,
+    /// Points claimed by several colors
+    pub overlaps: Vec<OverlapPoint>,
+    /// Classification changes between adjacent wedges within a polygon
+    pub wedge_disagreements: Vec<WedgeDisagreement>,
+    /// Recommended rule for resolving overlaps
+    pub suggested_policy: OverlapPolicy,
+    /// Explanation for the recommendation
+    pub suggestion_reason: String,
+}
+
+impl CoverageAudit {
+    /// Fraction of sampled points claimed by exactly one color.
+    pub fn unambiguous_ratio(&self) -> f64 {
+        if self.points_tested == 0 {
+            return 0.0;
+        }
+        let flagged = self.unclassified.len() + self.overlaps.len();
+        1.0 - flagged as f64 / self.points_tested as f64
+    }
+
+    /// Write the findings as CSV.
+    ///
+    /// Columns are `kind,hue,value,chroma,colors,other_hue,other_colors,count`,
+    /// where `kind` is `unclassified`, `overlap`, `interior_overlap` or
+    /// `wedge_disagreement`, and color lists are separated by `;`.
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<(), MunsellError> {
+        let mut csv = csv::Writer::from_writer(writer);
+        csv.write_record([
+            "kind", "hue", "value", "chroma", "colors", "other_hue", "other_colors", "count",
+        ])?;
+
+        for p in &self.unclassified {
+            csv.write_record([
+                "unclassified",
+                &p.hue,
+                &p.value.to_string(),
+                &p.chroma.to_string(),
+                "",
+                "",
+                "",
+                "1",
+            ])?;
+        }
+
+        for o in &self.overlaps {
+            csv.write_record([
+                if o.interior { "interior_overlap" } else { "overlap" },
+                &o.point.hue,
+                &o.point.value.to_string(),
+                &o.point.chroma.to_string(),
+                &join_colors(&o.colors),
+                "",
+                "",
+                "1",
+            ])?;
+        }
+
+        for d in &self.wedge_disagreements {
+            csv.write_record([
+                "wedge_disagreement",
+                &d.wedge_a,
+                &d.example.0.to_string(),
+                &d.example.1.to_string(),
+                &join_colors(&d.colors_a),
+                &d.wedge_b,
+                &join_colors(&d.colors_b),
+                &d.points.to_string(),
+            ])?;
+        }
+
+        csv.flush()?;
+        Ok(())
+    }
+
+    /// Render the findings as a CSV string.
+    pub fn to_csv(&self) -> Result<String, MunsellError> {
+        let mut buffer = Vec::new();
+        self.write_csv(&mut buffer)?;
+        String::from_utf8(buffer).map_err(|e| MunsellError::ConversionError {
+            message: format!("CSV output is not UTF-8: {}", e),
+        })
+    }
+}
+
+/// Join color numbers with `;` for CSV output.
+fn join_colors(colors: &[u16]) -> String {
+    colors
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Colors found on either side of a wedge boundary.
+type BoundaryOutcome = (Vec<u16>, Vec<u16>);
+
+/// A polygon by color number and polygon group.
+type PolygonKey = (u16, u8);
+
+/// Colors and polygons claiming one sampled point.
+#[derive(Debug, Clone, Default)]
+struct Sample {
+    colors: Vec<u16>,
+    polygons: Vec<PolygonKey>,
+}
+
+/// Per-wedge sampling results.
+struct WedgeScan {
+    wedge_key: String,
+    /// Polygons distributed to the wedge
+    polygons: HashSet<PolygonKey>,
+    /// Samples at each (value index, chroma index) for the first hue sample
+    first_grid: Vec<Vec<Sample>>,
+    /// Samples at each (value index, chroma index) for the last hue sample
+    last_grid: Vec<Vec<Sample>>,
+    unclassified: Vec<AuditPoint>,
+    overlaps: Vec<OverlapPoint>,
+    points_tested: usize,
+}
+
+impl IsccNbsClassifier {
+    /// Audit the polygon data for gaps, overlaps and wedge disagreements.
+    ///
+    /// Samples every wedge in parallel at the resolution given by `config`.
+    /// Hue samples are offset from wedge boundaries so each sample falls in
+    /// the interior of one wedge.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::IsccNbsClassifier;
+    /// use munsellspace::iscc::AuditConfig;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = IsccNbsClassifier::new()?;
+    /// let config = AuditConfig { value_step: 1.0, chroma_step: 2.0, max_chroma: 16.0, hues_per_wedge: 1 };
+    /// let audit = classifier.audit_coverage(&config);
+    ///
+    /// println!("{} gaps, {} overlaps", audit.unclassified.len(), audit.overlaps.len());
+    /// println!("Suggested policy: {:?}", audit.suggested_policy);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn audit_coverage(&self, config: &AuditConfig) -> CoverageAudit {
+        let values = grid_steps(10.0, config.value_step);
+        let chromas = grid_steps(config.max_chroma, config.chroma_step);
+        let hues_per_wedge = config.hues_per_wedge.max(1);
+
+        let scans: Vec<WedgeScan> = self
+            .wedge_system
+            .hue_sequence
+            .par_iter()
+            .map(|wedge_hue| self.scan_wedge(wedge_hue, hues_per_wedge, &values, &chromas))
+            .collect();
+
+        let mut audit = CoverageAudit {
+            config: config.clone(),
+            points_tested: 0,
+            unclassified: Vec::new(),
+            overlaps: Vec::new(),
+            wedge_disagreements: Vec::new(),
+            suggested_policy: OverlapPolicy::FirstMatch,
+            suggestion_reason: String::new(),
+        };
+
+        for (i, scan) in scans.iter().enumerate() {
+            audit.points_tested += scan.points_tested;
+            audit.unclassified.extend(scan.unclassified.iter().cloned());
+            audit.overlaps.extend(scan.overlaps.iter().cloned());
+
+            let next = &scans[(i + 1) % scans.len()];
+            audit
+                .wedge_disagreements
+                .extend(compare_wedges(scan, next, &values, &chromas));
+        }
+
+        let (policy, reason) = suggest_policy(&audit.overlaps);
+        audit.suggested_policy = policy;
+        audit.suggestion_reason = reason;
+        audit
+    }
+
+    /// Sample all points of one wedge.
+    fn scan_wedge(
+        &self,
+        wedge_hue: &str,
+        hues_per_wedge: usize,
+        values: &[f64],
+        chromas: &[f64],
+    ) -> WedgeScan {
+        // Wedge hues are "nF"; classification assigns hues in (n-1, n] to this wedge
+        let (number, family) = self
+            .wedge_system
+            .parse_hue(wedge_hue)
+            .unwrap_or((1.0, "R".to_string()));
+        let wedge_key = self
+            .wedge_system
+            .find_containing_wedge(wedge_hue)
+            .unwrap_or_default();
+        let container = self.wedge_system.get_wedge_polygons(&wedge_key);
+
+        let mut scan = WedgeScan {
+            wedge_key,
+            polygons: container
+                .into_iter()
+                .flatten()
+                .map(|p| (p.color_number, p.polygon_group))
+                .collect(),
+            first_grid: Vec::new(),
+            last_grid: Vec::new(),
+            unclassified: Vec::new(),
+            overlaps: Vec::new(),
+            points_tested: 0,
+        };
+
+        for k in 0..hues_per_wedge {
+            let offset = (k as f64 + 0.5) / hues_per_wedge as f64;
+            let hue = format!("{}{}", trim_float(number - 1.0 + offset), family);
+            let mut grid = Vec::with_capacity(values.len());
+
+            for &value in values {
+                let mut row = Vec::with_capacity(chromas.len());
+                for &chroma in chromas {
+                    scan.points_tested += 1;
+                    let polygons: Vec<PolygonKey> = self
+                        .wedge_system
+                        .find_all_polygons_at_point(&hue, value, chroma)
+                        .iter()
+                        .map(|p| (p.color_number, p.polygon_group))
+                        .collect();
+                    let mut colors: Vec<u16> = polygons.iter().map(|&(number, _)| number).collect();
+                    colors.sort_unstable();
+                    colors.dedup();
+
+                    let point = || AuditPoint {
+                        hue: hue.clone(),
+                        value,
+                        chroma,
+                    };
+
+                    if colors.is_empty() {
+                        scan.unclassified.push(point());
+                    } else if colors.len() > 1 {
+                        let probe = geo::Point::new(chroma, value);
+                        let interior_count = container
+                            .map(|c| c.iter().filter(|p| p.polygon.contains(&probe)).count())
+                            .unwrap_or(0);
+                        scan.overlaps.push(OverlapPoint {
+                            point: point(),
+                            colors: colors.clone(),
+                            interior: interior_count > 1,
+                        });
+                    }
+
+                    row.push(Sample { colors, polygons });
+                }
+                grid.push(row);
+            }
+
+            if k == 0 {
+                scan.first_grid = grid.clone();
+            }
+            if k + 1 == hues_per_wedge {
+                scan.last_grid = grid;
+            }
+        }
+
+        scan
+    }
+}
+
+/// Aggregate differing classifications between two adjacent wedge scans.
+///
+/// A point is compared only if a polygon distributed to both wedges claims it
+/// on either side.
+fn compare_wedges(
+    a: &WedgeScan,
+    b: &WedgeScan,
+    values: &[f64],
+    chromas: &[f64],
+) -> Vec<WedgeDisagreement> {
+    let spanning: HashSet<PolygonKey> = a.polygons.intersection(&b.polygons).copied().collect();
+    let mut grouped: BTreeMap<BoundaryOutcome, (usize, (f64, f64))> = BTreeMap::new();
+
+    for (vi, (row_a, row_b)) in a.last_grid.iter().zip(&b.first_grid).enumerate() {
+        for (ci, (sample_a, sample_b)) in row_a.iter().zip(row_b).enumerate() {
+            let spans = sample_a
+                .polygons
+                .iter()
+                .chain(&sample_b.polygons)
+                .any(|key| spanning.contains(key));
+            if spans && sample_a.colors != sample_b.colors {
+                let entry = grouped
+                    .entry((sample_a.colors.clone(), sample_b.colors.clone()))
+                    .or_insert((0, (values[vi], chromas[ci])));
+                entry.0 += 1;
+            }
+        }
+    }
+
+    grouped
+        .into_iter()
+        .map(|((colors_a, colors_b), (points, example))| WedgeDisagreement {
+            wedge_a: a.wedge_key.clone(),
+            wedge_b: b.wedge_key.clone(),
+            colors_a,
+            colors_b,
+            points,
+            example,
+        })
+        .collect()
+}
+
+/// Recommend an overlap policy from the observed overlaps.
+fn suggest_policy(overlaps: &[OverlapPoint]) -> (OverlapPolicy, String) {
+    if overlaps.is_empty() {
+        return (
+            OverlapPolicy::FirstMatch,
+            "No overlaps found; every classified point has a single color".to_string(),
+        );
+    }
+
+    let interior = overlaps.iter().filter(|o| o.interior).count();
+    if interior == 0 {
+        (
+            OverlapPolicy::LowestNumber,
+            format!(
+                "All {} overlapping points lie on shared edges; a fixed numeric order resolves them deterministically",
+                overlaps.len()
+            ),
+        )
+    } else {
+        (
+            OverlapPolicy::NearestCentroid,
+            format!(
+                "{} of {} overlapping points lie inside several polygons; the nearest polygon centroid is the most representative choice",
+                interior,
+                overlaps.len()
+            ),
+        )
+    }
+}
+
+/// Evenly spaced samples from 0 to `max` inclusive.
+///
+/// `max` is always sampled, even if `step` does not divide it.
+fn grid_steps(max: f64, step: f64) -> Vec<f64> {
+    let step = if step > 0.0 { step } else { max.max(1.0) };
+    let count = ((max / step) + 1e-9).floor() as usize;
+    let mut steps: Vec<f64> = (0..=count)
+        .map(|i| ((i as f64 * step) * 10000.0).round() / 10000.0)
+        .collect();
+    if steps.last().is_some_and(|&last| last < max) {
+        steps.push(max);
+    }
+    steps
+}
+
+/// Format a hue number without trailing zeros.
+fn trim_float(value: f64) -> String {
+    let formatted = format!("{:.4}", value);
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(polygons: &[PolygonKey], grid: Vec<Vec<Sample>>) -> WedgeScan {
+        WedgeScan {
+            wedge_key: String::new(),
+            polygons: polygons.iter().copied().collect(),
+            first_grid: grid.clone(),
+            last_grid: grid,
+            unclassified: Vec::new(),
+            overlaps: Vec::new(),
+            points_tested: 0,
+        }
+    }
+
+    fn sample(polygons: &[PolygonKey]) -> Sample {
+        let mut colors: Vec<u16> = polygons.iter().map(|&(number, _)| number).collect();
+        colors.dedup();
+        Sample { colors, polygons: polygons.to_vec() }
+    }
+
+    #[test]
+    fn test_compare_wedges_ignores_hue_page_edges() {
+        // Color 1 spans the boundary; color 2 ends at it and color 3 starts there
+        let a = scan(&[(1, 1), (2, 1)], vec![vec![sample(&[(1, 1)]), sample(&[(2, 1)])]]);
+        let b = scan(&[(1, 1), (3, 1)], vec![vec![sample(&[(1, 1)]), sample(&[(3, 1)])]]);
+        assert!(compare_wedges(&a, &b, &[5.0], &[2.0, 4.0]).is_empty());
+
+        // An overlap on one side only changes the classification within color 1
+        let a = scan(&[(1, 1), (2, 1)], vec![vec![sample(&[(1, 1), (2, 1)])]]);
+        let b = scan(&[(1, 1), (3, 1)], vec![vec![sample(&[(1, 1)])]]);
+        let disagreements = compare_wedges(&a, &b, &[5.0], &[2.0]);
+        assert_eq!(disagreements.len(), 1);
+        assert_eq!(disagreements[0].colors_a, vec![1, 2]);
+        assert_eq!(disagreements[0].colors_b, vec![1]);
+        assert_eq!(disagreements[0].example, (5.0, 2.0));
+    }
+
+    #[test]
+    fn test_grid_steps_include_max() {
+        assert_eq!(grid_steps(1.0, 0.1).len(), 11);
+        assert_eq!(grid_steps(10.0, 0.5).last(), Some(&10.0));
+        assert_eq!(grid_steps(16.0, 3.0), vec![0.0, 3.0, 6.0, 9.0, 12.0, 15.0, 16.0]);
+        assert_eq!(grid_steps(0.3, 0.1), vec![0.0, 0.1, 0.2, 0.3]);
+    }
+}
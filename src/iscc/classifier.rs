@@ -5,6 +5,7 @@ use crate::error::MunsellError;
 use crate::mechanical_wedges::MechanicalWedgeSystem;
 use super::color::IsccNbsColor;
 use super::metadata::ColorMetadata;
+use super::policy::OverlapPolicy;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
@@ -49,7 +50,7 @@ pub struct IsccNbsClassifier {
     pub wedge_system: MechanicalWedgeSystem,
 
     /// Metadata lookup table mapping color numbers to descriptive information.
-    pub(super) color_metadata: HashMap<u16, ColorMetadata>,
+    pub(crate) color_metadata: HashMap<u16, ColorMetadata>,
 
     /// Thread-safe FIFO cache for performance optimization.
     pub(super) cache: Arc<RwLock<HashMap<(String, i32, i32), Option<u16>>>>,
@@ -59,6 +60,9 @@ pub struct IsccNbsClassifier {
 
     /// Maximum number of entries to retain in the cache.
     pub(super) cache_max_size: usize,
+
+    /// Rule for choosing a color when several polygons contain a point.
+    pub(super) overlap_policy: OverlapPolicy,
 }
 
 impl IsccNbsClassifier {
@@ -99,6 +103,7 @@ impl IsccNbsClassifier {
             cache: Arc::new(RwLock::new(HashMap::new())),
             cache_order: Arc::new(RwLock::new(std::collections::VecDeque::new())),
             cache_max_size: 256,
+            overlap_policy: OverlapPolicy::default(),
         })
     }
 
@@ -117,6 +122,7 @@ impl IsccNbsClassifier {
             cache: Arc::new(RwLock::new(HashMap::new())),
             cache_order: Arc::new(RwLock::new(std::collections::VecDeque::new())),
             cache_max_size: 256,
+            overlap_policy: OverlapPolicy::default(),
         })
     }
 
@@ -151,17 +157,30 @@ impl IsccNbsClassifier {
     }
 
     /// Classify a Munsell color using the ISCC-NBS system.
+    ///
+    /// Points inside several polygons are resolved with the classifier's
+    /// [`OverlapPolicy`] (first match by default).
     pub fn classify_munsell(
         &self,
         hue: &str,
         value: f64,
         chroma: f64,
     ) -> Result<Option<ColorMetadata>, MunsellError> {
+        Ok(self
+            .classify_munsell_number(hue, value, chroma)?
+            .and_then(|num| self.build_result(num)))
+    }
+
+    /// Internal: ISCC-NBS color number of a Munsell color, resolved as in
+    /// [`classify_munsell`](Self::classify_munsell).
+    pub(crate) fn classify_munsell_number(
+        &self,
+        hue: &str,
+        value: f64,
+        chroma: f64,
+    ) -> Result<Option<u16>, MunsellError> {
         if self.is_achromatic(hue) {
-            if let Some(color_number) = self.classify_achromatic(value) {
-                return Ok(self.build_result(color_number));
-            }
-            return Ok(None);
+            return Ok(self.classify_achromatic(value));
         }
 
         let rounded_value = (value * 10000.0).round() / 10000.0;
@@ -177,21 +196,23 @@ impl IsccNbsClassifier {
         {
             let cache = self.cache.read().unwrap();
             if let Some(&cached_color_number) = cache.get(&cache_key) {
-                return Ok(cached_color_number.and_then(|num| self.build_result(num)));
+                return Ok(cached_color_number);
             }
         }
 
         // Use the mechanical wedge system for classification
-        if let Some(color) = self
-            .wedge_system
-            .classify_color(hue, rounded_value, rounded_chroma)
-        {
-            self.cache_result(cache_key, Some(color.color_number));
-            return Ok(self.build_result(color.color_number));
-        }
-
-        self.cache_result(cache_key, None);
-        Ok(None)
+        let color_number = match self.overlap_policy {
+            OverlapPolicy::FirstMatch => self
+                .wedge_system
+                .classify_color(hue, rounded_value, rounded_chroma)
+                .map(|color| color.color_number),
+            _ => self
+                .resolve_overlap(hue, rounded_value, rounded_chroma)
+                .color_number(),
+        };
+
+        self.cache_result(cache_key, color_number);
+        Ok(color_number)
     }
 
     /// Find all ISCC-NBS colors that contain a given point.
@@ -15,7 +15,7 @@ impl IsccNbsClassifier {
     /// instead for unified access to standard, extended, and semantic color names.
     #[deprecated(
         since = "1.2.0",
-        note = "Use ColorClassifier::classify_srgb() for unified color naming. This method will be removed in v2.0.0."
+        note = "Use ColorClassifier::classify_srgb() for unified color naming. This method will be removed in v3.0.0."
     )]
     pub fn classify_srgb(&self, rgb: [u8; 3]) -> Result<Option<ColorMetadata>, MunsellError> {
         use crate::MunsellConverter;
@@ -32,7 +32,7 @@ impl IsccNbsClassifier {
     /// instead for unified access to standard, extended, and semantic color names.
     #[deprecated(
         since = "1.2.0",
-        note = "Use ColorClassifier::classify_lab() for unified color naming. This method will be removed in v2.0.0."
+        note = "Use ColorClassifier::classify_lab() for unified color naming. This method will be removed in v3.0.0."
     )]
     pub fn classify_lab(&self, lab: [f64; 3]) -> Result<Option<ColorMetadata>, MunsellError> {
         use crate::MunsellConverter;
@@ -49,7 +49,7 @@ impl IsccNbsClassifier {
     /// instead for unified access to standard, extended, and semantic color names.
     #[deprecated(
         since = "1.2.0",
-        note = "Use ColorClassifier::classify_hex() for unified color naming. This method will be removed in v2.0.0."
+        note = "Use ColorClassifier::classify_hex() for unified color naming. This method will be removed in v3.0.0."
     )]
     #[allow(deprecated)]
     pub fn classify_hex(&self, hex: &str) -> Result<Option<ColorMetadata>, MunsellError> {
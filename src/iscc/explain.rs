@@ -0,0 +1,128 @@
+//! Structured classification traces for the ISCC-NBS classifier.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::MunsellError;
+use crate::mechanical_wedges::PolygonTest;
+use super::classifier::IsccNbsClassifier;
+
+/// Step-by-step record of how a Munsell color was classified.
+///
+/// Produced by [`IsccNbsClassifier::classify_explained`]. The trace is
+/// serializable so it can be logged or returned from a service.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassificationTrace {
+    /// Hue after trimming whitespace
+    pub hue: String,
+    /// Value rounded to the classifier's 4-decimal precision
+    pub value: f64,
+    /// Chroma rounded to the classifier's 4-decimal precision
+    pub chroma: f64,
+    /// Whether the hue was recognized as neutral ("N")
+    pub achromatic: bool,
+    /// Color number assigned by the achromatic value bands, if applied
+    pub achromatic_override: Option<u16>,
+    /// Wedge bracketing the hue according to `find_wedge_for_hue`
+    pub wedge_key: Option<String>,
+    /// Wedge whose polygons were actually tested.
+    ///
+    /// Classification assigns hues in `(n-1, n]` to wedge `n`, which can differ
+    /// from the bracketing wedge reported in `wedge_key`.
+    pub searched_wedge: Option<String>,
+    /// Every candidate polygon tested, in container order
+    pub candidates: Vec<PolygonTest>,
+    /// Whether several colors contained the point, so the decision
+    /// depended on the classifier's overlap policy
+    pub ambiguous: bool,
+    /// Final ISCC-NBS color number, if any
+    pub color_number: Option<u16>,
+    /// Descriptor of the final color (e.g. "vivid red")
+    pub descriptor: Option<String>,
+}
+
+impl ClassificationTrace {
+    /// Color numbers of all candidates that contain the point.
+    pub fn matched_colors(&self) -> Vec<u16> {
+        self.candidates
+            .iter()
+            .filter(|c| c.matched)
+            .map(|c| c.color_number)
+            .collect()
+    }
+}
+
+impl IsccNbsClassifier {
+    /// Classify a Munsell color and return a trace of every decision made.
+    ///
+    /// Follows the same path as [`classify_munsell`](Self::classify_munsell)
+    /// (bypassing the cache) and records the normalized input, the wedge
+    /// selected, every candidate polygon with the edges bounding the point,
+    /// any achromatic override, and the final decision under the configured
+    /// [`OverlapPolicy`](super::OverlapPolicy).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::IsccNbsClassifier;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = IsccNbsClassifier::new()?;
+    /// let trace = classifier.classify_explained("5R", 4.0, 14.0)?;
+    ///
+    /// assert_eq!(trace.color_number, Some(11));
+    /// println!("{}", serde_json::to_string_pretty(&trace)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn classify_explained(
+        &self,
+        hue: &str,
+        value: f64,
+        chroma: f64,
+    ) -> Result<ClassificationTrace, MunsellError> {
+        let hue = hue.trim().to_string();
+        let value = (value * 10000.0).round() / 10000.0;
+        let chroma = (chroma * 10000.0).round() / 10000.0;
+
+        let mut trace = ClassificationTrace {
+            hue,
+            value,
+            chroma,
+            achromatic: false,
+            achromatic_override: None,
+            wedge_key: None,
+            searched_wedge: None,
+            candidates: Vec::new(),
+            ambiguous: false,
+            color_number: None,
+            descriptor: None,
+        };
+
+        if crate::constants::is_achromatic_hue(&trace.hue) {
+            trace.achromatic = true;
+            trace.achromatic_override = crate::constants::get_achromatic_color_number(value)
+                .filter(|n| self.color_metadata.contains_key(n));
+            trace.color_number = trace.achromatic_override;
+        } else {
+            trace.wedge_key = self.wedge_system.find_wedge_for_hue(&trace.hue);
+            trace.searched_wedge = self.wedge_system.find_containing_wedge(&trace.hue);
+
+            if let Some(wedge) = &trace.searched_wedge {
+                trace.candidates = self
+                    .wedge_system
+                    .explain_point(wedge, value, chroma)
+                    .unwrap_or_default();
+            }
+
+            let resolved = self.resolve_overlap(&trace.hue, value, chroma);
+            trace.ambiguous = resolved.ambiguous;
+            trace.color_number = resolved.color_number();
+        }
+
+        trace.descriptor = trace
+            .color_number
+            .and_then(|n| self.color_metadata.get(&n))
+            .map(|m| m.iscc_nbs_descriptor());
+
+        Ok(trace)
+    }
+}
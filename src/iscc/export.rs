@@ -0,0 +1,274 @@
+//! GeoJSON and SVG export of ISCC-NBS polygon regions.
+//!
+//! ISCC-NBS regions are defined as polygons in the value/chroma plane of each
+//! hue page. These exports make the polygon data inspectable with standard
+//! tooling (GIS viewers, browsers) so classification boundaries, gaps and
+//! overlaps can be audited visually.
+//!
+//! Coordinates follow the internal polygon convention: `x = chroma`, `y = value`.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use geo::Centroid;
+use serde_json::{json, Value};
+
+use crate::error::MunsellError;
+use crate::reverse_conversion::ReverseConverter;
+use crate::semantic_overlay::{parse_hue_to_number, MunsellSpec};
+use super::classifier::IsccNbsClassifier;
+use super::color::IsccNbsColor;
+
+/// Pixels per Munsell unit used when rendering hue pages.
+const SVG_SCALE: f64 = 40.0;
+
+/// Margin around the plot area in pixels.
+const SVG_MARGIN: f64 = 30.0;
+
+/// Fill opacity of hue page polygons.
+const SVG_FILL_OPACITY: f64 = 0.35;
+
+impl IsccNbsClassifier {
+    /// Export every ISCC-NBS polygon as a GeoJSON `FeatureCollection`.
+    ///
+    /// Each polygon becomes one `Feature` with `[chroma, value]` coordinates and
+    /// properties `color_number`, `polygon_group`, `name`, `hue_start` and
+    /// `hue_end`. Polygons shared by several wedges are emitted only once.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::IsccNbsClassifier;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = IsccNbsClassifier::new()?;
+    /// let geojson = classifier.polygons_to_geojson();
+    /// assert_eq!(geojson["type"], "FeatureCollection");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn polygons_to_geojson(&self) -> Value {
+        let mut seen = HashSet::new();
+        let mut polygons: Vec<&IsccNbsColor> = self
+            .wedge_system
+            .wedge_containers
+            .values()
+            .flatten()
+            .filter(|p| {
+                seen.insert((
+                    p.color_number,
+                    p.polygon_group,
+                    p.hue_range.0.clone(),
+                    p.hue_range.1.clone(),
+                ))
+            })
+            .collect();
+
+        polygons.sort_by(|a, b| {
+            (a.color_number, a.polygon_group, &a.hue_range)
+                .cmp(&(b.color_number, b.polygon_group, &b.hue_range))
+        });
+
+        let features: Vec<Value> = polygons.iter().map(|p| self.polygon_feature(p)).collect();
+
+        json!({
+            "type": "FeatureCollection",
+            "features": features,
+        })
+    }
+
+    /// Export the polygons of the wedge used to classify `hue` as GeoJSON.
+    ///
+    /// The collection carries the wedge key as a `wedge` foreign member.
+    /// Returns `None` if the hue cannot be parsed or has no wedge.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::IsccNbsClassifier;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = IsccNbsClassifier::new()?;
+    /// let page = classifier.wedge_to_geojson("5R").expect("5R has a wedge");
+    /// assert!(!page["features"].as_array().unwrap().is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wedge_to_geojson(&self, hue: &str) -> Option<Value> {
+        let wedge_key = self.wedge_system.find_containing_wedge(hue)?;
+        let polygons = self.wedge_system.get_wedge_polygons(&wedge_key)?;
+
+        let features: Vec<Value> = polygons.iter().map(|p| self.polygon_feature(p)).collect();
+
+        Some(json!({
+            "type": "FeatureCollection",
+            "wedge": wedge_key,
+            "features": features,
+        }))
+    }
+
+    /// Render the hue page used to classify `hue` as an SVG document.
+    ///
+    /// Chroma runs left to right and value bottom to top. Each polygon is filled
+    /// with the sRGB colour of its centroid at `hue`; when the centroid lies
+    /// outside the renotation data, chroma is reduced until a colour is found.
+    /// Fills are translucent so overlapping polygons stay visible. Each polygon
+    /// carries a `<title>` with its colour number and name.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::InvalidNotation`] if `hue` is not a chromatic
+    /// Munsell hue, or an error from the reverse converter.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::IsccNbsClassifier;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = IsccNbsClassifier::new()?;
+    /// let svg = classifier.hue_page_svg("5R")?;
+    /// assert!(svg.starts_with("<svg"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn hue_page_svg(&self, hue: &str) -> Result<String, MunsellError> {
+        let reverse = ReverseConverter::new()?;
+        self.render_hue_page(hue, &reverse)
+    }
+
+    /// Render one SVG hue page for each of the 100 reference hues (1R through 10RP).
+    ///
+    /// Returns `(hue, svg)` pairs in hue-circle order.
+    pub fn hue_page_svgs(&self) -> Result<Vec<(String, String)>, MunsellError> {
+        let reverse = ReverseConverter::new()?;
+        self.wedge_system
+            .hue_sequence
+            .iter()
+            .map(|hue| Ok((hue.clone(), self.render_hue_page(hue, &reverse)?)))
+            .collect()
+    }
+
+    /// Build a GeoJSON feature for a single polygon.
+    fn polygon_feature(&self, polygon: &IsccNbsColor) -> Value {
+        let ring: Vec<[f64; 2]> = polygon
+            .polygon
+            .exterior()
+            .coords()
+            .map(|c| [c.x, c.y])
+            .collect();
+
+        let name = self
+            .color_metadata
+            .get(&polygon.color_number)
+            .map(|m| m.iscc_nbs_descriptor());
+
+        json!({
+            "type": "Feature",
+            "geometry": {
+                "type": "Polygon",
+                "coordinates": [ring],
+            },
+            "properties": {
+                "color_number": polygon.color_number,
+                "polygon_group": polygon.polygon_group,
+                "name": name,
+                "hue_start": polygon.hue_range.0,
+                "hue_end": polygon.hue_range.1,
+            },
+        })
+    }
+
+    /// Render a hue page with a shared reverse converter.
+    fn render_hue_page(&self, hue: &str, reverse: &ReverseConverter) -> Result<String, MunsellError> {
+        let invalid = || MunsellError::InvalidNotation {
+            notation: hue.to_string(),
+            reason: "Expected a chromatic hue such as '5R'".to_string(),
+        };
+
+        let hue_number = parse_hue_to_number(hue).ok_or_else(invalid)?;
+        let wedge_key = self.wedge_system.find_containing_wedge(hue).ok_or_else(invalid)?;
+        let polygons = self
+            .wedge_system
+            .get_wedge_polygons(&wedge_key)
+            .ok_or_else(invalid)?;
+
+        let max_chroma = polygons
+            .iter()
+            .flat_map(|p| p.polygon.exterior().coords().map(|c| c.x))
+            .fold(0.0_f64, f64::max)
+            .max(1.0);
+
+        let width = max_chroma * SVG_SCALE + 2.0 * SVG_MARGIN;
+        let height = 10.0 * SVG_SCALE + 2.0 * SVG_MARGIN;
+        let to_x = |chroma: f64| SVG_MARGIN + chroma * SVG_SCALE;
+        let to_y = |value: f64| SVG_MARGIN + (10.0 - value) * SVG_SCALE;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.0}" height="{h:.0}" viewBox="0 0 {w:.0} {h:.0}">"#,
+            w = width,
+            h = height
+        );
+        let _ = writeln!(svg, "<title>{} ({})</title>", escape_xml(hue), escape_xml(&wedge_key));
+
+        for polygon in polygons {
+            let fill = polygon_fill(polygon, hue_number, reverse);
+            let points: Vec<String> = polygon
+                .polygon
+                .exterior()
+                .coords()
+                .map(|c| format!("{:.1},{:.1}", to_x(c.x), to_y(c.y)))
+                .collect();
+            let name = self
+                .color_metadata
+                .get(&polygon.color_number)
+                .map(|m| m.iscc_nbs_descriptor())
+                .unwrap_or_default();
+
+            let _ = writeln!(
+                svg,
+                r#"<polygon points="{}" fill="{}" fill-opacity="{}" stroke="black" stroke-width="1"><title>{} {}</title></polygon>"#,
+                points.join(" "),
+                fill,
+                SVG_FILL_OPACITY,
+                polygon.color_number,
+                escape_xml(&name)
+            );
+        }
+
+        // Axes
+        let _ = writeln!(
+            svg,
+            r#"<line x1="{x0:.1}" y1="{y0:.1}" x2="{x1:.1}" y2="{y0:.1}" stroke="gray"/>"#,
+            x0 = to_x(0.0),
+            x1 = to_x(max_chroma),
+            y0 = to_y(0.0)
+        );
+        let _ = writeln!(
+            svg,
+            r#"<line x1="{x0:.1}" y1="{y0:.1}" x2="{x0:.1}" y2="{y1:.1}" stroke="gray"/>"#,
+            x0 = to_x(0.0),
+            y0 = to_y(0.0),
+            y1 = to_y(10.0)
+        );
+        svg.push_str("</svg>\n");
+
+        Ok(svg)
+    }
+}
+
+/// Display fill for a polygon from its centroid at the given hue number.
+fn polygon_fill(polygon: &IsccNbsColor, hue_number: f64, reverse: &ReverseConverter) -> String {
+    let Some(centroid) = polygon.polygon.centroid() else {
+        return "none".to_string();
+    };
+    let spec = MunsellSpec::new(hue_number, centroid.y().clamp(0.0, 10.0), centroid.x());
+    let [r, g, b] = spec.display_srgb(reverse);
+    format!("#{:02X}{:02X}{:02X}", r, g, b)
+}
+
+/// Escape the XML special characters in text content.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -27,7 +27,7 @@ use crate::constants::get_color_ish;
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ColorMetadata {
     /// Base color name from ISCC-NBS data (e.g., "red", "blue", "yellow").
     ///
@@ -34,6 +34,10 @@ mod metadata;
 mod color;
 mod classifier;
 mod data_loader;
+mod export;
+mod explain;
+mod policy;
+mod audit;
 pub mod validation;
 
 #[cfg(test)]
@@ -43,4 +47,7 @@ mod tests;
 pub use metadata::ColorMetadata;
 pub use color::IsccNbsColor;
 pub use classifier::IsccNbsClassifier;
+pub use explain::ClassificationTrace;
+pub use policy::{OverlapPolicy, PolicyClassification};
+pub use audit::{AuditConfig, AuditPoint, CoverageAudit, OverlapPoint, WedgeDisagreement};
 pub use validation::ValidationError;
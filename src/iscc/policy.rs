@@ -0,0 +1,178 @@
+//! Overlap resolution policies for points claimed by several ISCC-NBS colors.
+
+use geo::Centroid;
+use serde::{Deserialize, Serialize};
+
+use crate::error::MunsellError;
+use super::classifier::IsccNbsClassifier;
+use super::color::IsccNbsColor;
+use super::metadata::ColorMetadata;
+
+/// Rule for choosing a color when several ISCC-NBS polygons contain a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum OverlapPolicy {
+    /// First matching polygon in wedge container order
+    #[default]
+    FirstMatch,
+    /// Matching color with the lowest ISCC-NBS number
+    LowestNumber,
+    /// Matching color with the highest ISCC-NBS number
+    HighestNumber,
+    /// Matching polygon whose centroid is closest in the value/chroma plane
+    NearestCentroid,
+    /// Keep every matching color
+    AllMatches,
+}
+
+/// Result of classifying a point under an [`OverlapPolicy`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyClassification {
+    /// Color numbers chosen by the policy: one entry, or every match for
+    /// [`OverlapPolicy::AllMatches`], or none if the point is unclassified
+    pub selected: Vec<u16>,
+    /// Every color number containing the point, sorted ascending
+    pub candidates: Vec<u16>,
+    /// Whether more than one color contains the point
+    pub ambiguous: bool,
+    /// Policy used to choose `selected`
+    pub policy: OverlapPolicy,
+}
+
+impl PolicyClassification {
+    /// The chosen color number (the first, for [`OverlapPolicy::AllMatches`]).
+    pub fn color_number(&self) -> Option<u16> {
+        self.selected.first().copied()
+    }
+}
+
+impl IsccNbsClassifier {
+    /// Return a classifier that resolves overlapping polygons with `policy`.
+    ///
+    /// The policy applies to [`classify_munsell`](Self::classify_munsell) and
+    /// every method built on it. Under [`OverlapPolicy::AllMatches`] those
+    /// single-result methods return the lowest-numbered match; use
+    /// [`classify_resolved`](Self::classify_resolved) to obtain all of them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::IsccNbsClassifier;
+    /// use munsellspace::iscc::OverlapPolicy;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = IsccNbsClassifier::new()?
+    ///     .with_overlap_policy(OverlapPolicy::NearestCentroid);
+    /// assert_eq!(classifier.overlap_policy(), OverlapPolicy::NearestCentroid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_overlap_policy(mut self, policy: OverlapPolicy) -> Self {
+        self.overlap_policy = policy;
+        // Cached results were resolved under the previous policy
+        self.cache.write().unwrap().clear();
+        self.cache_order.write().unwrap().clear();
+        self
+    }
+
+    /// The policy used to resolve overlapping polygons.
+    pub fn overlap_policy(&self) -> OverlapPolicy {
+        self.overlap_policy
+    }
+
+    /// Classify a Munsell color under the configured overlap policy.
+    ///
+    /// Unlike [`classify_munsell`](Self::classify_munsell), the result lists
+    /// every candidate and flags points claimed by several colors, so callers
+    /// can detect classifications that depend on the tie-break.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::IsccNbsClassifier;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = IsccNbsClassifier::new()?;
+    /// let result = classifier.classify_resolved("5R", 4.0, 14.0)?;
+    /// assert_eq!(result.color_number(), Some(11));
+    /// assert!(!result.ambiguous);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn classify_resolved(
+        &self,
+        hue: &str,
+        value: f64,
+        chroma: f64,
+    ) -> Result<PolicyClassification, MunsellError> {
+        if crate::constants::is_achromatic_hue(hue) {
+            let candidates: Vec<u16> = crate::constants::get_achromatic_color_number(value)
+                .filter(|n| self.color_metadata.contains_key(n))
+                .into_iter()
+                .collect();
+            return Ok(PolicyClassification {
+                selected: candidates.clone(),
+                candidates,
+                ambiguous: false,
+                policy: self.overlap_policy,
+            });
+        }
+
+        let value = (value * 10000.0).round() / 10000.0;
+        let chroma = (chroma * 10000.0).round() / 10000.0;
+        Ok(self.resolve_overlap(hue, value, chroma))
+    }
+
+    /// Classify under the configured policy and return the chosen metadata.
+    pub fn classify_resolved_metadata(
+        &self,
+        hue: &str,
+        value: f64,
+        chroma: f64,
+    ) -> Result<(PolicyClassification, Option<ColorMetadata>), MunsellError> {
+        let result = self.classify_resolved(hue, value, chroma)?;
+        let metadata = result
+            .color_number()
+            .and_then(|n| self.color_metadata.get(&n).cloned());
+        Ok((result, metadata))
+    }
+
+    /// Apply the overlap policy to the polygons containing a chromatic point.
+    pub(super) fn resolve_overlap(&self, hue: &str, value: f64, chroma: f64) -> PolicyClassification {
+        let polygons = self.wedge_system.find_all_polygons_at_point(hue, value, chroma);
+
+        let mut candidates: Vec<u16> = polygons.iter().map(|p| p.color_number).collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        let selected = match self.overlap_policy {
+            OverlapPolicy::FirstMatch => polygons.first().map(|p| vec![p.color_number]),
+            OverlapPolicy::LowestNumber => candidates.first().map(|&n| vec![n]),
+            OverlapPolicy::HighestNumber => candidates.last().map(|&n| vec![n]),
+            OverlapPolicy::NearestCentroid => {
+                nearest_centroid(&polygons, value, chroma).map(|n| vec![n])
+            }
+            OverlapPolicy::AllMatches => Some(candidates.clone()),
+        }
+        .unwrap_or_default();
+
+        PolicyClassification {
+            ambiguous: candidates.len() > 1,
+            selected,
+            candidates,
+            policy: self.overlap_policy,
+        }
+    }
+}
+
+/// Color number of the polygon whose centroid is closest to (chroma, value).
+///
+/// Ties go to the lower color number.
+fn nearest_centroid(polygons: &[&IsccNbsColor], value: f64, chroma: f64) -> Option<u16> {
+    polygons
+        .iter()
+        .filter_map(|p| {
+            let c = p.polygon.centroid()?;
+            let distance = (c.x() - chroma).hypot(c.y() - value);
+            Some((distance, p.color_number))
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)))
+        .map(|(_, n)| n)
+}
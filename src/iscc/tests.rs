@@ -198,3 +198,219 @@ fn test_send_sync_traits() {
     assert_send::<Arc<IsccNbsClassifier>>();
     assert_sync::<Arc<IsccNbsClassifier>>();
 }
+
+#[test]
+fn test_polygons_to_geojson_features() {
+    let classifier = IsccNbsClassifier::new().expect("Failed to create classifier");
+    let geojson = classifier.polygons_to_geojson();
+
+    assert_eq!(geojson["type"], "FeatureCollection");
+    let features = geojson["features"].as_array().expect("features array");
+    assert_eq!(features.len(), crate::constants::get_polygon_definitions().len());
+
+    let first = &features[0];
+    assert_eq!(first["geometry"]["type"], "Polygon");
+    assert_eq!(first["properties"]["color_number"], 1);
+    assert!(first["properties"]["name"].is_string());
+
+    // Rings are closed and use [chroma, value] pairs
+    let ring = first["geometry"]["coordinates"][0].as_array().unwrap();
+    assert_eq!(ring.first(), ring.last());
+    for point in ring {
+        let value = point[1].as_f64().unwrap();
+        assert!((0.0..=10.0).contains(&value));
+    }
+}
+
+#[test]
+fn test_wedge_to_geojson_matches_classification_wedge() {
+    let classifier = IsccNbsClassifier::new().expect("Failed to create classifier");
+    let page = classifier.wedge_to_geojson("5R").expect("5R wedge");
+
+    let expected = classifier.wedge_system.find_containing_wedge("5R").unwrap();
+    assert_eq!(page["wedge"], expected.as_str());
+
+    let numbers: Vec<u64> = page["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["properties"]["color_number"].as_u64().unwrap())
+        .collect();
+    assert!(numbers.contains(&11), "vivid red should be on the 5R page");
+
+    assert!(classifier.wedge_to_geojson("not a hue").is_none());
+}
+
+#[test]
+fn test_hue_page_svg_rendering() {
+    let classifier = IsccNbsClassifier::new().expect("Failed to create classifier");
+    let svg = classifier.hue_page_svg("5R").expect("SVG page");
+
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains("<polygon"));
+    assert!(svg.contains("fill=\"#"));
+    assert!(svg.contains("fill-opacity=\"0.35\""));
+
+    assert!(classifier.hue_page_svg("N").is_err());
+}
+
+#[test]
+fn test_classify_explained_matches_classification() {
+    let classifier = IsccNbsClassifier::new().expect("Failed to create classifier");
+
+    for &(hue, value, chroma) in &[("5R", 4.0, 14.0), ("10YR", 7.0, 6.0), ("5PB", 3.0, 8.0)] {
+        let trace = classifier.classify_explained(hue, value, chroma).unwrap();
+        let expected = classifier
+            .classify_munsell(hue, value, chroma)
+            .unwrap()
+            .map(|m| m.iscc_nbs_descriptor());
+
+        assert!(!trace.achromatic);
+        assert!(trace.wedge_key.is_some());
+        assert!(!trace.candidates.is_empty());
+        assert_eq!(trace.descriptor, expected);
+        assert_eq!(
+            trace.matched_colors(),
+            classifier.find_all_colors_at_point(hue, value, chroma).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_classify_explained_boundary_and_achromatic() {
+    let classifier = IsccNbsClassifier::new().expect("Failed to create classifier");
+
+    // Chroma 11 lies on the edge shared by colors 12 and 15; (min, max] assigns it to 15
+    let trace = classifier.classify_explained("5R", 4.0, 11.0).unwrap();
+    assert_eq!(trace.color_number, Some(15));
+    let matched = trace.candidates.iter().find(|c| c.matched).unwrap();
+    assert!(!matched.interior);
+    let bounds = matched.bounds.as_ref().unwrap();
+    assert_eq!(bounds.chroma_max, 11.0);
+    assert_eq!(bounds.chroma_rule, crate::mechanical_wedges::BoundaryRule::HalfOpen);
+    let neighbour = trace.candidates.iter().find(|c| c.color_number == 12).unwrap();
+    assert!(!neighbour.matched);
+    assert_eq!(neighbour.bounds.as_ref().unwrap().chroma_min, 11.0);
+
+    let trace = classifier.classify_explained(" N ", 5.0, 0.0).unwrap();
+    assert!(trace.achromatic);
+    assert_eq!(trace.hue, "N");
+    assert_eq!(trace.achromatic_override, Some(265));
+    assert_eq!(trace.color_number, Some(265));
+    assert!(trace.candidates.is_empty());
+
+    let json = serde_json::to_string(&trace).unwrap();
+    let round_trip: ClassificationTrace = serde_json::from_str(&json).unwrap();
+    assert_eq!(round_trip, trace);
+}
+
+#[test]
+fn test_audit_coverage_reports() {
+    let classifier = IsccNbsClassifier::new().expect("Failed to create classifier");
+    let config = AuditConfig {
+        value_step: 0.5,
+        chroma_step: 1.0,
+        max_chroma: 16.0,
+        hues_per_wedge: 2,
+    };
+    let audit = classifier.audit_coverage(&config);
+
+    assert_eq!(audit.points_tested, 100 * 2 * 21 * 17);
+    assert!(audit.unambiguous_ratio() > 0.9);
+
+    for point in &audit.unclassified {
+        assert!(classifier
+            .find_all_colors_at_point(&point.hue, point.value, point.chroma)
+            .unwrap()
+            .is_empty());
+    }
+    for overlap in &audit.overlaps {
+        assert!(overlap.colors.len() > 1);
+    }
+
+    // Polygons spanning a boundary claim their points on both sides
+    for d in &audit.wedge_disagreements {
+        assert_ne!(d.colors_a, d.colors_b);
+        assert!(d.colors_a.iter().any(|c| d.colors_b.contains(c)));
+        assert!(d.points > 0);
+    }
+
+    let csv = audit.to_csv().unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("kind,hue,value,chroma,colors,other_hue,other_colors,count")
+    );
+    assert_eq!(
+        lines.count(),
+        audit.unclassified.len() + audit.overlaps.len() + audit.wedge_disagreements.len()
+    );
+}
+
+#[test]
+fn test_overlap_policies_on_shared_edge() {
+    // 2.5YR 6.5/7.0 lies on an edge claimed by colors 39 and 53
+    let point = ("2.5YR", 6.5, 7.0);
+
+    let default = IsccNbsClassifier::new().expect("Failed to create classifier");
+    assert_eq!(default.overlap_policy(), OverlapPolicy::FirstMatch);
+    let first = default.classify_resolved(point.0, point.1, point.2).unwrap();
+    assert!(first.ambiguous);
+    assert_eq!(first.candidates, vec![39, 53]);
+    assert_eq!(first.selected.len(), 1);
+    assert_eq!(
+        default
+            .classify_munsell(point.0, point.1, point.2)
+            .unwrap()
+            .map(|m| m.iscc_nbs_descriptor()),
+        default.classify_resolved_metadata(point.0, point.1, point.2).unwrap().1
+            .map(|m| m.iscc_nbs_descriptor())
+    );
+
+    let lowest = IsccNbsClassifier::new()
+        .unwrap()
+        .with_overlap_policy(OverlapPolicy::LowestNumber);
+    assert_eq!(lowest.classify_resolved(point.0, point.1, point.2).unwrap().selected, vec![39]);
+
+    let highest = IsccNbsClassifier::new()
+        .unwrap()
+        .with_overlap_policy(OverlapPolicy::HighestNumber);
+    assert_eq!(highest.classify_resolved(point.0, point.1, point.2).unwrap().selected, vec![53]);
+    let metadata = highest.classify_munsell(point.0, point.1, point.2).unwrap().unwrap();
+    assert_eq!(
+        Some(metadata.iscc_nbs_descriptor()),
+        highest.color_metadata.get(&53).map(|m| m.iscc_nbs_descriptor())
+    );
+
+    let all = IsccNbsClassifier::new()
+        .unwrap()
+        .with_overlap_policy(OverlapPolicy::AllMatches);
+    let result = all.classify_resolved(point.0, point.1, point.2).unwrap();
+    assert_eq!(result.selected, vec![39, 53]);
+    assert_eq!(result.color_number(), Some(39));
+
+    let nearest = IsccNbsClassifier::new()
+        .unwrap()
+        .with_overlap_policy(OverlapPolicy::NearestCentroid);
+    // Centroids: 39 at chroma 6.0 / value 5.5 (distance 1.41), 53 at
+    // chroma 8.21 / value 6.57 (distance 1.22)
+    let result = nearest.classify_resolved(point.0, point.1, point.2).unwrap();
+    assert_eq!(result.selected, vec![53]);
+    assert!(result.ambiguous);
+}
+
+#[test]
+fn test_overlap_policy_unambiguous_points() {
+    let classifier = IsccNbsClassifier::new()
+        .unwrap()
+        .with_overlap_policy(OverlapPolicy::HighestNumber);
+
+    let result = classifier.classify_resolved("5R", 4.0, 14.0).unwrap();
+    assert!(!result.ambiguous);
+    assert_eq!(result.selected, vec![11]);
+
+    let neutral = classifier.classify_resolved("N", 9.0, 0.0).unwrap();
+    assert_eq!(neutral.selected, vec![263]);
+    assert!(!neutral.ambiguous);
+}
@@ -0,0 +1,438 @@
+//! Contrast and legibility based on Munsell value.
+//!
+//! Munsell value is a perceptual lightness scale tied to relative luminance
+//! by ASTM D1535 ([`munsell_value_astmd1535`]), the same luminance WCAG
+//! contrast is computed from. Value differences are therefore a natural way
+//! to reason about legibility: lightening or darkening a color at constant
+//! hue and chroma changes its contrast without changing its identity.
+//!
+//! [`contrast`] reports side by side:
+//!
+//! - the Munsell value of both colors and their difference
+//! - the WCAG 2 contrast ratio and the level it reaches
+//! - the APCA lightness contrast (Lc, APCA-W3 0.0.98G) and the level it
+//!   reaches; Lc is positive for dark text on a light background and
+//!   negative for light text on a dark background
+//!
+//! [`LegibilityChecker::suggest_adjustment`] finds the smallest change of
+//! value of either color that makes a failing pair pass.
+//!
+//! # Example
+//!
+//! ```rust
+//! use munsellspace::legibility::{contrast, ApcaLevel, WcagLevel};
+//!
+//! let report = contrast([0x88, 0x88, 0x88], [255, 255, 255]);
+//! assert!((report.wcag_ratio - 3.54).abs() < 0.01);
+//! assert_eq!(report.wcag_level, WcagLevel::AaLarge);
+//! assert_eq!(report.apca_level, ApcaLevel::Content);
+//! assert!(report.value_difference > 4.0);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::converter::{srgb_to_linear, MunsellConverter};
+use crate::error::{MunsellError, Result};
+use crate::gamut::in_srgb_gamut;
+use crate::munsell_color_science::munsell_value_astmd1535;
+use crate::reverse_conversion::ReverseConverter;
+use crate::semantic_overlay::MunsellSpec;
+use crate::types::MunsellColor;
+
+/// Value increment when searching for a passing adjustment.
+const VALUE_SEARCH_STEP: f64 = 0.1;
+
+/// WCAG 2 contrast level reached by a pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum WcagLevel {
+    /// Below 3:1
+    Fail,
+    /// At least 3:1: large text (AA) and user interface components
+    AaLarge,
+    /// At least 4.5:1: normal text (AA), large text (AAA)
+    Aa,
+    /// At least 7:1: normal text (AAA)
+    Aaa,
+}
+
+impl WcagLevel {
+    /// Level reached by a contrast ratio.
+    pub fn from_ratio(ratio: f64) -> Self {
+        if ratio >= 7.0 {
+            WcagLevel::Aaa
+        } else if ratio >= 4.5 {
+            WcagLevel::Aa
+        } else if ratio >= 3.0 {
+            WcagLevel::AaLarge
+        } else {
+            WcagLevel::Fail
+        }
+    }
+}
+
+/// APCA use level reached by a pair, from the APCA Bronze simple mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ApcaLevel {
+    /// |Lc| below 15: not legible
+    Fail,
+    /// |Lc| 15 or more: non-text elements such as dividers
+    NonText,
+    /// |Lc| 30 or more: placeholder and disabled text, copyright lines
+    Spot,
+    /// |Lc| 45 or more: large text and headlines
+    Large,
+    /// |Lc| 60 or more: content text that is not body text
+    Content,
+    /// |Lc| 75 or more: body text
+    Body,
+}
+
+impl ApcaLevel {
+    /// Level reached by an Lc value of either polarity.
+    pub fn from_lc(lc: f64) -> Self {
+        let lc = lc.abs();
+        if lc >= 75.0 {
+            ApcaLevel::Body
+        } else if lc >= 60.0 {
+            ApcaLevel::Content
+        } else if lc >= 45.0 {
+            ApcaLevel::Large
+        } else if lc >= 30.0 {
+            ApcaLevel::Spot
+        } else if lc >= 15.0 {
+            ApcaLevel::NonText
+        } else {
+            ApcaLevel::Fail
+        }
+    }
+}
+
+/// Level a pair must reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LegibilityTarget {
+    /// A WCAG 2 contrast level
+    Wcag(WcagLevel),
+    /// An APCA use level
+    Apca(ApcaLevel),
+}
+
+/// Contrast of a foreground (text) color on a background color.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ContrastReport {
+    /// Foreground sRGB color
+    pub foreground: [u8; 3],
+    /// Background sRGB color
+    pub background: [u8; 3],
+    /// Munsell value of the foreground
+    pub foreground_value: f64,
+    /// Munsell value of the background
+    pub background_value: f64,
+    /// Absolute Munsell value difference
+    pub value_difference: f64,
+    /// WCAG 2 contrast ratio, from 1 to 21
+    pub wcag_ratio: f64,
+    /// WCAG 2 level reached
+    pub wcag_level: WcagLevel,
+    /// APCA lightness contrast Lc, roughly -108 to 106
+    pub apca_lc: f64,
+    /// APCA level reached
+    pub apca_level: ApcaLevel,
+}
+
+impl ContrastReport {
+    /// Whether the pair reaches a target level.
+    pub fn passes(&self, target: LegibilityTarget) -> bool {
+        match target {
+            LegibilityTarget::Wcag(level) => self.wcag_level >= level,
+            LegibilityTarget::Apca(level) => self.apca_level >= level,
+        }
+    }
+}
+
+/// Contrast of two palette colors, each tried as foreground.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PaletteContrast {
+    /// Index of the foreground color in the palette
+    pub foreground: usize,
+    /// Index of the background color in the palette
+    pub background: usize,
+    /// Contrast of the pair
+    pub report: ContrastReport,
+}
+
+/// Which color of a pair an adjustment changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AdjustedColor {
+    /// The foreground was lightened or darkened
+    Foreground,
+    /// The background was lightened or darkened
+    Background,
+}
+
+/// A value change that makes a pair pass.
+#[derive(Debug, Clone)]
+pub struct ValueAdjustment {
+    /// Color that was changed
+    pub adjusted: AdjustedColor,
+    /// New Munsell color, with the original hue and chroma
+    pub munsell: MunsellColor,
+    /// New sRGB color
+    pub srgb: [u8; 3],
+    /// Signed change of Munsell value; positive is lighter
+    pub value_change: f64,
+    /// Contrast of the adjusted pair
+    pub report: ContrastReport,
+}
+
+/// Relative luminance of an sRGB color, from 0 to 1.
+pub fn relative_luminance(rgb: [u8; 3]) -> f64 {
+    let [r, g, b] = rgb.map(|c| srgb_to_linear(c as f64 / 255.0));
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Munsell value of an sRGB color, from its relative luminance.
+pub fn munsell_value(rgb: [u8; 3]) -> f64 {
+    munsell_value_astmd1535(relative_luminance(rgb) * 100.0)
+}
+
+/// WCAG 2 contrast ratio of two colors, in either order.
+pub fn wcag_contrast_ratio(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// APCA lightness contrast (APCA-W3 0.0.98G) of text on a background.
+///
+/// Positive for dark text on a light background, negative for light text
+/// on a dark background.
+pub fn apca_contrast(text: [u8; 3], background: [u8; 3]) -> f64 {
+    // Soft clamp of near-black luminance
+    fn screen_luminance(rgb: [u8; 3]) -> f64 {
+        let [r, g, b] = rgb.map(|c| (c as f64 / 255.0).powf(2.4));
+        let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+        if y < 0.022 {
+            y + (0.022 - y).powf(1.414)
+        } else {
+            y
+        }
+    }
+
+    let y_text = screen_luminance(text);
+    let y_background = screen_luminance(background);
+    if (y_background - y_text).abs() < 0.0005 {
+        return 0.0;
+    }
+
+    let lc = if y_background > y_text {
+        let sapc = (y_background.powf(0.56) - y_text.powf(0.57)) * 1.14;
+        if sapc < 0.1 {
+            0.0
+        } else {
+            sapc - 0.027
+        }
+    } else {
+        let sapc = (y_background.powf(0.65) - y_text.powf(0.62)) * 1.14;
+        if sapc > -0.1 {
+            0.0
+        } else {
+            sapc + 0.027
+        }
+    };
+    lc * 100.0
+}
+
+/// Contrast report of a foreground color on a background color.
+pub fn contrast(foreground: [u8; 3], background: [u8; 3]) -> ContrastReport {
+    let foreground_value = munsell_value(foreground);
+    let background_value = munsell_value(background);
+    let wcag_ratio = wcag_contrast_ratio(foreground, background);
+    let apca_lc = apca_contrast(foreground, background);
+
+    ContrastReport {
+        foreground,
+        background,
+        foreground_value,
+        background_value,
+        value_difference: (foreground_value - background_value).abs(),
+        wcag_ratio,
+        wcag_level: WcagLevel::from_ratio(wcag_ratio),
+        apca_lc,
+        apca_level: ApcaLevel::from_lc(apca_lc),
+    }
+}
+
+/// Contrast of every ordered pair of palette colors.
+///
+/// APCA depends on polarity, so each pair appears with either color as the
+/// foreground.
+pub fn palette_contrast(palette: &[[u8; 3]]) -> Vec<PaletteContrast> {
+    let mut pairs = Vec::new();
+    for (i, &foreground) in palette.iter().enumerate() {
+        for (j, &background) in palette.iter().enumerate() {
+            if i != j {
+                pairs.push(PaletteContrast {
+                    foreground: i,
+                    background: j,
+                    report: contrast(foreground, background),
+                });
+            }
+        }
+    }
+    pairs
+}
+
+/// Finds value adjustments that make failing pairs legible.
+pub struct LegibilityChecker {
+    converter: MunsellConverter,
+    reverse: ReverseConverter,
+}
+
+impl LegibilityChecker {
+    /// Create a checker.
+    ///
+    /// # Errors
+    /// Returns an error if the converters cannot be created.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            converter: MunsellConverter::new()?,
+            reverse: ReverseConverter::new()?,
+        })
+    }
+
+    /// Find the smallest value change of either color that reaches `target`.
+    ///
+    /// Each color is lightened and darkened in steps of 0.1 value at constant
+    /// hue and chroma, skipping values where the color leaves the sRGB gamut.
+    /// Returns `Ok(None)` if the pair already passes or no value works.
+    ///
+    /// # Errors
+    /// Returns an error if either color cannot be converted to Munsell.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::legibility::{LegibilityChecker, LegibilityTarget, WcagLevel};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let checker = LegibilityChecker::new()?;
+    /// let target = LegibilityTarget::Wcag(WcagLevel::Aa);
+    ///
+    /// let fix = checker.suggest_adjustment([0x88, 0x88, 0x88], [255, 255, 255], target)?.unwrap();
+    /// assert!(fix.report.passes(target));
+    /// assert!(fix.value_change < 0.0); // the gray text gets darker
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn suggest_adjustment(
+        &self,
+        foreground: [u8; 3],
+        background: [u8; 3],
+        target: LegibilityTarget,
+    ) -> Result<Option<ValueAdjustment>> {
+        if contrast(foreground, background).passes(target) {
+            return Ok(None);
+        }
+
+        let foreground_fix = self.search(foreground, AdjustedColor::Foreground, target, |rgb| {
+            contrast(rgb, background)
+        })?;
+        let background_fix = self.search(background, AdjustedColor::Background, target, |rgb| {
+            contrast(foreground, rgb)
+        })?;
+
+        Ok([foreground_fix, background_fix]
+            .into_iter()
+            .flatten()
+            .min_by(|a, b| a.value_change.abs().total_cmp(&b.value_change.abs())))
+    }
+
+    /// Internal: nearest passing value of one color of the pair.
+    fn search(
+        &self,
+        rgb: [u8; 3],
+        adjusted: AdjustedColor,
+        target: LegibilityTarget,
+        report: impl Fn([u8; 3]) -> ContrastReport,
+    ) -> Result<Option<ValueAdjustment>> {
+        let munsell = self.converter.srgb_to_munsell(rgb)?;
+        let spec = munsell.to_munsell_spec().ok_or_else(|| {
+            MunsellError::InvalidMunsellColor(format!("Cannot read {}", munsell.notation))
+        })?;
+
+        let max_offset = (10.0 / VALUE_SEARCH_STEP) as usize;
+        for i in 1..=max_offset {
+            let offset = i as f64 * VALUE_SEARCH_STEP;
+            for change in [-offset, offset] {
+                let value = spec.value + change;
+                if !(0.0..=10.0).contains(&value) {
+                    continue;
+                }
+                let candidate = MunsellSpec::new(spec.hue_number, value, spec.chroma).rounded();
+                if !in_srgb_gamut(candidate, &self.reverse) {
+                    continue;
+                }
+                let Some(srgb) = candidate.to_srgb(&self.reverse) else {
+                    continue;
+                };
+                let result = report(srgb);
+                if result.passes(target) {
+                    return Ok(Some(ValueAdjustment {
+                        adjusted,
+                        munsell: candidate.to_munsell_color(),
+                        srgb,
+                        value_change: candidate.value - spec.value,
+                        report: result,
+                    }));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: [u8; 3] = [255, 255, 255];
+    const BLACK: [u8; 3] = [0, 0, 0];
+
+    #[test]
+    fn test_reference_contrasts() {
+        let report = contrast(BLACK, WHITE);
+        assert!((report.wcag_ratio - 21.0).abs() < 1e-9);
+        assert_eq!(report.wcag_level, WcagLevel::Aaa);
+        assert!((report.apca_lc - 106.04).abs() < 0.01);
+        assert!(report.value_difference > 9.9);
+
+        // Reverse polarity gives a negative Lc
+        assert!((apca_contrast(WHITE, BLACK) + 107.88).abs() < 0.01);
+        assert_eq!(apca_contrast([128; 3], [128; 3]), 0.0);
+
+        // Munsell value 5 is about 19.8% luminance
+        assert!((munsell_value([119, 119, 119]) - 5.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_palette_and_adjustment() {
+        let palette = [WHITE, [0x88, 0x88, 0x88], [20, 40, 120]];
+        let pairs = palette_contrast(&palette);
+        assert_eq!(pairs.len(), 6);
+        let forward = pairs.iter().find(|p| (p.foreground, p.background) == (1, 0)).unwrap();
+        let backward = pairs.iter().find(|p| (p.foreground, p.background) == (0, 1)).unwrap();
+        assert_eq!(forward.report.wcag_ratio, backward.report.wcag_ratio);
+        assert!(forward.report.apca_lc > 0.0 && backward.report.apca_lc < 0.0);
+
+        let checker = LegibilityChecker::new().unwrap();
+        let target = LegibilityTarget::Apca(ApcaLevel::Body);
+        let fix = checker
+            .suggest_adjustment([200, 60, 60], [240, 240, 240], target)
+            .unwrap()
+            .unwrap();
+        assert_eq!(fix.adjusted, AdjustedColor::Foreground);
+        assert!(fix.report.passes(target));
+        assert_eq!(fix.munsell.hue.as_deref().map(|h| h.ends_with('R')), Some(true));
+
+        // Passing pairs need no change
+        assert!(checker.suggest_adjustment(BLACK, WHITE, target).unwrap().is_none());
+    }
+}
@@ -243,6 +243,17 @@ pub mod unified_cache;
 pub mod semantic_overlay;
 pub mod semantic_overlay_data;
 pub mod color_names;
+pub mod category_system;
+pub mod temperature;
+pub mod screen_correction;
+pub mod harmony;
+pub mod gradient;
+pub mod image_analysis;
+pub mod cvd;
+pub mod legibility;
+pub mod soil;
+pub mod validation;
+mod gamut;
 
 // Test modules were moved to their respective implementation files
 #[cfg(test)]
@@ -270,16 +281,19 @@ pub use reverse_conversion::{ReverseConverter, ColorFormats, CieLab, HslColor, H
 pub use unified_cache::{UnifiedColorCache, CachedColorResult};
 pub use semantic_overlay::{
     MunsellSpec, MunsellCartesian, SemanticOverlay, SemanticOverlayRegistry,
+    OverlayDefinition, OverlayBuilder, OverlayRanking, OverlayContext,
     parse_hue_to_number, hue_number_to_string, parse_munsell_notation,
 };
 
 // Deprecated semantic overlay functions (v1.2.0) - Use ColorClassifier instead
-// These are re-exported for backward compatibility and will be removed in v2.0.0
+// These are re-exported for backward compatibility and will be removed in v3.0.0
 #[allow(deprecated)]
 pub use semantic_overlay::{
     semantic_overlay, matching_overlays, matching_overlays_ranked, matches_overlay, closest_overlay,
 };
-pub use semantic_overlay_data::{create_overlay_registry, get_registry};
+pub use semantic_overlay_data::{
+    create_overlay_registry, get_registry, create_screen_overlay_registry, get_screen_registry,
+};
 
 // Unified color naming API (v1.2.0+)
 pub use color_names::{
@@ -287,7 +301,12 @@ pub use color_names::{
     known_color_names, is_known_color, color_name_count,
     // New in v1.2.1: Flexible characterization API
     ColorCharacterization, FormatOptions, BaseColorSet, OverlayMode,
+    PhraseLookup, PhraseOverlay,
+};
+pub use category_system::{
+    CategorySystem, Category, CategoryMatch, CategoryRegion, CustomCategorySystem,
 };
+pub use temperature::{Temperature, TemperatureConfig, TemperatureLabel};
 
 // Note: General color conversions (RGB↔Hex↔Lab↔HSL↔HSV) are available via the palette crate
 // We only expose Munsell-specific conversions to avoid duplication
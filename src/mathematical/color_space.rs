@@ -3,7 +3,7 @@
 use palette::{Srgb, Xyz, convert::IntoColor, white_point::D65};
 
 use crate::constants::*;
-use crate::error::{MunsellError, Result};
+use crate::error::{ErrorCode, ErrorInput, MunsellError, PipelineStage, Result};
 
 use super::types::{Illuminant, CieXyY, MunsellSpecification};
 use super::MathematicalMunsellConverter;
@@ -27,15 +27,20 @@ impl MathematicalMunsellConverter {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Errors carry `rgb` as their input; see [`MunsellError::input`].
     pub fn srgb_to_munsell(&self, rgb: [u8; 3]) -> Result<MunsellSpecification> {
         // Step 1: Convert sRGB to xyY using palette crate
         let xyy = self.srgb_to_xyy(rgb)?;
 
         // Step 2: Convert xyY to Munsell specification using mathematical algorithm
-        self.xyy_to_munsell_specification(xyy)
+        self.solve_specification(xyy)
+            .map_err(|error| error.with_input(PipelineStage::Solver, ErrorInput::Rgb(rgb)))
     }
 
     /// Convert sRGB to CIE xyY color space with optional chromatic adaptation.
+    ///
+    /// Errors carry `rgb` as their input; see [`MunsellError::input`].
     pub fn srgb_to_xyy(&self, rgb: [u8; 3]) -> Result<CieXyY> {
         // Create sRGB color with normalized values [0.0, 1.0]
         let rgb_norm = [
@@ -58,7 +63,8 @@ impl MathematicalMunsellConverter {
         let xyz_adapted = if self.source_illuminant == self.target_illuminant {
             xyz_src
         } else {
-            self.chromatic_adaptation(xyz_src, self.source_illuminant, self.target_illuminant)?
+            self.chromatic_adaptation(xyz_src, self.source_illuminant, self.target_illuminant)
+                .map_err(|error| error.with_input(PipelineStage::ChromaticAdaptation, ErrorInput::Rgb(rgb)))?
         };
 
         // Convert XYZ to xyY
@@ -77,7 +83,7 @@ impl MathematicalMunsellConverter {
                 let target_wp = target.white_point();
 
                 if source_wp[0].abs() < 1e-15 || source_wp[1].abs() < 1e-15 || source_wp[2].abs() < 1e-15 {
-                    return Err(MunsellError::ConvergenceFailed);
+                    return Err(degenerate_white_point());
                 }
 
                 Ok([
@@ -107,7 +113,7 @@ impl MathematicalMunsellConverter {
         let cone_tgt_wp = self.matrix_multiply_3x3(&BRADFORD_MATRIX, &target_wp);
 
         if cone_src_wp[0].abs() < 1e-15 || cone_src_wp[1].abs() < 1e-15 || cone_src_wp[2].abs() < 1e-15 {
-            return Err(MunsellError::ConvergenceFailed);
+            return Err(degenerate_white_point());
         }
 
         let cone_adapted = [
@@ -131,7 +137,7 @@ impl MathematicalMunsellConverter {
         let cat_tgt_wp = self.matrix_multiply_3x3(&CAT02_MATRIX, &target_wp);
 
         if cat_src_wp[0].abs() < 1e-15 || cat_src_wp[1].abs() < 1e-15 || cat_src_wp[2].abs() < 1e-15 {
-            return Err(MunsellError::ConvergenceFailed);
+            return Err(degenerate_white_point());
         }
 
         let cat_adapted = [
@@ -167,3 +173,12 @@ impl MathematicalMunsellConverter {
         ]
     }
 }
+
+/// Error for a source white point that cannot be adapted from.
+fn degenerate_white_point() -> MunsellError {
+    MunsellError::pipeline(
+        ErrorCode::ConversionFailed,
+        PipelineStage::ChromaticAdaptation,
+        "Source white point has a zero component",
+    )
+}
@@ -105,3 +105,21 @@ fn test_black_color_conversion() {
     assert!(munsell.value < 1.0); // Should be very dark
     assert!(munsell.chroma < 1.0); // Should have very low chroma
 }
+
+#[test]
+fn test_renotation_failure_context() {
+    use crate::error::{ErrorCode, ErrorInput, PipelineStage};
+
+    let converter = MathematicalMunsellConverter::new().unwrap();
+    let rgb = [0, 0, 51];
+    let error = converter.srgb_to_munsell(rgb).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::InterpolationFailed);
+    assert_eq!(error.stage(), Some(PipelineStage::RenotationLookup));
+    assert_eq!(error.input(), Some(ErrorInput::Rgb(rgb)));
+    assert!(error.solver_context().is_none());
+
+    let xyy = CieXyY { x: 0.0, y: 0.0, y_luminance: 0.5 };
+    let error = converter.xyy_to_munsell_specification(xyy).unwrap_err();
+    assert_eq!(error.stage(), Some(PipelineStage::RenotationLookup));
+    assert_eq!(error.input(), Some(ErrorInput::Xyy([0.0, 0.0, 0.5])));
+}
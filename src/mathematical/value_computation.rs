@@ -1,7 +1,7 @@
 //! ASTM D1535 polynomial value computation and Munsell notation formatting.
 
 use crate::constants::*;
-use crate::error::{MunsellError, Result};
+use crate::error::{ErrorCode, MunsellError, PipelineStage, Result, SolverContext};
 
 use super::types::{MunsellSpecification, CieXyY};
 use super::MathematicalMunsellConverter;
@@ -25,16 +25,26 @@ impl MathematicalMunsellConverter {
 
         // Newton-Raphson iteration to solve for V given Y
         let mut v = 10.0 * y.sqrt(); // Initial guess
-
-        for _ in 0..NEWTON_RAPHSON_MAX_ITERATIONS {
+        let failure = |message: &str, iterations: usize, residual: f64| {
+            MunsellError::pipeline(ErrorCode::ConvergenceFailed, PipelineStage::Solver, message)
+                .with_solver(SolverContext {
+                    iterations,
+                    max_iterations: NEWTON_RAPHSON_MAX_ITERATIONS,
+                    last_residual: residual,
+                    tolerance: NEWTON_RAPHSON_TOLERANCE,
+                })
+        };
+        let mut delta = f64::INFINITY;
+
+        for iteration in 0..NEWTON_RAPHSON_MAX_ITERATIONS {
             let f = self.astm_polynomial(v) - y_scaled;
             let df = self.astm_polynomial_derivative(v);
 
             if df.abs() < 1e-15 {
-                return Err(MunsellError::ConvergenceFailed);
+                return Err(failure("Value polynomial has a zero derivative", iteration, delta));
             }
 
-            let delta = f / df;
+            delta = f / df;
             v -= delta;
 
             if delta.abs() < NEWTON_RAPHSON_TOLERANCE {
@@ -42,7 +52,11 @@ impl MathematicalMunsellConverter {
             }
         }
 
-        Err(MunsellError::ConvergenceFailed)
+        Err(failure(
+            "Value did not converge",
+            NEWTON_RAPHSON_MAX_ITERATIONS,
+            delta.abs(),
+        ))
     }
 
     /// ASTM D1535 fifth-order polynomial for Munsell Value.
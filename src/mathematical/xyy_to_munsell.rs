@@ -4,7 +4,7 @@
 //! manageable helper functions.
 
 use crate::constants::*;
-use crate::error::Result;
+use crate::error::{ErrorInput, PipelineStage, Result};
 
 use super::coordinate_transforms;
 use super::hue_conversions;
@@ -26,7 +26,22 @@ impl MathematicalMunsellConverter {
     /// returned as neutral (N) since no renotation data exists to resolve
     /// chroma, and human color discrimination is negligible at such low
     /// luminance.
+    ///
+    /// Errors carry `xyy` as their input; see [`MunsellError::input`].
+    ///
+    /// [`MunsellError::input`]: crate::MunsellError::input
     pub fn xyy_to_munsell_specification(&self, xyy: CieXyY) -> Result<MunsellSpecification> {
+        self.solve_specification(xyy).map_err(|error| {
+            error.with_input(
+                PipelineStage::Solver,
+                ErrorInput::Xyy([xyy.x, xyy.y, xyy.y_luminance]),
+            )
+        })
+    }
+
+    /// Internal: [`xyy_to_munsell_specification`](Self::xyy_to_munsell_specification)
+    /// for callers that record their own input on errors.
+    pub(super) fn solve_specification(&self, xyy: CieXyY) -> Result<MunsellSpecification> {
         let convergence_threshold = THRESHOLD_INTEGER / 1e4; // 1e-7
 
         // Step 1: Calculate Munsell Value using ASTM D1535 polynomial
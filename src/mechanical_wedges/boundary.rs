@@ -1,6 +1,7 @@
 //! Boundary detection and point-in-polygon tests for the mechanical wedge system.
 
 use crate::iscc::IsccNbsColor;
+use super::diagnostics::{BoundaryRule, PointBounds, PolygonTest};
 use super::system::MechanicalWedgeSystem;
 
 impl MechanicalWedgeSystem {
@@ -59,6 +60,43 @@ impl MechanicalWedgeSystem {
         false
     }
 
+    /// Test a point against a polygon and record how the decision was reached.
+    ///
+    /// Mirrors [`point_in_polygon`](Self::point_in_polygon), additionally reporting
+    /// the polygon extents bounding the point and the interval rule applied.
+    pub(super) fn explain_point_in_polygon(
+        &self,
+        value: f64,
+        chroma: f64,
+        polygon: &IsccNbsColor,
+    ) -> PolygonTest {
+        use geo::Contains;
+
+        let interior = polygon.polygon.contains(&geo::Point::new(chroma, value));
+        let (chroma_range, value_range) = self.get_polygon_ranges_at_point(value, chroma, polygon);
+
+        let bounds = match (chroma_range, value_range) {
+            (Some((chroma_min, chroma_max)), Some((value_min, value_max))) => Some(PointBounds {
+                chroma_min,
+                chroma_max,
+                value_min,
+                value_max,
+                chroma_rule: BoundaryRule::for_lower_bound(chroma_min),
+                value_rule: BoundaryRule::for_lower_bound(value_min),
+            }),
+            _ => None,
+        };
+
+        PolygonTest {
+            color_number: polygon.color_number,
+            polygon_group: polygon.polygon_group,
+            hue_range: polygon.hue_range.clone(),
+            interior,
+            bounds,
+            matched: self.point_in_polygon(value, chroma, polygon),
+        }
+    }
+
     /// Get the chroma and value ranges of the polygon at the given point.
     /// Returns (chroma_range, value_range) where each range is (min, max).
     #[inline]
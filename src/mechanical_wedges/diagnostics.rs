@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 use geo::CoordsIter;
+use serde::{Deserialize, Serialize};
 use crate::iscc::IsccNbsColor;
 use super::system::MechanicalWedgeSystem;
 
@@ -57,6 +58,63 @@ impl SingleWedgeValidation {
     }
 }
 
+/// Interval rule applied to a polygon extent when a point lies on its boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BoundaryRule {
+    /// `[0, max]`: used when the lower bound is zero
+    Closed,
+    /// `(min, max]`: used for all other lower bounds
+    HalfOpen,
+}
+
+impl BoundaryRule {
+    /// Select the rule for an extent starting at `lower`.
+    pub(super) fn for_lower_bound(lower: f64) -> Self {
+        if lower == 0.0 {
+            Self::Closed
+        } else {
+            Self::HalfOpen
+        }
+    }
+}
+
+/// Polygon extents bounding a test point.
+///
+/// The chroma extent is measured along the point's value line and the value
+/// extent along its chroma line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PointBounds {
+    /// Lowest chroma edge crossing the point's value line
+    pub chroma_min: f64,
+    /// Highest chroma edge crossing the point's value line
+    pub chroma_max: f64,
+    /// Lowest value edge crossing the point's chroma line
+    pub value_min: f64,
+    /// Highest value edge crossing the point's chroma line
+    pub value_max: f64,
+    /// Rule applied to the chroma extent
+    pub chroma_rule: BoundaryRule,
+    /// Rule applied to the value extent
+    pub value_rule: BoundaryRule,
+}
+
+/// Outcome of testing a point against one candidate polygon.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolygonTest {
+    /// ISCC-NBS color number of the polygon
+    pub color_number: u16,
+    /// Polygon group within the color
+    pub polygon_group: u8,
+    /// Hue range the polygon applies to
+    pub hue_range: (String, String),
+    /// Whether the point lies strictly inside the polygon
+    pub interior: bool,
+    /// Polygon extents at the point, if the point lies within the polygon's span
+    pub bounds: Option<PointBounds>,
+    /// Final containment result after boundary rules
+    pub matched: bool,
+}
+
 impl MechanicalWedgeSystem {
     /// Get statistics about wedge container distribution.
     pub fn get_wedge_statistics(&self) -> WedgeStatistics {
@@ -104,6 +162,15 @@ impl MechanicalWedgeSystem {
     }
 
     /// Debug method to test point-in-polygon for a specific color.
+    ///
+    /// # Deprecated
+    /// Use [`explain_point`](Self::explain_point) or
+    /// [`IsccNbsClassifier::classify_explained()`](crate::IsccNbsClassifier::classify_explained)
+    /// for a structured, serializable trace.
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use explain_point() or IsccNbsClassifier::classify_explained(). This method will be removed in v3.0.0."
+    )]
     pub fn debug_point_test(
         &self,
         wedge_key: &str,
@@ -121,6 +188,15 @@ impl MechanicalWedgeSystem {
     }
 
     /// Detailed debug method to show polygon bounds and test point.
+    ///
+    /// # Deprecated
+    /// Use [`explain_point`](Self::explain_point) or
+    /// [`IsccNbsClassifier::classify_explained()`](crate::IsccNbsClassifier::classify_explained)
+    /// for a structured, serializable trace.
+    #[deprecated(
+        since = "2.0.0",
+        note = "Use explain_point() or IsccNbsClassifier::classify_explained(). This method will be removed in v3.0.0."
+    )]
     pub fn debug_point_test_detailed(
         &self,
         wedge_key: &str,
@@ -156,6 +232,20 @@ impl MechanicalWedgeSystem {
         None
     }
 
+    /// Test a (value, chroma) point against every polygon in a wedge.
+    ///
+    /// Returns one [`PolygonTest`] per candidate polygon, in container order,
+    /// or `None` if the wedge key is unknown.
+    pub fn explain_point(&self, wedge_key: &str, value: f64, chroma: f64) -> Option<Vec<PolygonTest>> {
+        let container = self.wedge_containers.get(wedge_key)?;
+        Some(
+            container
+                .iter()
+                .map(|polygon| self.explain_point_in_polygon(value, chroma, polygon))
+                .collect(),
+        )
+    }
+
     /// Validate all wedge containers for coverage, gaps, and intersections.
     pub fn validate_all_wedges(&self) -> WedgeValidationResults {
         let mut results = WedgeValidationResults::new();
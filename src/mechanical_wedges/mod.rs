@@ -58,4 +58,7 @@ mod tests;
 
 // Re-export public types
 pub use system::MechanicalWedgeSystem;
-pub use diagnostics::{WedgeStatistics, WedgeValidationResults, SingleWedgeValidation};
+pub use diagnostics::{
+    WedgeStatistics, WedgeValidationResults, SingleWedgeValidation,
+    BoundaryRule, PointBounds, PolygonTest,
+};
@@ -24,10 +24,10 @@ use crate::iscc::IsccNbsColor;
 /// - Hue exactly at wedge end: belongs to *current* wedge
 pub struct MechanicalWedgeSystem {
     /// Map of wedge identifiers to color polygon containers.
-    pub(super) wedge_containers: HashMap<String, Vec<IsccNbsColor>>,
+    pub(crate) wedge_containers: HashMap<String, Vec<IsccNbsColor>>,
 
     /// Complete ordered sequence of Munsell hue references.
-    pub(super) hue_sequence: Vec<String>,
+    pub(crate) hue_sequence: Vec<String>,
 
     /// Fast lookup table mapping hue strings to sequence positions.
     pub(super) hue_to_position: HashMap<String, usize>,
@@ -163,6 +163,21 @@ impl MechanicalWedgeSystem {
         matching_colors
     }
 
+    /// Find all polygons containing the given Munsell point, in container order.
+    pub fn find_all_polygons_at_point(&self, hue: &str, value: f64, chroma: f64) -> Vec<&IsccNbsColor> {
+        let Some(container) = self
+            .find_containing_wedge(hue)
+            .and_then(|key| self.wedge_containers.get(&key))
+        else {
+            return vec![];
+        };
+
+        container
+            .iter()
+            .filter(|polygon| self.point_in_polygon(value, chroma, polygon))
+            .collect()
+    }
+
     /// Classify a Munsell color by finding the first matching ISCC-NBS color polygon.
     #[inline]
     pub fn classify_color(&self, hue: &str, value: f64, chroma: f64) -> Option<&IsccNbsColor> {
@@ -176,7 +191,7 @@ impl MechanicalWedgeSystem {
 
     /// Find which wedge contains the given hue using correct range interpretation.
     #[inline]
-    pub(super) fn find_containing_wedge(&self, hue: &str) -> Option<String> {
+    pub(crate) fn find_containing_wedge(&self, hue: &str) -> Option<String> {
         let (hue_number, hue_family) = self.parse_hue(hue).ok()?;
 
         let wedge_number = if hue_number <= 0.0 || hue_number > 10.0 {
@@ -206,7 +221,7 @@ impl MechanicalWedgeSystem {
 
     /// Parse Munsell hue notation (e.g., "4.5R", "7YR").
     #[inline]
-    pub(super) fn parse_hue(&self, hue: &str) -> Result<(f64, String)> {
+    pub(crate) fn parse_hue(&self, hue: &str) -> Result<(f64, String)> {
         let hue = hue.trim();
 
         let mut split_pos = 0;
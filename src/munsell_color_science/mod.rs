@@ -26,6 +26,7 @@ pub use interpolation_methods::interpolation_method_from_renotation_ovoid;
 pub use renotation_lookup::{xyy_from_renotation, maximum_chroma_from_renotation};
 pub use ovoid::{xy_from_renotation_ovoid_interpolated, xy_from_renotation_ovoid};
 pub use xyy_to_munsell::xyy_to_munsell_specification;
+pub(crate) use xyy_to_munsell::solve_specification;
 pub use spec_to_xy::{munsell_specification_to_xy, munsell_specification_to_xyy};
 
 /// Linear interpolation helper used across submodules.
@@ -18,3 +18,27 @@ fn test_python_functions_exact_match() {
     assert!(boundary1 >= 0.0 && boundary1 <= 100.0);
     assert!(boundary2 >= 0.0 && boundary2 <= 100.0);
 }
+
+#[test]
+fn test_solver_failure_context() {
+    use crate::error::{ErrorCode, ErrorInput, PipelineStage};
+
+    let xyy = [0.7, 0.2, 0.5];
+    let error = xyy_to_munsell_specification(xyy).unwrap_err();
+    assert_eq!(error.code(), ErrorCode::ConvergenceFailed);
+    assert_eq!(error.stage(), Some(PipelineStage::Solver));
+    assert_eq!(error.input(), Some(ErrorInput::Xyy(xyy)));
+    let solver = error.solver_context().unwrap();
+    assert_eq!((solver.iterations, solver.max_iterations), (64, 64));
+    assert!(solver.last_residual > solver.tolerance);
+
+    // sRGB callers report their own input, not the intermediate xyY
+    let rgb = [204, 255, 0];
+    let error = crate::munsell_converter_core::PythonMunsellConverter::new()
+        .srgb_to_munsell(rgb)
+        .unwrap_err();
+    assert_eq!(error.code(), ErrorCode::ConvergenceFailed);
+    assert_eq!(error.stage(), Some(PipelineStage::Solver));
+    assert_eq!(error.input(), Some(ErrorInput::Rgb(rgb)));
+    assert_eq!(error.solver_context().unwrap().iterations, 64);
+}
@@ -2,7 +2,7 @@
 //!
 //! Exact 1:1 port of Python colour-science `_xyY_to_munsell_specification`.
 
-use crate::error::Result;
+use crate::error::{ErrorCode, ErrorInput, MunsellError, PipelineStage, Result, SolverContext};
 use crate::color_interpolation::{LinearInterpolator, Extrapolator, ExtrapolationMethod};
 use crate::color_math_utils::euclidean_distance;
 use super::hue_conversions::{hue_to_hue_angle, hue_angle_to_hue};
@@ -18,7 +18,21 @@ use super::specification::{
 /// returned as neutral (N) since no renotation data exists to resolve
 /// chroma, and human color discrimination is negligible at such low
 /// luminance.
+///
+/// # Errors
+/// Returns a [`MunsellError::Pipeline`] error carrying `xyy`: at the
+/// [`Solver`](PipelineStage::Solver) stage with the solver state when the
+/// iteration does not converge, or at the
+/// [`RenotationLookup`](PipelineStage::RenotationLookup) stage when the
+/// renotation data cannot be interpolated.
 pub fn xyy_to_munsell_specification(xyy: [f64; 3]) -> Result<[f64; 4]> {
+    solve_specification(xyy)
+        .map_err(|error| error.with_input(PipelineStage::RenotationLookup, ErrorInput::Xyy(xyy)))
+}
+
+/// Internal: [`xyy_to_munsell_specification`] for callers that record their
+/// own input on errors.
+pub(crate) fn solve_specification(xyy: [f64; 3]) -> Result<[f64; 4]> {
     let (x, y, big_y) = (xyy[0], xyy[1], xyy[2]);
     let value = round_if_close(munsell_value_astmd1535(big_y * 100.0));
 
@@ -47,6 +61,7 @@ pub fn xyy_to_munsell_specification(xyy: [f64; 3]) -> Result<[f64; 4]> {
     // Main convergence loop
     let convergence_threshold = 1e-3 / 1e4;
     let iterations_maximum = 64;
+    let mut difference = f64::INFINITY;
 
     for _ in 0..iterations_maximum {
         let hue_current = specification_current[0];
@@ -80,16 +95,24 @@ pub fn xyy_to_munsell_specification(xyy: [f64; 3]) -> Result<[f64; 4]> {
 
         // Convergence check
         let xy_current = xy_from_renotation_ovoid_interpolated(&specification_current)?;
-        let difference = euclidean_distance(&[x, y], &[xy_current[0], xy_current[1]]);
+        difference = euclidean_distance(&[x, y], &[xy_current[0], xy_current[1]]);
 
         if difference < convergence_threshold {
             return check_hue_boundary(specification_current, x, y, value, difference);
         }
     }
 
-    Err(crate::error::MunsellError::ConversionError {
-        message: "Maximum iterations reached without convergence".to_string(),
-    })
+    Err(MunsellError::pipeline(
+        ErrorCode::ConvergenceFailed,
+        PipelineStage::Solver,
+        "Maximum iterations reached without convergence",
+    )
+    .with_solver(SolverContext {
+        iterations: iterations_maximum,
+        max_iterations: iterations_maximum,
+        last_residual: difference,
+        tolerance: convergence_threshold,
+    }))
 }
 
 fn round_if_close(v: f64) -> f64 {
@@ -1,7 +1,7 @@
 //! Python-compatible Munsell converter
 //! This module integrates the exact 1:1 Python ports for accurate conversion
 
-use crate::error::{MunsellError, Result};
+use crate::error::{ErrorInput, MunsellError, PipelineStage, Result};
 use crate::munsell_color_science::*;
 use crate::color_notation_parser::*;
 use crate::types::{MunsellColor, RgbColor};
@@ -28,7 +28,8 @@ impl PythonMunsellConverter {
         
         // Y is already in 0-1 range from sRGB conversion
         // Convert to Munsell specification using Python algorithm
-        let spec = xyy_to_munsell_specification(xyy)?;
+        let spec = solve_specification(xyy)
+            .map_err(|error| error.with_input(PipelineStage::Solver, ErrorInput::Rgb(rgb)))?;
         
         // Convert specification to MunsellColor
         self.specification_to_munsell_color(spec)
@@ -36,23 +37,32 @@ impl PythonMunsellConverter {
     
     /// Convert Munsell notation to sRGB using Python-compatible algorithm
     pub fn munsell_to_srgb(&self, munsell: &str) -> Result<RgbColor> {
+        let rgb_linear = self.munsell_to_linear_rgb(munsell)?;
+        
+        // Convert to sRGB
+        let rgb = self.linear_to_srgb(rgb_linear);
+        
+        Ok(RgbColor { r: rgb[0], g: rgb[1], b: rgb[2] })
+    }
+    
+    /// Convert Munsell notation to linear sRGB without clipping.
+    ///
+    /// Components outside `[0, 1]` mean the color is outside the sRGB gamut.
+    pub fn munsell_to_linear_rgb(&self, munsell: &str) -> Result<[f64; 3]> {
         // Parse Munsell notation using 1:1 ported function
         let spec = munsell_colour_to_munsell_specification(munsell)?;
         
         // Convert to xyY
-        let xyy = munsell_specification_to_xyy(&spec)?;
+        let xyy = munsell_specification_to_xyy(&spec).map_err(|error| {
+            error.with_input(PipelineStage::RenotationLookup, ErrorInput::Notation(munsell.to_string()))
+        })?;
         
         // Y is in 0-1 range, keep it that way
         // Convert to XYZ
         let xyz = self.xyy_to_xyz(xyy);
         
         // Convert to linear RGB
-        let rgb_linear = self.xyz_to_linear_rgb_d65(xyz);
-        
-        // Convert to sRGB
-        let rgb = self.linear_to_srgb(rgb_linear);
-        
-        Ok(RgbColor { r: rgb[0], g: rgb[1], b: rgb[2] })
+        Ok(self.xyz_to_linear_rgb_d65(xyz))
     }
     
     // Helper functions for color space conversions
@@ -11,6 +11,9 @@ use crate::munsell_color_science::munsell_specification_to_xyy;
 use crate::error::{MunsellError, Result};
 use palette::{Srgb, Hsl, Hsv, Xyz, convert::IntoColor, white_point::D65};
 
+/// Tolerance on linear sRGB components for [`ReverseConverter::is_in_srgb_gamut`].
+pub const SRGB_GAMUT_TOLERANCE: f64 = 1e-3;
+
 #[cfg(test)]
 mod tests;
 
@@ -165,6 +168,39 @@ impl ReverseConverter {
         Ok([rgb_color.r, rgb_color.g, rgb_color.b])
     }
 
+    /// Convert Munsell specification to linear sRGB without clipping.
+    ///
+    /// Components outside `[0, 1]` mean the color cannot be shown on an sRGB
+    /// display; [`munsell_to_srgb`](Self::munsell_to_srgb) clips them.
+    pub fn munsell_to_linear_srgb(&self, spec: &MunsellSpecification) -> Result<[f64; 3]> {
+        let notation = self.spec_to_notation_string(spec)?;
+        self.python_converter.munsell_to_linear_rgb(&notation)
+    }
+
+    /// Check whether a Munsell specification lies inside the sRGB gamut.
+    ///
+    /// Components may exceed `[0, 1]` by [`SRGB_GAMUT_TOLERANCE`] to allow for
+    /// rounding in the renotation data.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::ReverseConverter;
+    /// use munsellspace::reverse_conversion::parse_munsell_notation;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let converter = ReverseConverter::new()?;
+    /// assert!(converter.is_in_srgb_gamut(&parse_munsell_notation("5R 5.0/6.0")?)?);
+    /// assert!(!converter.is_in_srgb_gamut(&parse_munsell_notation("5G 5.0/20.0")?)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_in_srgb_gamut(&self, spec: &MunsellSpecification) -> Result<bool> {
+        let linear = self.munsell_to_linear_srgb(spec)?;
+        Ok(linear
+            .iter()
+            .all(|c| (-SRGB_GAMUT_TOLERANCE..=1.0 + SRGB_GAMUT_TOLERANCE).contains(c)))
+    }
+
     /// Convert Munsell specification to hexadecimal string
     pub fn munsell_to_hex(&self, spec: &MunsellSpecification) -> Result<String> {
         let srgb = self.munsell_to_srgb(spec)?;
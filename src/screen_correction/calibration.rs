@@ -0,0 +1,410 @@
+//! Fitting screen correction models to paired measurements, and storing them.
+
+use std::io::Read;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::{hue_number_to_degrees, HueCoefficients, ScreenCorrector, MAX_HARMONICS};
+use crate::error::{MunsellError, Result};
+use crate::semantic_overlay::MunsellSpec;
+use crate::types::MunsellColor;
+
+/// Pivots smaller than this make the hue system singular.
+const SINGULAR_PIVOT: f64 = 1e-12;
+
+/// Options for [`ScreenCorrector::fit`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScreenFitOptions {
+    /// Harmonics of the Fourier hue model (default 4)
+    pub harmonics: usize,
+    /// Minimum chroma of both colors of a pair for it to constrain the hue
+    /// model; hues of near-neutral colors are unreliable (default 1.0)
+    pub min_hue_chroma: f64,
+}
+
+impl Default for ScreenFitOptions {
+    fn default() -> Self {
+        Self {
+            harmonics: 4,
+            min_hue_chroma: 1.0,
+        }
+    }
+}
+
+/// Residuals of one pair: corrected screen color minus physical color.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PairResidual {
+    /// Value residual
+    pub value: f64,
+    /// Chroma residual
+    pub chroma: f64,
+    /// Hue residual in degrees, wrapped to (-180, 180]; `None` when the pair
+    /// did not constrain the hue model
+    pub hue_degrees: Option<f64>,
+}
+
+/// Fitted corrector with its residuals.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScreenFitReport {
+    /// Fitted corrector
+    pub corrector: ScreenCorrector,
+    /// Residuals of each pair, in input order
+    pub residuals: Vec<PairResidual>,
+    /// Pairs that constrained the hue model
+    pub hue_pairs: usize,
+    /// Root mean square value residual
+    pub value_rms: f64,
+    /// Root mean square chroma residual
+    pub chroma_rms: f64,
+    /// Root mean square hue residual in degrees
+    pub hue_rms_degrees: f64,
+    /// Largest absolute hue residual in degrees
+    pub hue_max_degrees: f64,
+}
+
+impl ScreenCorrector {
+    /// Fit a corrector to paired (screen, physical) Munsell colors.
+    ///
+    /// Value and chroma offsets are the least-squares constants, which are
+    /// the mean differences between screen and physical colors. The hue
+    /// correction is a Fourier series in the screen hue with
+    /// [`ScreenFitOptions::harmonics`] harmonics, fitted by linear least
+    /// squares to the hue differences of pairs whose colors are both
+    /// chromatic enough. The clamping limits are the defaults.
+    ///
+    /// # Errors
+    /// Returns an error if a color cannot be read, if fewer pairs constrain
+    /// the hue than the model has coefficients, or if their hues are too
+    /// clustered to determine the coefficients.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::MunsellColor;
+    /// use munsellspace::screen_correction::{ScreenCorrector, ScreenFitOptions};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // Measurements of six swatches: screen reading, then spectrophotometer
+    /// let pairs: Vec<(MunsellColor, MunsellColor)> = [
+    ///     ("5R 5.8/12.0", "6R 5.0/10.0"),
+    ///     ("5Y 8.9/10.0", "4Y 8.0/8.2"),
+    ///     ("5G 6.4/9.0", "6G 5.5/7.0"),
+    ///     ("5B 5.9/8.0", "4B 5.1/6.1"),
+    ///     ("5P 4.7/10.0", "5P 3.9/8.0"),
+    ///     ("5YR 6.6/9.0", "5YR 5.8/7.1"),
+    /// ]
+    /// .iter()
+    /// .map(|(s, p)| Ok((MunsellColor::from_notation(s)?, MunsellColor::from_notation(p)?)))
+    /// .collect::<munsellspace::Result<_>>()?;
+    ///
+    /// let options = ScreenFitOptions { harmonics: 1, ..ScreenFitOptions::default() };
+    /// let report = ScreenCorrector::fit(&pairs, &options)?;
+    /// assert!((report.corrector.value_correction - 0.83).abs() < 0.01);
+    /// assert_eq!(report.corrector.harmonics(), 1);
+    /// println!("hue RMS {:.1} degrees", report.hue_rms_degrees);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fit(
+        pairs: &[(MunsellColor, MunsellColor)],
+        options: &ScreenFitOptions,
+    ) -> Result<ScreenFitReport> {
+        if pairs.is_empty() {
+            return Err(MunsellError::ConversionError {
+                message: "Fitting a screen correction needs at least one pair".to_string(),
+            });
+        }
+
+        let specs = pairs
+            .iter()
+            .map(|(screen, physical)| Ok((read_spec(screen)?, read_spec(physical)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let n = specs.len() as f64;
+        let value_correction = specs.iter().map(|(s, p)| s.value - p.value).sum::<f64>() / n;
+        let chroma_correction = specs.iter().map(|(s, p)| s.chroma - p.chroma).sum::<f64>() / n;
+
+        // Hue differences, screen minus physical, against the screen hue
+        let hue_samples: Vec<(usize, f64, f64)> = specs
+            .iter()
+            .enumerate()
+            .filter(|(_, (s, p))| {
+                s.chroma >= options.min_hue_chroma && p.chroma >= options.min_hue_chroma
+            })
+            .map(|(i, (s, p))| {
+                let screen = hue_number_to_degrees(s.hue_number);
+                let physical = hue_number_to_degrees(p.hue_number);
+                (i, screen, wrap_degrees(screen - physical))
+            })
+            .collect();
+
+        if options.harmonics > MAX_HARMONICS {
+            return Err(MunsellError::ConversionError {
+                message: format!(
+                    "A hue model has at most {} harmonics, got {}",
+                    MAX_HARMONICS, options.harmonics
+                ),
+            });
+        }
+        let terms = 2 * options.harmonics + 1;
+        if hue_samples.len() < terms {
+            return Err(MunsellError::ConversionError {
+                message: format!(
+                    "A {}-harmonic hue model needs at least {} chromatic pairs, got {}",
+                    options.harmonics,
+                    terms,
+                    hue_samples.len()
+                ),
+            });
+        }
+
+        // Normal equations of the Fourier design matrix
+        let mut normal = vec![vec![0.0; terms]; terms];
+        let mut rhs = vec![0.0; terms];
+        for &(_, hue, difference) in &hue_samples {
+            let row = fourier_terms(hue, options.harmonics);
+            for i in 0..terms {
+                rhs[i] += row[i] * difference;
+                for j in 0..terms {
+                    normal[i][j] += row[i] * row[j];
+                }
+            }
+        }
+        let hue_coefficients = solve(normal, rhs)
+            .as_deref()
+            .and_then(HueCoefficients::from_slice)
+            .ok_or_else(|| MunsellError::ConversionError {
+                message: "Screen hues are too clustered to fit the hue model; \
+                          add pairs around the hue circle or lower the harmonics"
+                    .to_string(),
+            })?;
+
+        let corrector = ScreenCorrector {
+            value_correction,
+            chroma_correction,
+            hue_coefficients,
+            ..ScreenCorrector::new()
+        };
+
+        let mut residuals: Vec<PairResidual> = specs
+            .iter()
+            .map(|(s, p)| PairResidual {
+                value: s.value - value_correction - p.value,
+                chroma: s.chroma - chroma_correction - p.chroma,
+                hue_degrees: None,
+            })
+            .collect();
+        for &(i, hue, difference) in &hue_samples {
+            residuals[i].hue_degrees = Some(wrap_degrees(corrector.get_hue_correction(hue) - difference));
+        }
+
+        let rms = |values: &mut dyn Iterator<Item = f64>| {
+            let (sum, count) = values.fold((0.0, 0usize), |(sum, count), v| (sum + v * v, count + 1));
+            (sum / count as f64).sqrt()
+        };
+        let hue_residuals = || residuals.iter().filter_map(|r| r.hue_degrees);
+
+        Ok(ScreenFitReport {
+            value_rms: rms(&mut residuals.iter().map(|r| r.value)),
+            chroma_rms: rms(&mut residuals.iter().map(|r| r.chroma)),
+            hue_rms_degrees: rms(&mut hue_residuals()),
+            hue_max_degrees: hue_residuals().map(f64::abs).fold(0.0, f64::max),
+            hue_pairs: hue_samples.len(),
+            corrector,
+            residuals,
+        })
+    }
+
+    /// Serialize the corrector to pretty-printed JSON.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Load a corrector from JSON.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::ReferenceDataError`] for invalid JSON or a hue
+    /// coefficient list that [`HueCoefficients::from_slice`] rejects.
+    pub fn from_json_str(json: &str) -> Result<Self> {
+        serde_json::from_str::<Self>(json).map_err(json_error)
+    }
+
+    /// Load a corrector in the JSON format from a reader.
+    ///
+    /// # Errors
+    /// As [`from_json_str`](Self::from_json_str).
+    pub fn from_json_reader<R: Read>(reader: R) -> Result<Self> {
+        serde_json::from_reader::<_, Self>(reader).map_err(json_error)
+    }
+
+    /// Load a corrector from a JSON file.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::IoError`] if the file cannot be read, otherwise
+    /// as [`from_json_str`](Self::from_json_str).
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = std::fs::File::open(path)?;
+        Self::from_json_reader(std::io::BufReader::new(file))
+    }
+
+    /// Write the corrector to a JSON file.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::IoError`] if the file cannot be written.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        std::fs::write(path, self.to_json_string()?)?;
+        Ok(())
+    }
+}
+
+/// Read a color as a specification with numeric hue.
+fn read_spec(color: &MunsellColor) -> Result<MunsellSpec> {
+    color.to_munsell_spec().ok_or_else(|| {
+        MunsellError::InvalidMunsellColor(format!("Cannot read {}", color.notation))
+    })
+}
+
+/// Wrap an angle difference to (-180, 180].
+fn wrap_degrees(degrees: f64) -> f64 {
+    let wrapped = degrees.rem_euclid(360.0);
+    if wrapped > 180.0 {
+        wrapped - 360.0
+    } else {
+        wrapped
+    }
+}
+
+/// Row of the Fourier design matrix: 1, then cos and sin of each harmonic.
+fn fourier_terms(hue_degrees: f64, harmonics: usize) -> Vec<f64> {
+    let hue_rad = hue_degrees.to_radians();
+    let mut row = Vec::with_capacity(2 * harmonics + 1);
+    row.push(1.0);
+    for k in 1..=harmonics {
+        row.push((k as f64 * hue_rad).cos());
+        row.push((k as f64 * hue_rad).sin());
+    }
+    row
+}
+
+/// Solve a square linear system by Gaussian elimination with partial
+/// pivoting; `None` if it is singular.
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let n = rhs.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))?;
+        if matrix[pivot][col].abs() < SINGULAR_PIVOT {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+
+        let pivot_row = matrix[col].clone();
+        for row in col + 1..n {
+            let factor = matrix[row][col] / pivot_row[col];
+            for (m, p) in matrix[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *m -= factor * p;
+            }
+            rhs[row] -= factor * rhs[col];
+        }
+    }
+
+    let mut solution = vec![0.0; n];
+    for row in (0..n).rev() {
+        let tail: f64 = (row + 1..n).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (rhs[row] - tail) / matrix[row][row];
+    }
+    Some(solution)
+}
+
+/// Convert a JSON parse error into a reference data error.
+fn json_error(error: serde_json::Error) -> MunsellError {
+    MunsellError::ReferenceDataError {
+        message: format!("Invalid screen correction JSON: {}", error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic_overlay::hue_number_to_string;
+
+    /// Pairs generated by a known two-harmonic model.
+    fn synthetic_pairs(truth: &ScreenCorrector) -> Vec<(MunsellColor, MunsellColor)> {
+        (0..24)
+            .map(|i| {
+                let screen_hue = i as f64 * 15.0 + 3.0;
+                let physical_hue = screen_hue - truth.get_hue_correction(screen_hue);
+                let chroma = 6.0 + (i % 3) as f64 * 2.0;
+                let color = |hue_degrees: f64, value: f64, chroma: f64| {
+                    let (hue, _) = hue_number_to_string(hue_degrees.rem_euclid(360.0) / 9.0);
+                    MunsellColor::new_chromatic(hue, value, chroma)
+                };
+                (
+                    color(screen_hue, 6.0, chroma),
+                    color(physical_hue, 6.0 - truth.value_correction, chroma - truth.chroma_correction),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fit_recovers_model() {
+        let truth = ScreenCorrector {
+            value_correction: 0.6,
+            chroma_correction: 2.5,
+            hue_coefficients: HueCoefficients::from_slice(&[1.5, 4.0, -6.0, 2.0, 3.0]).unwrap(),
+            ..ScreenCorrector::new()
+        };
+        let options = ScreenFitOptions {
+            harmonics: 2,
+            ..ScreenFitOptions::default()
+        };
+        let report = ScreenCorrector::fit(&synthetic_pairs(&truth), &options).unwrap();
+
+        let fitted = &report.corrector;
+        assert!((fitted.value_correction - 0.6).abs() < 1e-6);
+        assert!((fitted.chroma_correction - 2.5).abs() < 1e-6);
+        assert_eq!(fitted.harmonics(), 2);
+        for (a, b) in fitted.hue_coefficients.iter().zip(truth.hue_coefficients.iter()) {
+            // Hue notation is rounded to 0.1 hue step (0.36 degrees)
+            assert!((a - b).abs() < 0.3, "{:?}", fitted.hue_coefficients);
+        }
+        assert_eq!(report.hue_pairs, 24);
+        assert!(report.hue_rms_degrees < 0.3 && report.value_rms < 1e-6);
+
+        // Too few pairs for twelve harmonics
+        let options = ScreenFitOptions {
+            harmonics: 12,
+            ..ScreenFitOptions::default()
+        };
+        assert!(ScreenCorrector::fit(&synthetic_pairs(&truth), &options).is_err());
+        let options = ScreenFitOptions {
+            harmonics: MAX_HARMONICS + 1,
+            ..ScreenFitOptions::default()
+        };
+        assert!(ScreenCorrector::fit(&synthetic_pairs(&truth), &options).is_err());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let corrector = ScreenCorrector {
+            hue_coefficients: HueCoefficients::from_slice(&[1.0, 2.0, 3.0]).unwrap(),
+            ..ScreenCorrector::with_custom(0.5, 1.5)
+        };
+        let json = corrector.to_json_string().unwrap();
+        let loaded = ScreenCorrector::from_json_str(&json).unwrap();
+        assert_eq!(loaded, corrector);
+        assert_eq!(loaded.get_hue_correction(0.0), corrector.get_hue_correction(0.0));
+
+        let path = std::env::temp_dir().join(format!("screen_corrector_{}.json", std::process::id()));
+        ScreenCorrector::new().save(&path).unwrap();
+        assert_eq!(ScreenCorrector::from_file(&path).unwrap(), ScreenCorrector::new());
+        std::fs::remove_file(&path).unwrap();
+
+        let even = json.replace("3.0\n", "3.0,\n    4.0\n");
+        assert!(ScreenCorrector::from_json_str(&even).is_err());
+    }
+}
@@ -40,6 +40,18 @@
 //! }
 //! ```
 //!
+//! ## Calibration
+//!
+//! The default model suits typical sRGB displays. A model for a specific
+//! monitor can be fitted from paired screen and physical measurements with
+//! [`ScreenCorrector::fit`], and saved and loaded as JSON.
+//!
+//! ## Previewing Physical Colors
+//!
+//! [`ScreenCorrector::preview`] runs the correction backwards, so a physical
+//! chip can be shown on screen the way it looks on paper, and reports when
+//! the screen color falls outside the sRGB gamut.
+//!
 //! ## Accuracy
 //!
 //! The correction model achieves:
@@ -52,10 +64,18 @@
 //! - XKCD Color Survey (175,844 color names from web users)
 //! - Centore Polyhedron Data (30 spectrophotometer-measured color categories)
 
+use serde::{Deserialize, Serialize};
+
 use crate::error::{MunsellError, Result};
-use crate::semantic_overlay::parse_hue_to_number;
+use crate::semantic_overlay::{parse_hue_to_number, MunsellSpec};
 use crate::types::MunsellColor;
 
+mod calibration;
+mod preview;
+
+pub use calibration::{PairResidual, ScreenFitOptions, ScreenFitReport};
+pub use preview::{preview_physical_color, ScreenPreview};
+
 /// Correction constants derived from XKCD vs Centore comparison
 ///
 /// Value correction: subtract from screen value to get physical value
@@ -87,6 +107,85 @@ const HUE_COEFFS: [f64; 9] = [
 /// Number of harmonics in the Fourier model
 const N_HARMONICS: usize = 4;
 
+// The coefficient table must hold a constant and a cos/sin pair per harmonic
+const _: () = assert!(HUE_COEFFS.len() == 2 * N_HARMONICS + 1);
+
+/// Largest number of harmonics a corrector's hue model can have
+pub const MAX_HARMONICS: usize = 12;
+
+/// Fourier hue coefficients: the constant term, then a cos/sin pair per
+/// harmonic, up to [`MAX_HARMONICS`] harmonics.
+///
+/// The coefficients are stored inline so that [`ScreenCorrector`] is `Copy`.
+/// They dereference to a slice and serialize as a list of numbers.
+#[derive(Clone, Copy, PartialEq)]
+pub struct HueCoefficients {
+    len: usize,
+    values: [f64; 2 * MAX_HARMONICS + 1],
+}
+
+impl HueCoefficients {
+    /// Coefficients from a list, or `None` unless it holds a constant and a
+    /// cos/sin pair per harmonic, with at most [`MAX_HARMONICS`] harmonics.
+    #[must_use]
+    pub fn from_slice(coefficients: &[f64]) -> Option<Self> {
+        if coefficients.len() % 2 == 0 || coefficients.len() > 2 * MAX_HARMONICS + 1 {
+            return None;
+        }
+        let mut values = [0.0; 2 * MAX_HARMONICS + 1];
+        values[..coefficients.len()].copy_from_slice(coefficients);
+        Some(Self {
+            len: coefficients.len(),
+            values,
+        })
+    }
+
+    /// The coefficients as a slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[f64] {
+        &self.values[..self.len]
+    }
+}
+
+impl Default for HueCoefficients {
+    fn default() -> Self {
+        Self::from_slice(&HUE_COEFFS).expect("the default model fits")
+    }
+}
+
+impl std::ops::Deref for HueCoefficients {
+    type Target = [f64];
+
+    fn deref(&self) -> &[f64] {
+        self.as_slice()
+    }
+}
+
+impl std::fmt::Debug for HueCoefficients {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl Serialize for HueCoefficients {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+impl<'de> Deserialize<'de> for HueCoefficients {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let coefficients = Vec::<f64>::deserialize(deserializer)?;
+        Self::from_slice(&coefficients).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "expected an odd number of hue coefficients, at most {}, got {}",
+                2 * MAX_HARMONICS + 1,
+                coefficients.len()
+            ))
+        })
+    }
+}
+
 /// Predict the hue correction to apply for a given screen hue.
 ///
 /// # Arguments
@@ -109,14 +208,19 @@ const N_HARMONICS: usize = 4;
 /// assert!(correction > 20.0);
 /// ```
 pub fn predict_hue_correction(hue_degrees: f64) -> f64 {
+    fourier_series(&HUE_COEFFS, hue_degrees)
+}
+
+/// Evaluate Fourier coefficients laid out as in [`HUE_COEFFS`].
+fn fourier_series(coefficients: &[f64], hue_degrees: f64) -> f64 {
     let hue_rad = hue_degrees.to_radians();
 
-    let mut correction = HUE_COEFFS[0]; // constant term
+    let mut correction = coefficients[0]; // constant term
 
-    for k in 1..=N_HARMONICS {
+    for k in 1..=(coefficients.len() - 1) / 2 {
         let idx = 2 * k - 1;
-        correction += HUE_COEFFS[idx] * ((k as f64) * hue_rad).cos();
-        correction += HUE_COEFFS[idx + 1] * ((k as f64) * hue_rad).sin();
+        correction += coefficients[idx] * ((k as f64) * hue_rad).cos();
+        correction += coefficients[idx + 1] * ((k as f64) * hue_rad).sin();
     }
 
     correction
@@ -168,7 +272,12 @@ fn hue_number_to_string(hue_num: f64) -> String {
 ///
 /// This struct provides methods to correct Munsell colors derived from
 /// screen (RGB) sources to more accurate physical color references.
-#[derive(Debug, Clone, Copy)]
+///
+/// Correctors serialize to JSON, so a model fitted with
+/// [`fit`](Self::fit) can be stored with
+/// [`to_json_string`](Self::to_json_string) and loaded with
+/// [`from_json_str`](Self::from_json_str) or [`from_file`](Self::from_file).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct ScreenCorrector {
     /// Value correction factor
     pub value_correction: f64,
@@ -180,6 +289,9 @@ pub struct ScreenCorrector {
     pub min_value: f64,
     /// Maximum value after correction
     pub max_value: f64,
+    /// Fourier hue coefficients (defaults to the 4-harmonic XKCD vs Centore
+    /// fit)
+    pub hue_coefficients: HueCoefficients,
 }
 
 impl Default for ScreenCorrector {
@@ -198,6 +310,7 @@ impl ScreenCorrector {
             min_chroma: 0.0,
             min_value: 0.0,
             max_value: 10.0,
+            hue_coefficients: HueCoefficients::default(),
         }
     }
 
@@ -214,6 +327,7 @@ impl ScreenCorrector {
             min_chroma: 0.0,
             min_value: 0.0,
             max_value: 10.0,
+            hue_coefficients: HueCoefficients::default(),
         }
     }
 
@@ -248,7 +362,7 @@ impl ScreenCorrector {
         let hue_degrees = hue_number_to_degrees(hue_num);
 
         // Compute corrections
-        let hue_correction = predict_hue_correction(hue_degrees);
+        let hue_correction = self.get_hue_correction(hue_degrees);
         let corrected_hue_degrees = hue_degrees - hue_correction;
         let corrected_hue_num = degrees_to_hue_number(corrected_hue_degrees);
         let corrected_hue_str = hue_number_to_string(corrected_hue_num);
@@ -271,6 +385,12 @@ impl ScreenCorrector {
         ))
     }
 
+    /// Number of harmonics in the hue model.
+    #[must_use]
+    pub fn harmonics(&self) -> usize {
+        self.hue_coefficients.len().saturating_sub(1) / 2
+    }
+
     /// Get the hue correction for a given screen hue in degrees.
     ///
     /// # Arguments
@@ -280,8 +400,102 @@ impl ScreenCorrector {
     /// Hue correction in degrees (subtract from screen hue)
     #[must_use]
     pub fn get_hue_correction(&self, hue_degrees: f64) -> f64 {
-        predict_hue_correction(hue_degrees)
+        if self.hue_coefficients.is_empty() {
+            return 0.0;
+        }
+        fourier_series(&self.hue_coefficients, hue_degrees)
+    }
+
+    /// Find the screen hue that corrects to a given physical hue.
+    ///
+    /// The Fourier hue correction is not monotonic everywhere, so the inverse
+    /// is found numerically: every screen hue `s` with
+    /// `s - correction(s) = physical` is located by bracketing and bisection,
+    /// and the one closest to the physical hue is returned.
+    ///
+    /// # Arguments
+    /// * `physical_degrees` - Physical hue in degrees (0-360)
+    ///
+    /// # Returns
+    /// Screen hue in degrees, normalized to 0-360
+    #[must_use]
+    pub fn inverse_hue_degrees(&self, physical_degrees: f64) -> f64 {
+        let physical = physical_degrees.rem_euclid(360.0);
+        // Residual wrapped to (-180, 180]
+        let residual = |s: f64| {
+            let r = (s - self.get_hue_correction(s) - physical).rem_euclid(360.0);
+            if r > 180.0 { r - 360.0 } else { r }
+        };
+
+        let mut best: Option<f64> = None;
+        let mut previous = (0.0, residual(0.0));
+        for step in 1..=INVERSE_SCAN_STEPS {
+            let s = step as f64 * 360.0 / INVERSE_SCAN_STEPS as f64;
+            let current = (s, residual(s));
+
+            // A sign change away from the ±180° wrap brackets a root
+            if (previous.1 <= 0.0) != (current.1 <= 0.0)
+                && previous.1.abs() < 90.0
+                && current.1.abs() < 90.0
+            {
+                let (mut lo, mut hi) = (previous.0, current.0);
+                let lo_negative = previous.1 <= 0.0;
+                for _ in 0..60 {
+                    let mid = (lo + hi) / 2.0;
+                    if (residual(mid) <= 0.0) == lo_negative {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                let root = ((lo + hi) / 2.0).rem_euclid(360.0);
+                let closer = match best {
+                    Some(b) => circular_distance(root, physical) < circular_distance(b, physical),
+                    None => true,
+                };
+                if closer {
+                    best = Some(root);
+                }
+            }
+            previous = current;
+        }
+
+        // The correction is periodic, so a root always exists; fall back to a
+        // first-order estimate should the scan miss it
+        best.unwrap_or_else(|| (physical + self.get_hue_correction(physical)).rem_euclid(360.0))
     }
+
+    /// Map a physical Munsell color back to the screen-derived color that
+    /// [`correct`](Self::correct) would turn into it.
+    ///
+    /// Value and chroma offsets are added back and the hue is inverted with
+    /// [`inverse_hue_degrees`](Self::inverse_hue_degrees). Screen chroma below
+    /// the chroma correction collapses to neutral in the forward direction, so
+    /// a neutral physical color maps to the neutral screen color of the same
+    /// corrected value.
+    #[must_use]
+    pub fn invert_spec(&self, physical: &MunsellSpec) -> MunsellSpec {
+        let value = (physical.value + self.value_correction).clamp(0.0, 10.0);
+        if physical.chroma <= 0.0 {
+            return MunsellSpec::neutral(value);
+        }
+
+        let hue_degrees = self.inverse_hue_degrees(hue_number_to_degrees(physical.hue_number));
+        MunsellSpec::new(
+            degrees_to_hue_number(hue_degrees),
+            value,
+            physical.chroma + self.chroma_correction,
+        )
+    }
+}
+
+/// Sampling steps used to bracket roots of the inverse hue correction.
+const INVERSE_SCAN_STEPS: usize = 720;
+
+/// Angular distance between two hues in degrees.
+fn circular_distance(a: f64, b: f64) -> f64 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d)
 }
 
 /// Convenience function to correct a screen-derived Munsell color.
@@ -417,6 +631,35 @@ mod tests {
         assert_eq!(hue_number_to_string(10.0), "5.0Y");
     }
 
+    #[test]
+    fn test_inverse_hue_round_trip() {
+        let corrector = ScreenCorrector::new();
+        for i in 0..360 {
+            let physical = i as f64;
+            let screen = corrector.inverse_hue_degrees(physical);
+            let back = screen - corrector.get_hue_correction(screen);
+            assert!(circular_distance(back, physical) < 1e-6, "{} -> {} -> {}", physical, screen, back);
+        }
+    }
+
+    #[test]
+    fn test_invert_spec_round_trip() {
+        let corrector = ScreenCorrector::new();
+        let physical = MunsellSpec::new(22.0, 5.0, 6.0);
+        let screen = corrector.invert_spec(&physical);
+        assert!((screen.value - (5.0 + VALUE_CORRECTION)).abs() < 1e-9);
+        assert!((screen.chroma - (6.0 + CHROMA_CORRECTION)).abs() < 1e-9);
+
+        let screen_color = MunsellColor::from_notation(&screen.to_notation()).unwrap();
+        let back = corrector.correct(&screen_color).unwrap().to_munsell_spec().unwrap();
+        // Notation rounding limits the round trip precision
+        assert!(back.distance_from(&physical) < 0.2, "{:?}", back);
+
+        let gray = corrector.invert_spec(&MunsellSpec::neutral(5.0));
+        assert_eq!(gray.chroma, 0.0);
+        assert!((gray.value - (5.0 + VALUE_CORRECTION)).abs() < 1e-9);
+    }
+
     #[test]
     fn test_correct_screen_color_function() {
         let screen = MunsellColor::new_chromatic("5BG".to_string(), 7.0, 8.0);
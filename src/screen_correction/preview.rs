@@ -0,0 +1,162 @@
+//! Physical-to-screen preview: the inverse of the screen correction.
+
+use serde::{Deserialize, Serialize};
+
+use super::ScreenCorrector;
+use crate::error::{MunsellError, Result};
+use crate::gamut::in_srgb_gamut;
+use crate::reverse_conversion::ReverseConverter;
+use crate::types::MunsellColor;
+
+/// How a physical Munsell color is shown on screen.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScreenPreview {
+    /// Physical color being previewed
+    pub physical: MunsellColor,
+    /// Screen color that the corrector maps to the physical color
+    pub screen: MunsellColor,
+    /// Display sRGB color
+    pub srgb: [u8; 3],
+    /// Whether the screen color lies inside the sRGB gamut; when it does not,
+    /// `srgb` is an approximation
+    pub in_gamut: bool,
+    /// Unclipped linear sRGB of the screen color, or `None` when it lies
+    /// outside the renotation data; components outside `[0, 1]` show how
+    /// far it leaves the gamut
+    pub linear_rgb: Option<[f64; 3]>,
+}
+
+impl ScreenCorrector {
+    /// Map a physical Munsell color to the screen color that
+    /// [`correct`](Self::correct) turns into it.
+    ///
+    /// The hue Fourier series is inverted numerically with
+    /// [`inverse_hue_degrees`](Self::inverse_hue_degrees), and the value and
+    /// chroma offsets are added back; see [`invert_spec`](Self::invert_spec).
+    ///
+    /// # Errors
+    /// Returns an error if the hue of the color cannot be read.
+    pub fn invert(&self, physical: &MunsellColor) -> Result<MunsellColor> {
+        let spec = physical.to_munsell_spec().ok_or_else(|| MunsellError::InvalidNotation {
+            notation: physical.notation.clone(),
+            reason: "Failed to parse hue".to_string(),
+        })?;
+        Ok(self.invert_spec(&spec).to_munsell_color())
+    }
+
+    /// Preview a physical Munsell color on an sRGB display.
+    ///
+    /// The color is mapped to its screen counterpart with
+    /// [`invert`](Self::invert) and rendered with the reverse converter.
+    /// Screen colors outside the sRGB gamut are clipped; screen colors beyond
+    /// the renotation data are shown at the highest chroma that can be
+    /// rendered. Both are reported with `in_gamut == false`.
+    ///
+    /// # Errors
+    /// Returns an error if the hue of the color cannot be read.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::{MunsellColor, ReverseConverter};
+    /// use munsellspace::screen_correction::ScreenCorrector;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let corrector = ScreenCorrector::new();
+    /// let reverse = ReverseConverter::new()?;
+    ///
+    /// // A soil chip: the screen needs a lighter, more chromatic color
+    /// let chip = MunsellColor::from_notation("10YR 4.0/3.0")?;
+    /// let preview = corrector.preview(&chip, &reverse)?;
+    /// assert!(preview.screen.value > chip.value);
+    /// assert!(preview.in_gamut);
+    ///
+    /// // A vivid green paper sample cannot be matched on an sRGB display
+    /// let green = MunsellColor::from_notation("5G 5.0/12.0")?;
+    /// assert!(!corrector.preview(&green, &reverse)?.in_gamut);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn preview(
+        &self,
+        physical: &MunsellColor,
+        reverse: &ReverseConverter,
+    ) -> Result<ScreenPreview> {
+        let physical_spec = physical.to_munsell_spec().ok_or_else(|| {
+            MunsellError::InvalidNotation {
+                notation: physical.notation.clone(),
+                reason: "Failed to parse hue".to_string(),
+            }
+        })?;
+        let screen_spec = self.invert_spec(&physical_spec);
+        let specification = screen_spec.to_specification();
+
+        let linear_rgb = reverse.munsell_to_linear_srgb(&specification).ok();
+        let in_gamut = linear_rgb.is_some() && in_srgb_gamut(screen_spec, reverse);
+
+        Ok(ScreenPreview {
+            physical: physical.clone(),
+            screen: screen_spec.to_munsell_color(),
+            srgb: screen_spec.display_srgb(reverse),
+            in_gamut,
+            linear_rgb,
+        })
+    }
+}
+
+/// Convenience function to preview a physical Munsell color on screen.
+///
+/// This is equivalent to `ScreenCorrector::new().preview(color, &reverse)`
+/// with a new [`ReverseConverter`]; create one converter and call
+/// [`ScreenCorrector::preview`] to preview many colors.
+///
+/// # Errors
+/// Returns an error if the reverse converter cannot be created or the hue of
+/// the color cannot be read.
+pub fn preview_physical_color(physical: &MunsellColor) -> Result<ScreenPreview> {
+    ScreenCorrector::new().preview(physical, &ReverseConverter::new()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_inverts_correction() {
+        let corrector = ScreenCorrector::new();
+        let reverse = ReverseConverter::new().unwrap();
+
+        let physical = MunsellColor::from_notation("5R 5.0/4.0").unwrap();
+        let preview = corrector.preview(&physical, &reverse).unwrap();
+        assert!(preview.in_gamut);
+        assert!(preview.linear_rgb.unwrap().iter().all(|c| (0.0..=1.0).contains(c)));
+
+        let back = corrector.correct(&preview.screen).unwrap();
+        let distance = back
+            .to_munsell_spec()
+            .unwrap()
+            .distance_from(&physical.to_munsell_spec().unwrap());
+        assert!(distance < 0.2, "{} -> {} -> {}", physical, preview.screen, back);
+
+        let screen_spec = preview.screen.to_munsell_spec().unwrap();
+        assert_eq!(Some(preview.srgb), screen_spec.to_srgb(&reverse));
+
+        let gray = preview_physical_color(&MunsellColor::new_neutral(5.0)).unwrap();
+        assert!(gray.screen.is_neutral() && gray.in_gamut);
+    }
+
+    #[test]
+    fn test_preview_reports_gamut() {
+        let corrector = ScreenCorrector::new();
+        let reverse = ReverseConverter::new().unwrap();
+
+        let preview = corrector
+            .preview(&MunsellColor::from_notation("5PB 3.0/12.0").unwrap(), &reverse)
+            .unwrap();
+        assert!(!preview.in_gamut);
+        assert!(preview.linear_rgb.unwrap().iter().any(|c| !(0.0..=1.0).contains(c)));
+
+        // Teal shifts far on screen, so even a moderate chip leaves the gamut
+        let teal = MunsellColor::from_notation("5BG 5.0/4.0").unwrap();
+        assert!(!corrector.preview(&teal, &reverse).unwrap().in_gamut);
+    }
+}
@@ -0,0 +1,172 @@
+//! Building semantic overlays from labelled color samples.
+
+use crate::error::{MunsellError, Result};
+use crate::MunsellConverter;
+use super::polyhedron::ConvexPolyhedron;
+use super::registry::SemanticOverlay;
+use super::types::{MunsellCartesian, MunsellSpec};
+
+/// Builds a [`SemanticOverlay`] from samples labelled with one color name.
+///
+/// Follows Centore's method: samples are converted to Munsell Cartesian
+/// coordinates with [`MunsellSpec::to_cartesian`], optionally trimmed of
+/// outliers, and the overlay region is their convex hull. The centroid is
+/// the center of mass of the hull.
+///
+/// # Examples
+/// ```rust
+/// use munsellspace::semantic_overlay::{MunsellSpec, OverlayBuilder};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let samples = [
+///     MunsellSpec::new(14.0, 5.0, 2.0),
+///     MunsellSpec::new(13.0, 4.0, 3.0),
+///     MunsellSpec::new(15.0, 6.0, 3.0),
+///     MunsellSpec::new(14.0, 5.5, 4.0),
+///     MunsellSpec::new(14.5, 4.5, 2.5),
+/// ];
+///
+/// let sage = OverlayBuilder::new("sage").samples(samples).build()?;
+/// assert_eq!(sage.name, "sage");
+/// assert!(sage.contains(&sage.centroid));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OverlayBuilder {
+    name: String,
+    samples: Vec<MunsellSpec>,
+    trim_percentile: Option<f64>,
+}
+
+impl OverlayBuilder {
+    /// Start building an overlay for a color name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            samples: Vec::new(),
+            trim_percentile: None,
+        }
+    }
+
+    /// Add one Munsell sample.
+    pub fn sample(mut self, sample: MunsellSpec) -> Self {
+        self.samples.push(sample);
+        self
+    }
+
+    /// Add Munsell samples.
+    pub fn samples<I: IntoIterator<Item = MunsellSpec>>(mut self, samples: I) -> Self {
+        self.samples.extend(samples);
+        self
+    }
+
+    /// Convert sRGB samples to Munsell and add them.
+    ///
+    /// # Errors
+    /// Returns an error if the converter cannot be created or a sample cannot
+    /// be converted.
+    pub fn srgb_samples(self, samples: &[[u8; 3]]) -> Result<Self> {
+        let converter = MunsellConverter::new()?;
+        self.srgb_samples_with(&converter, samples)
+    }
+
+    /// Convert sRGB samples with an existing converter and add them.
+    pub fn srgb_samples_with(
+        mut self,
+        converter: &MunsellConverter,
+        samples: &[[u8; 3]],
+    ) -> Result<Self> {
+        for &rgb in samples {
+            let spec = converter
+                .srgb_to_munsell(rgb)?
+                .to_munsell_spec()
+                .ok_or_else(|| MunsellError::ConversionError {
+                    message: format!("RGB {:?} has no numeric Munsell hue", rgb),
+                })?;
+            self.samples.push(spec);
+        }
+        Ok(self)
+    }
+
+    /// Discard samples farther from the sample mean than the given percentile
+    /// of distances.
+    ///
+    /// `percentile` is in `(0, 100]`; `95.0` drops the 5% of samples farthest
+    /// from the mean in Munsell Cartesian space.
+    pub fn trim_outliers(mut self, percentile: f64) -> Self {
+        self.trim_percentile = Some(percentile);
+        self
+    }
+
+    /// Number of samples added so far.
+    pub fn sample_count(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Compute the hull and build the overlay.
+    ///
+    /// The overlay's `sample_count` is the number of samples kept after
+    /// outlier trimming.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::ConversionError`] if the name is empty, the
+    /// trim percentile is outside `(0, 100]`, or the remaining samples do not
+    /// span a 3D region.
+    pub fn build(&self) -> Result<SemanticOverlay> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(MunsellError::ConversionError {
+                message: "Overlay name must not be empty".to_string(),
+            });
+        }
+
+        let mut points: Vec<MunsellCartesian> =
+            self.samples.iter().map(MunsellSpec::to_cartesian).collect();
+
+        if let Some(percentile) = self.trim_percentile {
+            if !(percentile > 0.0 && percentile <= 100.0) {
+                return Err(MunsellError::ConversionError {
+                    message: format!("Trim percentile {} is outside (0, 100]", percentile),
+                });
+            }
+            points = trim_outliers(points, percentile);
+        }
+
+        let polyhedron = ConvexPolyhedron::convex_hull(&points).map_err(|e| {
+            MunsellError::ConversionError {
+                message: format!("Overlay '{}': {}", name, e),
+            }
+        })?;
+        let centroid = MunsellSpec::from_cartesian(&polyhedron.center_of_mass());
+
+        Ok(SemanticOverlay {
+            name: name.to_string(),
+            polyhedron,
+            centroid,
+            sample_count: points.len() as u32,
+        })
+    }
+}
+
+/// Keep points within the given percentile of distance from their mean.
+fn trim_outliers(points: Vec<MunsellCartesian>, percentile: f64) -> Vec<MunsellCartesian> {
+    if points.is_empty() {
+        return points;
+    }
+
+    let n = points.len() as f64;
+    let mean = MunsellCartesian::new(
+        points.iter().map(|p| p.x).sum::<f64>() / n,
+        points.iter().map(|p| p.y).sum::<f64>() / n,
+        points.iter().map(|p| p.z).sum::<f64>() / n,
+    );
+
+    let mut distances: Vec<f64> = points.iter().map(|p| p.distance(&mean)).collect();
+    distances.sort_by(f64::total_cmp);
+    // Nearest-rank percentile
+    let rank = ((percentile / 100.0) * n).ceil().max(1.0) as usize;
+    let cutoff = distances[rank.min(distances.len()) - 1];
+
+    points.into_iter().filter(|p| p.distance(&mean) <= cutoff).collect()
+}
@@ -1,7 +1,7 @@
 //! Deprecated public API functions for semantic overlay matching.
 //!
 //! These functions are preserved for backward compatibility and will be
-//! removed in v2.0.0. Use `ColorClassifier` instead.
+//! removed in v3.0.0. Use `ColorClassifier` instead.
 
 use super::types::MunsellSpec;
 use super::parsing::parse_munsell_notation;
@@ -21,11 +21,11 @@ use super::parsing::parse_munsell_notation;
 /// ```
 #[deprecated(
     since = "1.2.0",
-    note = "Use ColorClassifier for unified color naming. Access semantic names via ColorDescriptor::semantic_name. This function will be removed in v2.0.0."
+    note = "Use ColorClassifier for unified color naming. Access semantic names via ColorDescriptor::semantic_name. This function will be removed in v3.0.0."
 )]
 pub fn semantic_overlay(color: &MunsellSpec) -> Option<&'static str> {
     let registry = crate::semantic_overlay_data::get_registry();
-    registry.best_match(color).map(|o| o.name)
+    registry.best_match(color).map(|o| o.name.as_str())
 }
 
 /// Get all semantic overlay names that match a Munsell color.
@@ -43,13 +43,13 @@ pub fn semantic_overlay(color: &MunsellSpec) -> Option<&'static str> {
 /// ```
 #[deprecated(
     since = "1.2.0",
-    note = "Use ColorClassifier for unified color naming. Access semantic matches via ColorDescriptor::semantic_name and semantic_alternates. This function will be removed in v2.0.0."
+    note = "Use ColorClassifier for unified color naming. Access semantic matches via ColorDescriptor::semantic_name and semantic_alternates. This function will be removed in v3.0.0."
 )]
 pub fn matching_overlays(color: &MunsellSpec) -> Vec<&'static str> {
     let registry = crate::semantic_overlay_data::get_registry();
     registry.matching_overlays(color)
         .into_iter()
-        .map(|o| o.name)
+        .map(|o| o.name.as_str())
         .collect()
 }
 
@@ -71,13 +71,13 @@ pub fn matching_overlays(color: &MunsellSpec) -> Vec<&'static str> {
 /// ```
 #[deprecated(
     since = "1.2.0",
-    note = "Use ColorClassifier for unified color naming. Access ranked matches via ColorDescriptor::nearest_semantic_descriptor(). This function will be removed in v2.0.0."
+    note = "Use ColorClassifier for unified color naming. Access ranked matches via ColorDescriptor::nearest_semantic_descriptor(). This function will be removed in v3.0.0."
 )]
 pub fn matching_overlays_ranked(color: &MunsellSpec) -> Vec<(&'static str, f64)> {
     let registry = crate::semantic_overlay_data::get_registry();
     registry.matching_overlays_ranked(color)
         .into_iter()
-        .map(|(o, d)| (o.name, d))
+        .map(|(o, d)| (o.name.as_str(), d))
         .collect()
 }
 
@@ -96,7 +96,7 @@ pub fn matching_overlays_ranked(color: &MunsellSpec) -> Vec<(&'static str, f64)>
 /// ```
 #[deprecated(
     since = "1.2.0",
-    note = "Use ColorClassifier for unified color naming. Check semantic matches via ColorDescriptor. This function will be removed in v2.0.0."
+    note = "Use ColorClassifier for unified color naming. Check semantic matches via ColorDescriptor. This function will be removed in v3.0.0."
 )]
 pub fn matches_overlay(color: &MunsellSpec, overlay_name: &str) -> bool {
     let registry = crate::semantic_overlay_data::get_registry();
@@ -119,11 +119,11 @@ pub fn matches_overlay(color: &MunsellSpec, overlay_name: &str) -> bool {
 /// ```
 #[deprecated(
     since = "1.2.0",
-    note = "Use ColorClassifier for unified color naming. Access nearest overlay via ColorDescriptor::nearest_semantic. This function will be removed in v2.0.0."
+    note = "Use ColorClassifier for unified color naming. Access nearest overlay via ColorDescriptor::nearest_semantic. This function will be removed in v3.0.0."
 )]
 pub fn closest_overlay(color: &MunsellSpec) -> Option<(&'static str, f64)> {
     let registry = crate::semantic_overlay_data::get_registry();
-    registry.closest_overlay(color).map(|(o, d)| (o.name, d))
+    registry.closest_overlay(color).map(|(o, d)| (o.name.as_str(), d))
 }
 
 /// Get a semantic overlay name from a Munsell notation string.
@@ -140,7 +140,7 @@ pub fn closest_overlay(color: &MunsellSpec) -> Option<(&'static str, f64)> {
 /// ```
 #[deprecated(
     since = "1.2.0",
-    note = "Use ColorClassifier::classify_munsell() for unified color naming. This function will be removed in v2.0.0."
+    note = "Use ColorClassifier::classify_munsell() for unified color naming. This function will be removed in v3.0.0."
 )]
 #[allow(deprecated)]
 pub fn semantic_overlay_from_notation(notation: &str) -> Option<&'static str> {
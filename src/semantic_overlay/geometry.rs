@@ -0,0 +1,244 @@
+//! Volume, area and overlap measurements on convex polyhedra.
+
+use super::polyhedron::ConvexPolyhedron;
+use super::registry::SemanticOverlay;
+use super::types::MunsellCartesian;
+use super::vec3::{cross, dot, length, sub};
+
+/// Plane `normal · p + offset = 0` with a unit normal pointing outward.
+#[derive(Debug, Clone, Copy)]
+struct Plane {
+    normal: (f64, f64, f64),
+    offset: f64,
+}
+
+impl Plane {
+    fn distance(&self, p: &MunsellCartesian) -> f64 {
+        self.normal.0 * p.x + self.normal.1 * p.y + self.normal.2 * p.z + self.offset
+    }
+}
+
+impl ConvexPolyhedron {
+    /// Volume enclosed by the polyhedron, in cubic Munsell Cartesian units.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::get_registry;
+    ///
+    /// let navy = get_registry().get("navy").unwrap();
+    /// assert!(navy.polyhedron.volume() > 0.0);
+    /// ```
+    pub fn volume(&self) -> f64 {
+        self.tetrahedra().map(|(v, _)| v).sum()
+    }
+
+    /// Total area of the faces.
+    pub fn surface_area(&self) -> f64 {
+        self.faces
+            .iter()
+            .map(|f| {
+                let (a, b, c) = (
+                    &self.vertices[f.v0],
+                    &self.vertices[f.v1],
+                    &self.vertices[f.v2],
+                );
+                length(cross(sub(b, a), sub(c, a))) / 2.0
+            })
+            .sum()
+    }
+
+    /// Axis-aligned bounding box as `(min, max)` corners, or `None` without vertices.
+    pub fn bounding_box(&self) -> Option<(MunsellCartesian, MunsellCartesian)> {
+        let first = *self.vertices.first()?;
+        Some(self.vertices.iter().fold((first, first), |(min, max), v| {
+            (
+                MunsellCartesian::new(min.x.min(v.x), min.y.min(v.y), min.z.min(v.z)),
+                MunsellCartesian::new(max.x.max(v.x), max.y.max(v.y), max.z.max(v.z)),
+            )
+        }))
+    }
+
+    /// Center of mass of the enclosed solid.
+    ///
+    /// Unlike [`centroid`](Self::centroid), which averages the vertices, this is
+    /// not biased toward densely sampled parts of the surface. Falls back to the
+    /// vertex average when the volume vanishes.
+    pub fn center_of_mass(&self) -> MunsellCartesian {
+        let mut volume = 0.0;
+        let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+        for (v, c) in self.tetrahedra() {
+            volume += v;
+            x += v * c.x;
+            y += v * c.y;
+            z += v * c.z;
+        }
+
+        if volume <= f64::EPSILON {
+            return self.centroid();
+        }
+        MunsellCartesian::new(x / volume, y / volume, z / volume)
+    }
+
+    /// Intersection of two convex polyhedra, or `None` if they do not share a
+    /// region of positive volume.
+    ///
+    /// The intersection vertices are the vertices of each polyhedron inside
+    /// the other plus the points where the edges of each cross the faces of
+    /// the other; the result is their convex hull.
+    pub fn intersection(&self, other: &ConvexPolyhedron) -> Option<ConvexPolyhedron> {
+        let (own_min, own_max) = self.bounding_box()?;
+        let (other_min, other_max) = other.bounding_box()?;
+        if own_max.x < other_min.x
+            || other_max.x < own_min.x
+            || own_max.y < other_min.y
+            || other_max.y < own_min.y
+            || own_max.z < other_min.z
+            || other_max.z < own_min.z
+        {
+            return None;
+        }
+
+        let own_planes = self.planes();
+        let other_planes = other.planes();
+        let extent = [own_min, own_max, other_min, other_max]
+            .iter()
+            .flat_map(|p| [p.x.abs(), p.y.abs(), p.z.abs()])
+            .fold(1.0_f64, f64::max);
+        let eps = 1e-9 * extent;
+        let inside = |planes: &[Plane], p: &MunsellCartesian| planes.iter().all(|pl| pl.distance(p) <= eps);
+
+        let mut points: Vec<MunsellCartesian> = Vec::new();
+        points.extend(self.vertices.iter().filter(|v| inside(&other_planes, v)));
+        points.extend(other.vertices.iter().filter(|v| inside(&own_planes, v)));
+
+        // Edges of each polyhedron crossing the faces of the other
+        for (edges_of, planes) in [(self, &other_planes), (other, &own_planes)] {
+            for (a, b) in edges_of.edges() {
+                for plane in planes.iter() {
+                    let da = plane.distance(&a);
+                    let db = plane.distance(&b);
+                    if (da < 0.0) == (db < 0.0) || da == db {
+                        continue;
+                    }
+                    let t = da / (da - db);
+                    let p = MunsellCartesian::new(
+                        a.x + (b.x - a.x) * t,
+                        a.y + (b.y - a.y) * t,
+                        a.z + (b.z - a.z) * t,
+                    );
+                    if inside(planes, &p) {
+                        points.push(p);
+                    }
+                }
+            }
+        }
+
+        let hull = ConvexPolyhedron::convex_hull(&points).ok()?;
+        if hull.volume() <= eps {
+            return None;
+        }
+        Some(hull)
+    }
+
+    /// Volume shared with another polyhedron.
+    pub fn intersection_volume(&self, other: &ConvexPolyhedron) -> f64 {
+        self.intersection(other).map_or(0.0, |p| p.volume())
+    }
+
+    /// Overlap between two polyhedra as intersection over union (Jaccard
+    /// index): 0 for disjoint regions, 1 for identical ones.
+    pub fn overlap_ratio(&self, other: &ConvexPolyhedron) -> f64 {
+        let shared = self.intersection_volume(other);
+        let union = self.volume() + other.volume() - shared;
+        if union <= 0.0 {
+            return 0.0;
+        }
+        (shared / union).clamp(0.0, 1.0)
+    }
+
+    /// Decompose into tetrahedra from the vertex average, yielding each
+    /// tetrahedron's volume and centroid.
+    fn tetrahedra(&self) -> impl Iterator<Item = (f64, MunsellCartesian)> + '_ {
+        let apex = self.centroid();
+        self.faces.iter().map(move |f| {
+            let (a, b, c) = (
+                &self.vertices[f.v0],
+                &self.vertices[f.v1],
+                &self.vertices[f.v2],
+            );
+            let volume = dot(sub(a, &apex), cross(sub(b, &apex), sub(c, &apex))).abs() / 6.0;
+            let center = MunsellCartesian::new(
+                (a.x + b.x + c.x + apex.x) / 4.0,
+                (a.y + b.y + c.y + apex.y) / 4.0,
+                (a.z + b.z + c.z + apex.z) / 4.0,
+            );
+            (volume, center)
+        })
+    }
+
+    /// Face planes oriented away from the vertex average.
+    fn planes(&self) -> Vec<Plane> {
+        let center = self.centroid();
+        self.faces
+            .iter()
+            .filter_map(|f| {
+                let a = &self.vertices[f.v0];
+                let n = cross(sub(&self.vertices[f.v1], a), sub(&self.vertices[f.v2], a));
+                let len = length(n);
+                if len == 0.0 {
+                    return None;
+                }
+                let mut plane = Plane {
+                    normal: (n.0 / len, n.1 / len, n.2 / len),
+                    offset: -(n.0 * a.x + n.1 * a.y + n.2 * a.z) / len,
+                };
+                if plane.distance(&center) > 0.0 {
+                    plane.normal = (-plane.normal.0, -plane.normal.1, -plane.normal.2);
+                    plane.offset = -plane.offset;
+                }
+                Some(plane)
+            })
+            .collect()
+    }
+
+    /// Unique edges as endpoint pairs.
+    fn edges(&self) -> Vec<(MunsellCartesian, MunsellCartesian)> {
+        let mut pairs: Vec<(usize, usize)> = self
+            .faces
+            .iter()
+            .flat_map(|f| [(f.v0, f.v1), (f.v1, f.v2), (f.v2, f.v0)])
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect();
+        pairs.sort_unstable();
+        pairs.dedup();
+        pairs
+            .into_iter()
+            .map(|(a, b)| (self.vertices[a], self.vertices[b]))
+            .collect()
+    }
+}
+
+impl SemanticOverlay {
+    /// Volume of the overlay region.
+    pub fn volume(&self) -> f64 {
+        self.polyhedron.volume()
+    }
+
+    /// Overlap with another overlay as intersection over union.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::get_registry;
+    ///
+    /// let registry = get_registry();
+    /// let teal = registry.get("teal").unwrap();
+    /// let turquoise = registry.get("turquoise").unwrap();
+    ///
+    /// let ratio = teal.overlap_ratio(turquoise);
+    /// assert!((0.0..=1.0).contains(&ratio));
+    /// assert!((teal.overlap_ratio(teal) - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn overlap_ratio(&self, other: &SemanticOverlay) -> f64 {
+        self.polyhedron.overlap_ratio(&other.polyhedron)
+    }
+}
@@ -0,0 +1,187 @@
+//! 3D convex hull construction for semantic overlay polyhedra.
+//!
+//! Centore (2020) defines each overlay as the convex hull of the samples named
+//! with a color term, taken in Munsell Cartesian space. This module computes
+//! that hull with an incremental algorithm: start from a non-degenerate
+//! tetrahedron, then add each point outside the current hull by removing the
+//! faces it can see and fanning new faces from the horizon to the point.
+
+use std::collections::HashSet;
+
+use crate::error::{MunsellError, Result};
+use super::polyhedron::{ConvexPolyhedron, TriFace};
+use super::types::MunsellCartesian;
+use super::vec3::{cross, dot, length, sub};
+
+impl ConvexPolyhedron {
+    /// Compute the convex hull of a set of points.
+    ///
+    /// Faces are oriented counter-clockwise when viewed from outside, and only
+    /// points on the hull are kept as vertices. Points inside the hull or on a
+    /// face (within a tolerance relative to the extent of the data) are dropped.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::ConversionError`] if fewer than four points are
+    /// given, a coordinate is not finite, or all points are coplanar.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::semantic_overlay::{ConvexPolyhedron, MunsellCartesian};
+    ///
+    /// let points = [
+    ///     MunsellCartesian::new(0.0, 0.0, 0.0),
+    ///     MunsellCartesian::new(1.0, 0.0, 0.0),
+    ///     MunsellCartesian::new(0.0, 1.0, 0.0),
+    ///     MunsellCartesian::new(0.0, 0.0, 1.0),
+    ///     MunsellCartesian::new(0.1, 0.1, 0.1), // interior
+    /// ];
+    /// let hull = ConvexPolyhedron::convex_hull(&points).unwrap();
+    /// assert_eq!(hull.vertices.len(), 4);
+    /// assert_eq!(hull.faces.len(), 4);
+    /// ```
+    pub fn convex_hull(points: &[MunsellCartesian]) -> Result<Self> {
+        let degenerate = |reason: &str| MunsellError::ConversionError {
+            message: format!("Cannot build convex hull: {}", reason),
+        };
+
+        if points.len() < 4 {
+            return Err(degenerate("at least 4 points are required"));
+        }
+        if points.iter().any(|p| !(p.x.is_finite() && p.y.is_finite() && p.z.is_finite())) {
+            return Err(degenerate("coordinates must be finite"));
+        }
+
+        let extent = points
+            .iter()
+            .flat_map(|p| [p.x.abs(), p.y.abs(), p.z.abs()])
+            .fold(1.0_f64, f64::max);
+        let eps = 1e-9 * extent;
+
+        let [a, b, c, d] = initial_tetrahedron(points, eps)
+            .ok_or_else(|| degenerate("all points are coplanar"))?;
+
+        let mut faces = vec![[a, b, c], [a, d, b], [b, d, c], [c, d, a]];
+        // Orient the seed faces outward
+        if signed_distance(points, faces[0], &points[d]) > 0.0 {
+            for face in &mut faces {
+                face.swap(1, 2);
+            }
+        }
+
+        for (index, point) in points.iter().enumerate() {
+            if [a, b, c, d].contains(&index) {
+                continue;
+            }
+
+            let visible: Vec<bool> = faces
+                .iter()
+                .map(|&face| signed_distance(points, face, point) > eps)
+                .collect();
+            if !visible.contains(&true) {
+                continue;
+            }
+
+            let visible_edges: HashSet<(usize, usize)> = faces
+                .iter()
+                .zip(&visible)
+                .filter(|(_, &v)| v)
+                .flat_map(|(f, _)| [(f[0], f[1]), (f[1], f[2]), (f[2], f[0])])
+                .collect();
+
+            // A horizon edge borders a visible face whose neighbour is hidden
+            let horizon: Vec<(usize, usize)> = visible_edges
+                .iter()
+                .filter(|&&(u, v)| !visible_edges.contains(&(v, u)))
+                .copied()
+                .collect();
+
+            let mut kept: Vec<[usize; 3]> = faces
+                .iter()
+                .zip(&visible)
+                .filter(|(_, &v)| !v)
+                .map(|(&f, _)| f)
+                .collect();
+            kept.extend(horizon.into_iter().map(|(u, v)| [u, v, index]));
+            faces = kept;
+        }
+
+        // Keep only hull vertices, in first-use order
+        let mut remap = vec![usize::MAX; points.len()];
+        let mut vertices = Vec::new();
+        let faces = faces
+            .into_iter()
+            .map(|face| {
+                let mut indices = [0; 3];
+                for (slot, &i) in indices.iter_mut().zip(&face) {
+                    if remap[i] == usize::MAX {
+                        remap[i] = vertices.len();
+                        vertices.push(points[i]);
+                    }
+                    *slot = remap[i];
+                }
+                TriFace::new(indices[0], indices[1], indices[2])
+            })
+            .collect();
+
+        Ok(Self::new(vertices, faces))
+    }
+}
+
+/// Pick four affinely independent points to seed the hull.
+fn initial_tetrahedron(points: &[MunsellCartesian], eps: f64) -> Option<[usize; 4]> {
+    // Two points far apart along the widest axis
+    let axis_extremes = |key: fn(&MunsellCartesian) -> f64| {
+        let min = (0..points.len()).min_by(|&i, &j| key(&points[i]).total_cmp(&key(&points[j])))?;
+        let max = (0..points.len()).max_by(|&i, &j| key(&points[i]).total_cmp(&key(&points[j])))?;
+        Some((min, max))
+    };
+    let (a, b) = [
+        axis_extremes(|p| p.x)?,
+        axis_extremes(|p| p.y)?,
+        axis_extremes(|p| p.z)?,
+    ]
+    .into_iter()
+    .max_by(|x, y| {
+        points[x.0]
+            .distance(&points[x.1])
+            .total_cmp(&points[y.0].distance(&points[y.1]))
+    })?;
+    if points[a].distance(&points[b]) <= eps {
+        return None;
+    }
+
+    // Farthest point from the line ab
+    let ab = sub(&points[b], &points[a]);
+    let c = (0..points.len()).max_by(|&i, &j| {
+        let di = length(cross(ab, sub(&points[i], &points[a])));
+        let dj = length(cross(ab, sub(&points[j], &points[a])));
+        di.total_cmp(&dj)
+    })?;
+    if length(cross(ab, sub(&points[c], &points[a]))) <= eps * length(ab) {
+        return None;
+    }
+
+    // Farthest point from the plane abc
+    let d = (0..points.len()).max_by(|&i, &j| {
+        signed_distance(points, [a, b, c], &points[i])
+            .abs()
+            .total_cmp(&signed_distance(points, [a, b, c], &points[j]).abs())
+    })?;
+    if signed_distance(points, [a, b, c], &points[d]).abs() <= eps {
+        return None;
+    }
+
+    Some([a, b, c, d])
+}
+
+/// Signed distance of `point` from the plane of a face, positive on the side
+/// its counter-clockwise normal points to.
+fn signed_distance(points: &[MunsellCartesian], face: [usize; 3], point: &MunsellCartesian) -> f64 {
+    let v0 = &points[face[0]];
+    let normal = cross(sub(&points[face[1]], v0), sub(&points[face[2]], v0));
+    let length = length(normal);
+    if length == 0.0 {
+        return 0.0;
+    }
+    dot(normal, sub(point, v0)) / length
+}
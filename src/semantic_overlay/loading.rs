@@ -0,0 +1,361 @@
+//! Loading semantic overlays from JSON and CSV data files.
+//!
+//! The built-in registry holds Centore's 30 polyhedra. Additional vocabularies
+//! (e.g. "sage", "mint", "burgundy") can be supplied at runtime in either format
+//! and merged with, or used instead of, the built-in set.
+//!
+//! # JSON format
+//!
+//! An array of overlay objects. `centroid` is a Munsell notation and may be
+//! omitted, in which case the vertex average is used; `sample_count` defaults
+//! to 0.
+//!
+//! ```json
+//! [
+//!   {
+//!     "name": "sage",
+//!     "vertices": [[-1.0, 1.5, 4.0], [-2.0, 1.5, 4.0], [-1.5, 2.5, 4.0], [-1.5, 2.0, 7.0]],
+//!     "faces": [[0, 2, 1], [0, 1, 3], [1, 2, 3], [2, 0, 3]],
+//!     "centroid": "5.7GY 4.8/2.4",
+//!     "sample_count": 42
+//!   }
+//! ]
+//! ```
+//!
+//! # CSV format
+//!
+//! One row per element with the header `overlay,element,a,b,c`:
+//!
+//! | element    | a          | b     | c      |
+//! |------------|------------|-------|--------|
+//! | `vertex`   | x          | y     | z      |
+//! | `face`     | index 0    | index 1 | index 2 |
+//! | `centroid` | hue number | value | chroma |
+//! | `samples`  | count      |       |        |
+//!
+//! Vertices are numbered in the order they appear for each overlay.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::Read;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{MunsellError, Result};
+use super::parsing::parse_munsell_notation;
+use super::registry::{SemanticOverlay, SemanticOverlayRegistry};
+use super::types::MunsellSpec;
+
+/// Serializable definition of a semantic overlay polyhedron.
+///
+/// Vertices are Munsell Cartesian coordinates `[x, y, z]` as produced by
+/// [`MunsellSpec::to_cartesian`]; faces are triangles of vertex indices.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverlayDefinition {
+    /// Color name (e.g. "sage")
+    pub name: String,
+    /// Polyhedron vertices in Munsell Cartesian space
+    pub vertices: Vec<[f64; 3]>,
+    /// Triangular faces as vertex indices
+    pub faces: Vec<[usize; 3]>,
+    /// Focal color as Munsell notation; defaults to the vertex average
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub centroid: Option<String>,
+    /// Number of samples the region was derived from
+    #[serde(default)]
+    pub sample_count: u32,
+}
+
+impl OverlayDefinition {
+    /// Validate the definition and build a [`SemanticOverlay`].
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::ReferenceDataError`] if the name is empty, the
+    /// polyhedron has fewer than four vertices or faces, a coordinate is not
+    /// finite, a face index is out of range, or the centroid cannot be parsed.
+    pub fn to_overlay(&self) -> Result<SemanticOverlay> {
+        let name = self.name.trim();
+        let invalid = |reason: String| MunsellError::ReferenceDataError {
+            message: format!("Invalid overlay '{}': {}", name, reason),
+        };
+
+        if name.is_empty() {
+            return Err(MunsellError::ReferenceDataError {
+                message: "Overlay name must not be empty".to_string(),
+            });
+        }
+        if self.vertices.len() < 4 || self.faces.len() < 4 {
+            return Err(invalid(format!(
+                "a polyhedron needs at least 4 vertices and 4 faces, got {} and {}",
+                self.vertices.len(),
+                self.faces.len()
+            )));
+        }
+        if self.vertices.iter().flatten().any(|c| !c.is_finite()) {
+            return Err(invalid("vertex coordinates must be finite".to_string()));
+        }
+        if let Some(face) = self
+            .faces
+            .iter()
+            .find(|f| f.iter().any(|&i| i >= self.vertices.len()))
+        {
+            return Err(invalid(format!(
+                "face {:?} references a vertex beyond {}",
+                face,
+                self.vertices.len() - 1
+            )));
+        }
+
+        let vertices: Vec<(f64, f64, f64)> =
+            self.vertices.iter().map(|v| (v[0], v[1], v[2])).collect();
+        let faces: Vec<(usize, usize, usize)> =
+            self.faces.iter().map(|f| (f[0], f[1], f[2])).collect();
+
+        let mut overlay = SemanticOverlay::new(
+            name,
+            &vertices,
+            &faces,
+            MunsellSpec::neutral(0.0),
+            self.sample_count,
+        );
+        overlay.centroid = match &self.centroid {
+            Some(notation) => parse_munsell_notation(notation)
+                .ok_or_else(|| invalid(format!("cannot parse centroid '{}'", notation)))?,
+            None => MunsellSpec::from_cartesian(&overlay.polyhedron.centroid()),
+        };
+
+        Ok(overlay)
+    }
+}
+
+impl From<&SemanticOverlay> for OverlayDefinition {
+    fn from(overlay: &SemanticOverlay) -> Self {
+        Self {
+            name: overlay.name.clone(),
+            vertices: overlay.polyhedron.vertices.iter().map(|v| [v.x, v.y, v.z]).collect(),
+            faces: overlay.polyhedron.faces.iter().map(|f| [f.v0, f.v1, f.v2]).collect(),
+            centroid: Some(overlay.centroid_notation()),
+            sample_count: overlay.sample_count,
+        }
+    }
+}
+
+/// One row of the CSV overlay format.
+#[derive(Debug, Deserialize)]
+struct OverlayCsvRow {
+    overlay: String,
+    element: String,
+    a: f64,
+    b: Option<f64>,
+    c: Option<f64>,
+}
+
+/// Overlay being assembled from CSV rows.
+#[derive(Default)]
+struct CsvOverlay {
+    vertices: Vec<[f64; 3]>,
+    faces: Vec<[usize; 3]>,
+    centroid: Option<MunsellSpec>,
+    sample_count: u32,
+}
+
+impl SemanticOverlayRegistry {
+    /// Build a registry from overlay definitions.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::ReferenceDataError`] if a definition is invalid
+    /// or two definitions share a name (case-insensitive).
+    pub fn from_definitions(definitions: &[OverlayDefinition]) -> Result<Self> {
+        let overlays = definitions
+            .iter()
+            .map(OverlayDefinition::to_overlay)
+            .collect::<Result<Vec<_>>>()?;
+        check_unique_names(&overlays)?;
+        Ok(Self::new(overlays))
+    }
+
+    /// Load overlays from a JSON array of [`OverlayDefinition`]s.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::{create_overlay_registry, SemanticOverlayRegistry};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let json = r#"[{
+    ///     "name": "sage",
+    ///     "vertices": [[-1.0, 1.5, 4.0], [-2.0, 1.5, 4.0], [-1.5, 2.5, 4.0], [-1.5, 2.0, 7.0]],
+    ///     "faces": [[0, 2, 1], [0, 1, 3], [1, 2, 3], [2, 0, 3]]
+    /// }]"#;
+    ///
+    /// let mut registry = create_overlay_registry();
+    /// registry.merge(SemanticOverlayRegistry::from_json_str(json)?);
+    /// assert!(registry.get("sage").is_some());
+    /// assert_eq!(registry.len(), 31);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_json_str(json: &str) -> Result<Self> {
+        let definitions: Vec<OverlayDefinition> =
+            serde_json::from_str(json).map_err(json_error)?;
+        Self::from_definitions(&definitions)
+    }
+
+    /// Load overlays in the JSON format from a reader.
+    pub fn from_json_reader<R: Read>(reader: R) -> Result<Self> {
+        let definitions: Vec<OverlayDefinition> =
+            serde_json::from_reader(reader).map_err(json_error)?;
+        Self::from_definitions(&definitions)
+    }
+
+    /// Load overlays in the CSV format from a reader.
+    ///
+    /// Overlays keep the order in which their names first appear.
+    pub fn from_csv_reader<R: Read>(reader: R) -> Result<Self> {
+        let mut order: Vec<String> = Vec::new();
+        let mut parts: BTreeMap<String, CsvOverlay> = BTreeMap::new();
+
+        let mut csv_reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader);
+        for (line, row) in csv_reader.deserialize::<OverlayCsvRow>().enumerate() {
+            let row = row?;
+            let invalid = |reason: &str| MunsellError::ReferenceDataError {
+                message: format!("Overlay CSV row {}: {}", line + 2, reason),
+            };
+
+            if !parts.contains_key(&row.overlay) {
+                order.push(row.overlay.clone());
+            }
+            let overlay = parts.entry(row.overlay.clone()).or_default();
+
+            match row.element.to_lowercase().as_str() {
+                "vertex" => {
+                    let (b, c) = row.b.zip(row.c).ok_or_else(|| invalid("vertex needs x, y and z"))?;
+                    overlay.vertices.push([row.a, b, c]);
+                }
+                "face" => {
+                    let (b, c) = row.b.zip(row.c).ok_or_else(|| invalid("face needs three indices"))?;
+                    let mut face = [0usize; 3];
+                    for (slot, index) in face.iter_mut().zip([row.a, b, c]) {
+                        if index < 0.0 || index.fract() != 0.0 {
+                            return Err(invalid("face indices must be non-negative integers"));
+                        }
+                        *slot = index as usize;
+                    }
+                    overlay.faces.push(face);
+                }
+                "centroid" => {
+                    let (b, c) = row
+                        .b
+                        .zip(row.c)
+                        .ok_or_else(|| invalid("centroid needs hue number, value and chroma"))?;
+                    overlay.centroid = Some(MunsellSpec::new(row.a, b, c));
+                }
+                "samples" => {
+                    if row.a < 0.0 || row.a.fract() != 0.0 {
+                        return Err(invalid("sample count must be a non-negative integer"));
+                    }
+                    overlay.sample_count = row.a as u32;
+                }
+                other => {
+                    return Err(invalid(&format!("unknown element '{}'", other)));
+                }
+            }
+        }
+
+        let mut overlays = Vec::with_capacity(order.len());
+        for name in order {
+            let part = parts.remove(&name).unwrap_or_default();
+            let definition = OverlayDefinition {
+                name,
+                vertices: part.vertices,
+                faces: part.faces,
+                centroid: None,
+                sample_count: part.sample_count,
+            };
+            let mut overlay = definition.to_overlay()?;
+            if let Some(centroid) = part.centroid {
+                overlay.centroid = centroid;
+            }
+            overlays.push(overlay);
+        }
+
+        check_unique_names(&overlays)?;
+        Ok(Self::new(overlays))
+    }
+
+    /// Load overlays from a `.json` or `.csv` file, chosen by extension.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::IoError`] if the file cannot be read, or
+    /// [`MunsellError::ReferenceDataError`] for an unsupported extension or
+    /// invalid data.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_lowercase);
+        let file = std::fs::File::open(path)?;
+
+        match extension.as_deref() {
+            Some("json") => Self::from_json_reader(std::io::BufReader::new(file)),
+            Some("csv") => Self::from_csv_reader(file),
+            _ => Err(MunsellError::ReferenceDataError {
+                message: format!(
+                    "Unsupported overlay file '{}': expected .json or .csv",
+                    path.display()
+                ),
+            }),
+        }
+    }
+
+    /// Add an overlay, replacing any existing overlay with the same name
+    /// (case-insensitive). Returns the replaced overlay.
+    pub fn insert(&mut self, overlay: SemanticOverlay) -> Option<SemanticOverlay> {
+        match self
+            .overlays
+            .iter()
+            .position(|o| o.name.eq_ignore_ascii_case(&overlay.name))
+        {
+            Some(index) => Some(std::mem::replace(&mut self.overlays[index], overlay)),
+            None => {
+                self.overlays.push(overlay);
+                None
+            }
+        }
+    }
+
+    /// Merge another registry into this one.
+    ///
+    /// Overlays from `other` replace same-named overlays in place; new names
+    /// are appended in their original order.
+    pub fn merge(&mut self, other: SemanticOverlayRegistry) {
+        for overlay in other.overlays {
+            self.insert(overlay);
+        }
+    }
+
+    /// Export the overlays as serializable definitions.
+    pub fn to_definitions(&self) -> Vec<OverlayDefinition> {
+        self.overlays.iter().map(OverlayDefinition::from).collect()
+    }
+}
+
+/// Reject registries where two overlays share a name (case-insensitive).
+fn check_unique_names(overlays: &[SemanticOverlay]) -> Result<()> {
+    let mut seen = HashSet::new();
+    for overlay in overlays {
+        if !seen.insert(overlay.name.to_lowercase()) {
+            return Err(MunsellError::ReferenceDataError {
+                message: format!("Duplicate overlay name '{}'", overlay.name),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Convert a JSON parse error into a reference data error.
+fn json_error(error: serde_json::Error) -> MunsellError {
+    MunsellError::ReferenceDataError {
+        message: format!("Invalid overlay JSON: {}", error),
+    }
+}
@@ -0,0 +1,128 @@
+//! Graded membership and boundary-based ranking for semantic overlays.
+//!
+//! Containment in an overlay polyhedron is binary. The signed distance to the
+//! polyhedron surface (negative inside, positive outside) gives a continuous
+//! measure of how firmly a color belongs to a name, from which a smooth
+//! membership in `[0, 1]` is derived.
+
+use serde::{Deserialize, Serialize};
+
+use super::registry::{SemanticOverlay, SemanticOverlayRegistry};
+use super::types::MunsellSpec;
+
+/// Default width of the membership transition, in Munsell Cartesian units.
+///
+/// With this softness a color one chroma step inside the boundary has a
+/// membership of about 0.88, and one step outside about 0.12.
+pub const DEFAULT_MEMBERSHIP_SOFTNESS: f64 = 0.5;
+
+/// Measure used to order overlays for a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum OverlayRanking {
+    /// Euclidean distance to the overlay centroid (focal color)
+    #[default]
+    CentroidDistance,
+    /// Signed distance to the overlay surface: most deeply inside first
+    BoundaryDistance,
+}
+
+impl SemanticOverlay {
+    /// Signed distance from a color to the overlay surface.
+    ///
+    /// Negative inside, positive outside, in Munsell Cartesian units.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::get_registry;
+    ///
+    /// let aqua = get_registry().get("aqua").unwrap();
+    /// assert!(aqua.signed_distance(&aqua.centroid) < 0.0);
+    /// ```
+    pub fn signed_distance(&self, color: &MunsellSpec) -> f64 {
+        self.polyhedron.signed_distance(&color.to_cartesian())
+    }
+
+    /// Smooth membership of a color in this overlay, between 0 and 1.
+    ///
+    /// A logistic function of the signed distance: 0.5 on the boundary,
+    /// approaching 1 deep inside and 0 far outside. `softness` sets the width
+    /// of the transition in Munsell Cartesian units; a softness of zero or less
+    /// gives the binary containment test.
+    pub fn membership(&self, color: &MunsellSpec, softness: f64) -> f64 {
+        let distance = self.signed_distance(color);
+        if softness <= 0.0 {
+            return if distance <= 0.0 { 1.0 } else { 0.0 };
+        }
+        1.0 / (1.0 + (distance / softness).exp())
+    }
+}
+
+impl SemanticOverlayRegistry {
+    /// Find all overlays that contain the given color, ranked by the chosen measure.
+    ///
+    /// With [`OverlayRanking::CentroidDistance`] this matches
+    /// [`matching_overlays_ranked`](Self::matching_overlays_ranked). With
+    /// [`OverlayRanking::BoundaryDistance`] the score is the signed surface
+    /// distance (negative), and the overlay the color lies most deeply inside
+    /// comes first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::get_registry;
+    /// use munsellspace::semantic_overlay::{centroids, OverlayRanking};
+    ///
+    /// let registry = get_registry();
+    /// let ranked = registry.matching_overlays_ranked_by(&centroids::teal(), OverlayRanking::BoundaryDistance);
+    /// assert!(ranked.iter().all(|(_, distance)| *distance <= 0.0));
+    /// ```
+    pub fn matching_overlays_ranked_by(
+        &self,
+        color: &MunsellSpec,
+        ranking: OverlayRanking,
+    ) -> Vec<(&SemanticOverlay, f64)> {
+        let mut matches: Vec<(&SemanticOverlay, f64)> = self
+            .overlays
+            .iter()
+            .filter(|o| o.contains(color))
+            .map(|o| (o, score(o, color, ranking)))
+            .collect();
+
+        matches.sort_by(|a, b| a.1.total_cmp(&b.1));
+        matches
+    }
+
+    /// Find the closest overlay by the chosen measure, even if the color is outside.
+    ///
+    /// With [`OverlayRanking::BoundaryDistance`] this is the overlay whose
+    /// region, rather than focal color, is nearest.
+    pub fn closest_overlay_by(
+        &self,
+        color: &MunsellSpec,
+        ranking: OverlayRanking,
+    ) -> Option<(&SemanticOverlay, f64)> {
+        self.overlays
+            .iter()
+            .map(|o| (o, score(o, color, ranking)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Membership of a color in every overlay, highest first.
+    pub fn memberships(&self, color: &MunsellSpec, softness: f64) -> Vec<(&SemanticOverlay, f64)> {
+        let mut memberships: Vec<(&SemanticOverlay, f64)> = self
+            .overlays
+            .iter()
+            .map(|o| (o, o.membership(color, softness)))
+            .collect();
+
+        memberships.sort_by(|a, b| b.1.total_cmp(&a.1));
+        memberships
+    }
+}
+
+/// Score of a color against an overlay under a ranking; lower is better.
+fn score(overlay: &SemanticOverlay, color: &MunsellSpec, ranking: OverlayRanking) -> f64 {
+    match ranking {
+        OverlayRanking::CentroidDistance => overlay.distance_to_centroid(color),
+        OverlayRanking::BoundaryDistance => overlay.signed_distance(color),
+    }
+}
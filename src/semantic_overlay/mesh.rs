@@ -0,0 +1,140 @@
+//! Wavefront OBJ and ASCII PLY export of overlay polyhedra.
+//!
+//! Meshes use Munsell Cartesian coordinates (`z` is value) and carry a
+//! per-vertex sRGB color computed with [`ReverseConverter`], so they can be
+//! opened directly in 3D viewers such as Blender or MeshLab. OBJ colors use
+//! the widespread `v x y z r g b` extension with components in `[0, 1]`.
+
+use std::fmt::Write;
+
+use crate::error::Result;
+use crate::reverse_conversion::ReverseConverter;
+use super::registry::{SemanticOverlay, SemanticOverlayRegistry};
+use super::types::MunsellSpec;
+
+impl SemanticOverlay {
+    /// Export the overlay polyhedron as a Wavefront OBJ document.
+    ///
+    /// # Errors
+    /// Returns an error if the reverse converter cannot be created.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::get_registry;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let obj = get_registry().get("teal").unwrap().to_obj()?;
+    /// assert!(obj.contains("o teal"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_obj(&self) -> Result<String> {
+        let reverse = ReverseConverter::new()?;
+        let mut obj = String::new();
+        write_obj_object(&mut obj, self, 0, &reverse);
+        Ok(obj)
+    }
+
+    /// Export the overlay polyhedron as an ASCII PLY document.
+    ///
+    /// # Errors
+    /// Returns an error if the reverse converter cannot be created.
+    pub fn to_ply(&self) -> Result<String> {
+        let reverse = ReverseConverter::new()?;
+        Ok(write_ply(std::slice::from_ref(self), &reverse))
+    }
+}
+
+impl SemanticOverlayRegistry {
+    /// Export every overlay as one named object of a Wavefront OBJ document.
+    ///
+    /// # Errors
+    /// Returns an error if the reverse converter cannot be created.
+    pub fn to_obj(&self) -> Result<String> {
+        let reverse = ReverseConverter::new()?;
+        let mut obj = String::new();
+        let mut offset = 0;
+        for overlay in self.all() {
+            write_obj_object(&mut obj, overlay, offset, &reverse);
+            offset += overlay.polyhedron.vertices.len();
+        }
+        Ok(obj)
+    }
+
+    /// Export every overlay into a single ASCII PLY mesh.
+    ///
+    /// PLY has no object grouping, so overlay names are listed as header
+    /// comments in mesh order.
+    ///
+    /// # Errors
+    /// Returns an error if the reverse converter cannot be created.
+    pub fn to_ply(&self) -> Result<String> {
+        let reverse = ReverseConverter::new()?;
+        Ok(write_ply(self.all(), &reverse))
+    }
+}
+
+/// Append one overlay as an OBJ object whose vertices start after `offset`.
+fn write_obj_object(obj: &mut String, overlay: &SemanticOverlay, offset: usize, reverse: &ReverseConverter) {
+    let _ = writeln!(obj, "o {}", overlay.name.replace(char::is_whitespace, "_"));
+    for vertex in &overlay.polyhedron.vertices {
+        let [r, g, b] = MunsellSpec::from_cartesian(vertex).display_srgb(reverse);
+        let _ = writeln!(
+            obj,
+            "v {:.6} {:.6} {:.6} {:.4} {:.4} {:.4}",
+            vertex.x,
+            vertex.y,
+            vertex.z,
+            r as f64 / 255.0,
+            g as f64 / 255.0,
+            b as f64 / 255.0
+        );
+    }
+    // OBJ indices are 1-based
+    for face in &overlay.polyhedron.faces {
+        let _ = writeln!(
+            obj,
+            "f {} {} {}",
+            face.v0 + offset + 1,
+            face.v1 + offset + 1,
+            face.v2 + offset + 1
+        );
+    }
+}
+
+/// Build an ASCII PLY document holding all overlays.
+fn write_ply(overlays: &[SemanticOverlay], reverse: &ReverseConverter) -> String {
+    let vertex_count: usize = overlays.iter().map(|o| o.polyhedron.vertices.len()).sum();
+    let face_count: usize = overlays.iter().map(|o| o.polyhedron.faces.len()).sum();
+
+    let mut ply = String::new();
+    ply.push_str("ply\nformat ascii 1.0\n");
+    for overlay in overlays {
+        let _ = writeln!(ply, "comment overlay {}", overlay.name);
+    }
+    let _ = writeln!(ply, "element vertex {}", vertex_count);
+    ply.push_str("property float x\nproperty float y\nproperty float z\n");
+    ply.push_str("property uchar red\nproperty uchar green\nproperty uchar blue\n");
+    let _ = writeln!(ply, "element face {}", face_count);
+    ply.push_str("property list uchar int vertex_indices\nend_header\n");
+
+    for vertex in overlays.iter().flat_map(|o| &o.polyhedron.vertices) {
+        let [r, g, b] = MunsellSpec::from_cartesian(vertex).display_srgb(reverse);
+        let _ = writeln!(ply, "{:.6} {:.6} {:.6} {} {} {}", vertex.x, vertex.y, vertex.z, r, g, b);
+    }
+
+    let mut offset = 0;
+    for overlay in overlays {
+        for face in &overlay.polyhedron.faces {
+            let _ = writeln!(
+                ply,
+                "3 {} {} {}",
+                face.v0 + offset,
+                face.v1 + offset,
+                face.v2 + offset
+            );
+        }
+        offset += overlay.polyhedron.vertices.len();
+    }
+    ply
+}
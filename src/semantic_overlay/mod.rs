@@ -28,6 +28,13 @@
 //! - [`matches_overlay`]: Check if a color matches a specific overlay name
 //! - [`closest_overlay`]: Find the nearest overlay by centroid distance
 //!
+//! For graded membership, [`SemanticOverlay::signed_distance`] measures the
+//! distance to an overlay's surface and [`SemanticOverlay::membership`] turns it
+//! into a smooth score; [`OverlayRanking`] selects centroid or boundary ranking.
+//!
+//! Additional overlays can be loaded at runtime from JSON or CSV with
+//! [`SemanticOverlayRegistry::from_file`] and merged into the built-in set.
+//!
 //! # The 30 Color Names
 //!
 //! Centore defined boundaries for 30 color names (20 non-basic + 10 basic):
@@ -40,6 +47,14 @@ mod parsing;
 mod registry;
 pub mod centroids;
 mod polyhedron;
+mod hull;
+mod vec3;
+mod geometry;
+mod builder;
+mod loading;
+mod membership;
+mod mesh;
+mod screen;
 mod deprecated_api;
 
 #[cfg(test)]
@@ -55,6 +70,10 @@ pub use parsing::{parse_hue_to_number, hue_number_to_string, parse_munsell_notat
 
 // Re-export overlay structures
 pub use registry::{SemanticOverlay, SemanticOverlayRegistry};
+pub use loading::OverlayDefinition;
+pub use builder::OverlayBuilder;
+pub use membership::{OverlayRanking, DEFAULT_MEMBERSHIP_SOFTNESS};
+pub use screen::OverlayContext;
 
 // Re-export polyhedron types and functions
 pub use polyhedron::{ConvexPolyhedron, TriFace, point_in_polyhedron, munsell_in_polyhedron};
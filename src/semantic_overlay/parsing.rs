@@ -1,6 +1,7 @@
 //! Munsell hue string parsing and formatting.
 
 use super::types::MunsellSpec;
+use crate::types::notation::GleyNotation;
 use super::HUE_FAMILIES;
 
 /// Parse a Munsell hue string to a numeric hue value (0-40).
@@ -123,6 +124,11 @@ pub fn hue_number_to_string(hue_number: f64) -> (String, &'static str) {
 pub fn parse_munsell_notation(notation: &str) -> Option<MunsellSpec> {
     let notation = notation.trim();
 
+    // Handle soil chart gley notation by its ordinary notation
+    if let Some(gley) = GleyNotation::parse(notation).ok()? {
+        return parse_munsell_notation(&gley.to_munsell_notation());
+    }
+
     // Handle neutral colors
     if notation.starts_with("N ") {
         let value_str = notation.strip_prefix("N ")?.trim_end_matches('/');
@@ -1,6 +1,7 @@
 //! Convex polyhedron point-in-polyhedron algorithm.
 
 use super::types::{MunsellCartesian, MunsellSpec};
+use super::vec3::{cross, dot, length, sub};
 
 /// A triangular face of a polyhedron, defined by vertex indices.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -80,10 +81,7 @@ impl ConvexPolyhedron {
             let v1 = &self.vertices[face.v1];
             let v2 = &self.vertices[face.v2];
 
-            let edge1 = (v1.x - v0.x, v1.y - v0.y, v1.z - v0.z);
-            let edge2 = (v2.x - v0.x, v2.y - v0.y, v2.z - v0.z);
-
-            let normal = cross_product(edge1, edge2);
+            let normal = cross(sub(v1, v0), sub(v2, v0));
             let d = -(normal.0 * v0.x + normal.1 * v0.y + normal.2 * v0.z);
 
             let point_side =
@@ -121,14 +119,140 @@ impl ConvexPolyhedron {
 
         false
     }
+
+    /// Signed Euclidean distance from a point to the polyhedron surface.
+    ///
+    /// Negative inside (the distance to the nearest face), positive outside
+    /// (the distance to the nearest point of the surface), and zero on the
+    /// boundary. Face orientation does not matter. Returns `f64::INFINITY`
+    /// for a polyhedron without faces.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::semantic_overlay::{ConvexPolyhedron, MunsellCartesian};
+    ///
+    /// let cube = ConvexPolyhedron::convex_hull(&[
+    ///     MunsellCartesian::new(0.0, 0.0, 0.0), MunsellCartesian::new(2.0, 0.0, 0.0),
+    ///     MunsellCartesian::new(0.0, 2.0, 0.0), MunsellCartesian::new(2.0, 2.0, 0.0),
+    ///     MunsellCartesian::new(0.0, 0.0, 2.0), MunsellCartesian::new(2.0, 0.0, 2.0),
+    ///     MunsellCartesian::new(0.0, 2.0, 2.0), MunsellCartesian::new(2.0, 2.0, 2.0),
+    /// ]).unwrap();
+    ///
+    /// assert!((cube.signed_distance(&MunsellCartesian::new(1.0, 1.0, 1.0)) + 1.0).abs() < 1e-9);
+    /// assert!((cube.signed_distance(&MunsellCartesian::new(3.0, 1.0, 1.0)) - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn signed_distance(&self, point: &MunsellCartesian) -> f64 {
+        if self.faces.is_empty() || self.vertices.len() < 4 {
+            return f64::INFINITY;
+        }
+
+        if self.contains_point(point) {
+            let centroid = self.centroid();
+            let depth = self
+                .faces
+                .iter()
+                .filter_map(|face| {
+                    let v0 = &self.vertices[face.v0];
+                    let normal = face_normal(&self.vertices, face);
+                    let length = length(normal);
+                    if length == 0.0 {
+                        return None;
+                    }
+                    Some(dot(normal, sub(point, v0)).abs() / length)
+                })
+                .fold(f64::INFINITY, f64::min);
+            // Degenerate faces only: fall back to the centroid distance
+            return if depth.is_finite() { -depth } else { -point.distance(&centroid) };
+        }
+
+        self.faces
+            .iter()
+            .map(|face| {
+                let closest = closest_point_on_triangle(
+                    point,
+                    &self.vertices[face.v0],
+                    &self.vertices[face.v1],
+                    &self.vertices[face.v2],
+                );
+                point.distance(&closest)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// Normal of a face from its vertex winding (not normalized).
+fn face_normal(vertices: &[MunsellCartesian], face: &TriFace) -> (f64, f64, f64) {
+    let v0 = &vertices[face.v0];
+    let v1 = &vertices[face.v1];
+    let v2 = &vertices[face.v2];
+    cross(sub(v1, v0), sub(v2, v0))
 }
 
-/// Calculate cross product of two 3D vectors.
-fn cross_product(a: (f64, f64, f64), b: (f64, f64, f64)) -> (f64, f64, f64) {
-    (
-        a.1 * b.2 - a.2 * b.1,
-        a.2 * b.0 - a.0 * b.2,
-        a.0 * b.1 - a.1 * b.0,
+/// Closest point to `p` on triangle `abc` (Ericson, Real-Time Collision
+/// Detection, section 5.1.5).
+fn closest_point_on_triangle(
+    p: &MunsellCartesian,
+    a: &MunsellCartesian,
+    b: &MunsellCartesian,
+    c: &MunsellCartesian,
+) -> MunsellCartesian {
+    let along = |origin: &MunsellCartesian, dir: (f64, f64, f64), t: f64| {
+        MunsellCartesian::new(origin.x + dir.0 * t, origin.y + dir.1 * t, origin.z + dir.2 * t)
+    };
+
+    let ab = sub(b, a);
+    let ac = sub(c, a);
+    let ap = sub(p, a);
+    let d1 = dot(ab, ap);
+    let d2 = dot(ac, ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return *a;
+    }
+
+    let bp = sub(p, b);
+    let d3 = dot(ab, bp);
+    let d4 = dot(ac, bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return *b;
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return along(a, ab, d1 / (d1 - d3));
+    }
+
+    let cp = sub(p, c);
+    let d5 = dot(ab, cp);
+    let d6 = dot(ac, cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return *c;
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return along(a, ac, d2 / (d2 - d6));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let bc = sub(c, b);
+        return along(b, bc, (d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+
+    let denom = va + vb + vc;
+    if denom == 0.0 {
+        // Degenerate triangle: nearest vertex
+        return *[a, b, c]
+            .into_iter()
+            .min_by(|u, v| p.distance(u).total_cmp(&p.distance(v)))
+            .unwrap_or(a);
+    }
+    let v = vb / denom;
+    let w = vc / denom;
+    MunsellCartesian::new(
+        a.x + ab.0 * v + ac.0 * w,
+        a.y + ab.1 * v + ac.1 * w,
+        a.z + ab.2 * v + ac.2 * w,
     )
 }
 
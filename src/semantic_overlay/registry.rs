@@ -11,7 +11,7 @@ use super::polyhedron::ConvexPolyhedron;
 #[derive(Debug, Clone)]
 pub struct SemanticOverlay {
     /// The color name (e.g., "aqua", "beige", "coral")
-    pub name: &'static str,
+    pub name: String,
     /// The polyhedron defining the color region
     pub polyhedron: ConvexPolyhedron,
     /// Centroid (focal color) from Centore's Table 1
@@ -23,14 +23,14 @@ pub struct SemanticOverlay {
 impl SemanticOverlay {
     /// Create a new semantic overlay.
     pub fn new(
-        name: &'static str,
+        name: impl Into<String>,
         vertices: &[(f64, f64, f64)],
         faces: &[(usize, usize, usize)],
         centroid: MunsellSpec,
         sample_count: u32,
     ) -> Self {
         Self {
-            name,
+            name: name.into(),
             polyhedron: ConvexPolyhedron::from_arrays(vertices, faces),
             centroid,
             sample_count,
@@ -62,11 +62,12 @@ impl SemanticOverlay {
 
 /// Registry of all semantic overlays.
 ///
-/// This struct holds all 30 color name overlays from Centore (2020):
-/// 20 non-basic + 10 basic.
+/// The built-in registry holds all 30 color name overlays from Centore (2020):
+/// 20 non-basic + 10 basic. Custom overlays can be loaded at runtime from
+/// JSON or CSV and merged in (see [`SemanticOverlayRegistry::merge`]).
 #[derive(Debug, Clone)]
 pub struct SemanticOverlayRegistry {
-    overlays: Vec<SemanticOverlay>,
+    pub(super) overlays: Vec<SemanticOverlay>,
 }
 
 impl SemanticOverlayRegistry {
@@ -146,8 +147,8 @@ impl SemanticOverlayRegistry {
     }
 
     /// Get overlay names.
-    pub fn names(&self) -> Vec<&'static str> {
-        self.overlays.iter().map(|o| o.name).collect()
+    pub fn names(&self) -> Vec<&str> {
+        self.overlays.iter().map(|o| o.name.as_str()).collect()
     }
 
     /// Number of overlays in the registry.
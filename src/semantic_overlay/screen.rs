@@ -0,0 +1,78 @@
+//! Screen-referenced variants of semantic overlays.
+//!
+//! Centore's polyhedra were measured on physical fabric samples, while Munsell
+//! values computed from sRGB are screen-referenced and systematically lighter,
+//! more saturated and hue-shifted (see [`crate::screen_correction`]). A screen
+//! variant of an overlay maps its polyhedron through the inverse of the
+//! [`ScreenCorrector`] model, so screen-derived colors can be tested directly
+//! against it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::screen_correction::ScreenCorrector;
+use super::polyhedron::ConvexPolyhedron;
+use super::registry::{SemanticOverlay, SemanticOverlayRegistry};
+use super::types::MunsellSpec;
+
+/// Viewing context of the colors being matched against overlays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum OverlayContext {
+    /// Physical surface colors; overlays are used as measured by Centore
+    #[default]
+    Physical,
+    /// Screen colors such as sRGB or hex input; overlays are mapped to the
+    /// screen-referenced region that corrects to the physical one
+    Screen,
+}
+
+impl SemanticOverlay {
+    /// Screen-referenced variant of this overlay.
+    ///
+    /// Every polyhedron vertex and the centroid are mapped with
+    /// [`ScreenCorrector::invert_spec`]. The mapping is nonlinear, so the
+    /// region is the convex hull of the mapped vertices; the original faces are
+    /// kept if the mapped vertices are degenerate.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::get_registry;
+    /// use munsellspace::screen_correction::ScreenCorrector;
+    ///
+    /// let teal = get_registry().get("teal").unwrap();
+    /// let screen_teal = teal.to_screen(&ScreenCorrector::new());
+    ///
+    /// // Screen colors are lighter and more saturated
+    /// assert!(screen_teal.centroid.value > teal.centroid.value);
+    /// assert!(screen_teal.centroid.chroma > teal.centroid.chroma);
+    /// ```
+    pub fn to_screen(&self, corrector: &ScreenCorrector) -> SemanticOverlay {
+        let vertices: Vec<_> = self
+            .polyhedron
+            .vertices
+            .iter()
+            .map(|v| corrector.invert_spec(&MunsellSpec::from_cartesian(v)).to_cartesian())
+            .collect();
+
+        let polyhedron = ConvexPolyhedron::convex_hull(&vertices).unwrap_or_else(|_| ConvexPolyhedron {
+            vertices,
+            faces: self.polyhedron.faces.clone(),
+        });
+
+        SemanticOverlay {
+            name: self.name.clone(),
+            polyhedron,
+            centroid: corrector.invert_spec(&self.centroid),
+            sample_count: self.sample_count,
+        }
+    }
+}
+
+impl SemanticOverlayRegistry {
+    /// Registry of the screen-referenced variants of every overlay.
+    ///
+    /// See [`SemanticOverlay::to_screen`]. The built-in screen registry is
+    /// available from [`get_screen_registry`](crate::semantic_overlay_data::get_screen_registry).
+    pub fn to_screen(&self, corrector: &ScreenCorrector) -> SemanticOverlayRegistry {
+        SemanticOverlayRegistry::new(self.overlays.iter().map(|o| o.to_screen(corrector)).collect())
+    }
+}
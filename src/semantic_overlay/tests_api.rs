@@ -205,3 +205,292 @@ fn test_matching_overlays_ranked_consistency_with_best_match() {
         }
     }
 }
+
+// ========================================================================
+// Runtime Overlay Loading Tests
+// ========================================================================
+
+const SAGE_JSON: &str = r#"[{
+    "name": "sage",
+    "vertices": [[-1.0, 1.5, 4.0], [-2.0, 1.5, 4.0], [-1.5, 2.5, 4.0], [-1.5, 2.0, 7.0]],
+    "faces": [[0, 2, 1], [0, 1, 3], [1, 2, 3], [2, 0, 3]],
+    "sample_count": 12
+}]"#;
+
+#[test]
+fn test_load_overlays_from_json() {
+    let registry = SemanticOverlayRegistry::from_json_str(SAGE_JSON).unwrap();
+    assert_eq!(registry.names(), vec!["sage"]);
+
+    let sage = registry.get("Sage").unwrap();
+    assert_eq!(sage.sample_count, 12);
+    // Centroid defaults to the vertex average, which lies inside
+    assert!(sage.contains(&sage.centroid));
+    assert!(registry.best_match(&sage.centroid).is_some());
+}
+
+#[test]
+fn test_load_overlays_from_csv_matches_json() {
+    let csv = "overlay,element,a,b,c\n\
+               mint,vertex,-1.0,1.5,4.0\n\
+               mint,vertex,-2.0,1.5,4.0\n\
+               mint,vertex,-1.5,2.5,4.0\n\
+               mint,vertex,-1.5,2.0,7.0\n\
+               mint,face,0,2,1\n\
+               mint,face,0,1,3\n\
+               mint,face,1,2,3\n\
+               mint,face,2,0,3\n\
+               mint,centroid,14.3,4.8,2.4\n\
+               mint,samples,7,,\n";
+    let registry = SemanticOverlayRegistry::from_csv_reader(csv.as_bytes()).unwrap();
+    let mint = registry.get("mint").unwrap();
+    let sage_registry = SemanticOverlayRegistry::from_json_str(SAGE_JSON).unwrap();
+    let sage = sage_registry.get("sage").unwrap();
+
+    assert_eq!(mint.sample_count, 7);
+    assert_eq!(mint.polyhedron.vertices, sage.polyhedron.vertices);
+    assert_eq!(mint.polyhedron.faces, sage.polyhedron.faces);
+    assert!((mint.centroid.hue_number - 14.3).abs() < 1e-9);
+}
+
+#[test]
+fn test_merge_custom_overlays_with_builtin() {
+    let mut registry = crate::semantic_overlay_data::create_overlay_registry();
+    let builtin_len = registry.len();
+
+    registry.merge(SemanticOverlayRegistry::from_json_str(SAGE_JSON).unwrap());
+    assert_eq!(registry.len(), builtin_len + 1);
+    assert_eq!(registry.names().last(), Some(&"sage"));
+
+    // A loaded overlay named like a built-in one replaces it in place
+    let burgundy = SAGE_JSON.replace("sage", "Wine");
+    let wine_index = registry.names().iter().position(|n| *n == "wine").unwrap();
+    registry.merge(SemanticOverlayRegistry::from_json_str(&burgundy).unwrap());
+    assert_eq!(registry.len(), builtin_len + 1);
+    assert_eq!(registry.all()[wine_index].name, "Wine");
+    assert_eq!(registry.get("wine").unwrap().sample_count, 12);
+}
+
+#[test]
+fn test_overlay_definitions_round_trip() {
+    let registry = crate::semantic_overlay_data::create_overlay_registry();
+    let json = serde_json::to_string(&registry.to_definitions()).unwrap();
+    let reloaded = SemanticOverlayRegistry::from_json_str(&json).unwrap();
+
+    assert_eq!(reloaded.names(), registry.names());
+    let aqua = super::centroids::aqua();
+    assert_eq!(
+        reloaded.best_match(&aqua).map(|o| o.name.as_str()),
+        registry.best_match(&aqua).map(|o| o.name.as_str())
+    );
+}
+
+#[test]
+fn test_invalid_overlay_data_is_rejected() {
+    let out_of_range = SAGE_JSON.replace("[2, 0, 3]", "[2, 0, 9]");
+    assert!(SemanticOverlayRegistry::from_json_str(&out_of_range).is_err());
+
+    let unnamed = SAGE_JSON.replace("\"sage\"", "\"  \"");
+    assert!(SemanticOverlayRegistry::from_json_str(&unnamed).is_err());
+
+    let duplicated = format!(
+        "[{0}, {0}]",
+        SAGE_JSON.trim().trim_start_matches('[').trim_end_matches(']')
+    );
+    assert!(SemanticOverlayRegistry::from_json_str(&duplicated).is_err());
+
+    let bad_element = "overlay,element,a,b,c\nsage,edge,0,1,2\n";
+    assert!(SemanticOverlayRegistry::from_csv_reader(bad_element.as_bytes()).is_err());
+
+    assert!(SemanticOverlayRegistry::from_file("overlays.txt").is_err());
+}
+
+// ========================================================================
+// Overlay Builder Tests
+// ========================================================================
+
+#[test]
+fn test_builder_rebuilds_overlay_from_samples() {
+    let aqua = crate::semantic_overlay_data::get_registry().get("aqua").unwrap();
+    let samples: Vec<MunsellSpec> = aqua
+        .polyhedron
+        .vertices
+        .iter()
+        .map(MunsellSpec::from_cartesian)
+        .collect();
+
+    let rebuilt = OverlayBuilder::new("aqua").samples(samples.clone()).build().unwrap();
+    assert_eq!(rebuilt.sample_count as usize, samples.len());
+    assert!(rebuilt.contains(&rebuilt.centroid));
+    assert!(rebuilt.contains(&aqua.centroid));
+    assert!(rebuilt.distance_to_centroid(&aqua.centroid) < 1.0);
+}
+
+#[test]
+fn test_builder_trims_outliers() {
+    let mut samples: Vec<MunsellSpec> = (0..20)
+        .map(|i| {
+            let t = i as f64;
+            MunsellSpec::new(14.0 + (t % 3.0) * 0.5, 4.0 + (t % 5.0) * 0.4, 2.0 + (t % 4.0) * 0.5)
+        })
+        .collect();
+    let outlier = MunsellSpec::new(30.0, 9.0, 12.0);
+    samples.push(outlier);
+
+    let all = OverlayBuilder::new("mint").samples(samples.clone()).build().unwrap();
+    assert!(all.contains(&outlier));
+
+    let trimmed = OverlayBuilder::new("mint")
+        .samples(samples)
+        .trim_outliers(95.0)
+        .build()
+        .unwrap();
+    assert!(!trimmed.contains(&outlier));
+    assert_eq!(trimmed.sample_count, 20);
+}
+
+#[test]
+fn test_builder_from_srgb_samples() {
+    let overlay = OverlayBuilder::new("burgundy")
+        .srgb_samples(&[
+            [128, 0, 32],
+            [110, 10, 40],
+            [140, 20, 50],
+            [100, 30, 45],
+            [120, 15, 25],
+            [150, 40, 60],
+        ])
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(overlay.name, "burgundy");
+    assert_eq!(overlay.sample_count, 6);
+    assert!(overlay.contains(&overlay.centroid));
+}
+
+#[test]
+fn test_builder_rejects_invalid_input() {
+    let samples = [
+        MunsellSpec::new(2.0, 4.0, 6.0),
+        MunsellSpec::new(3.0, 5.0, 7.0),
+    ];
+    assert!(OverlayBuilder::new("sparse").samples(samples).build().is_err());
+    assert!(OverlayBuilder::new("").sample(samples[0]).build().is_err());
+
+    let cloud: Vec<MunsellSpec> = (0..8)
+        .map(|i| MunsellSpec::new(i as f64, 3.0 + (i % 2) as f64, 4.0 + (i % 3) as f64))
+        .collect();
+    assert!(OverlayBuilder::new("cloud").samples(cloud).trim_outliers(0.0).build().is_err());
+}
+
+// ========================================================================
+// Graded Membership Tests
+// ========================================================================
+
+#[test]
+fn test_membership_is_graded_around_boundary() {
+    let registry = crate::semantic_overlay_data::get_registry();
+    let teal = registry.get("teal").unwrap();
+
+    let inside = teal.membership(&teal.centroid, DEFAULT_MEMBERSHIP_SOFTNESS);
+    assert!(inside > 0.5 && inside < 1.0);
+
+    let far = MunsellSpec::new(2.0, 4.0, 14.0);
+    let outside = teal.membership(&far, DEFAULT_MEMBERSHIP_SOFTNESS);
+    assert!(outside < 0.01);
+
+    // Zero softness is the binary containment test
+    assert_eq!(teal.membership(&teal.centroid, 0.0), 1.0);
+    assert_eq!(teal.membership(&far, 0.0), 0.0);
+
+    let memberships = registry.memberships(&teal.centroid, DEFAULT_MEMBERSHIP_SOFTNESS);
+    assert_eq!(memberships.len(), registry.len());
+    assert!(memberships.windows(2).all(|w| w[0].1 >= w[1].1));
+}
+
+#[test]
+fn test_ranking_by_boundary_distance() {
+    let registry = crate::semantic_overlay_data::get_registry();
+
+    for name in &super::OVERLAY_NAMES {
+        let centroid = super::centroids::get(name).unwrap();
+
+        let by_centroid = registry.matching_overlays_ranked_by(&centroid, OverlayRanking::CentroidDistance);
+        let legacy = registry.matching_overlays_ranked(&centroid);
+        assert_eq!(
+            by_centroid.iter().map(|(o, _)| o.name.as_str()).collect::<Vec<_>>(),
+            legacy.iter().map(|(o, _)| o.name.as_str()).collect::<Vec<_>>()
+        );
+
+        let by_boundary = registry.matching_overlays_ranked_by(&centroid, OverlayRanking::BoundaryDistance);
+        assert_eq!(by_boundary.len(), by_centroid.len());
+        assert!(by_boundary.iter().all(|(_, d)| *d <= 1e-9));
+        assert!(by_boundary.windows(2).all(|w| w[0].1 <= w[1].1));
+    }
+
+    // Outside every region, the nearest region is found by its surface
+    let color = MunsellSpec::new(2.0, 9.5, 1.0);
+    let (_, distance) = registry
+        .closest_overlay_by(&color, OverlayRanking::BoundaryDistance)
+        .unwrap();
+    let closest_region = registry
+        .all()
+        .iter()
+        .map(|o| o.signed_distance(&color))
+        .fold(f64::INFINITY, f64::min);
+    assert_eq!(distance, closest_region);
+}
+
+#[test]
+fn test_mesh_export() {
+    let registry = crate::semantic_overlay_data::get_registry();
+    let teal = registry.get("teal").unwrap();
+    let (vertices, faces) = (teal.polyhedron.vertices.len(), teal.polyhedron.faces.len());
+
+    let obj = teal.to_obj().unwrap();
+    assert_eq!(obj.lines().next(), Some("o teal"));
+    assert_eq!(obj.lines().filter(|l| l.starts_with("v ")).count(), vertices);
+    assert_eq!(obj.lines().filter(|l| l.starts_with("f ")).count(), faces);
+    assert!(obj
+        .lines()
+        .filter(|l| l.starts_with("v "))
+        .all(|l| l.split_whitespace().count() == 7));
+
+    let ply = teal.to_ply().unwrap();
+    assert!(ply.starts_with("ply\nformat ascii 1.0\n"));
+    assert!(ply.contains(&format!("element vertex {}\n", vertices)));
+    let body: Vec<&str> = ply.split("end_header\n").nth(1).unwrap().lines().collect();
+    assert_eq!(body.len(), vertices + faces);
+
+    // Registry export offsets face indices past earlier overlays
+    let all = registry.to_obj().unwrap();
+    assert_eq!(all.lines().filter(|l| l.starts_with("o ")).count(), registry.len());
+    let total: usize = registry.all().iter().map(|o| o.polyhedron.vertices.len()).sum();
+    let max_index = all
+        .lines()
+        .filter(|l| l.starts_with("f "))
+        .flat_map(|l| l[2..].split_whitespace().map(|i| i.parse::<usize>().unwrap()))
+        .max()
+        .unwrap();
+    assert_eq!(max_index, total);
+}
+
+#[test]
+fn test_screen_overlay_variants() {
+    use crate::screen_correction::ScreenCorrector;
+
+    let physical = crate::semantic_overlay_data::get_registry();
+    let screen = crate::semantic_overlay_data::get_screen_registry();
+    assert_eq!(screen.names(), physical.names());
+
+    let corrector = ScreenCorrector::new();
+    for (p, s) in physical.all().iter().zip(screen.all()) {
+        assert!(s.polyhedron.volume() > 0.0, "{}", s.name);
+        assert!(s.contains(&s.centroid), "{}", s.name);
+        // Mapped centroids correct back to the physical ones
+        let centroid = corrector.invert_spec(&p.centroid);
+        assert!(centroid.distance_from(&s.centroid) < 1e-9);
+        assert!(s.centroid.value >= p.centroid.value);
+    }
+}
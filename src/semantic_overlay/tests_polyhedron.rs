@@ -327,3 +327,170 @@ fn test_centore_centroids() {
     let navy = parse_munsell_notation("7.3PB 2.1/3.6").unwrap();
     assert!((navy.value - 2.1).abs() < 0.001);
 }
+
+// ========================================================================
+// Convex Hull Tests
+// ========================================================================
+
+#[test]
+fn test_convex_hull_of_cube_with_interior_points() {
+    let mut points: Vec<MunsellCartesian> = unit_cube().vertices;
+    points.push(MunsellCartesian::new(0.0, 0.0, 0.0));
+    points.push(MunsellCartesian::new(0.2, -0.1, 0.3));
+    // Point on a face is not a hull vertex
+    points.push(MunsellCartesian::new(0.5, 0.0, 0.0));
+
+    let hull = ConvexPolyhedron::convex_hull(&points).unwrap();
+    assert_eq!(hull.vertices.len(), 8);
+    assert_eq!(hull.faces.len(), 12);
+
+    for point in &points {
+        assert!(hull.contains_point(point), "{:?} should be inside", point);
+    }
+    assert!(!hull.contains_point(&MunsellCartesian::new(0.6, 0.0, 0.0)));
+}
+
+#[test]
+fn test_convex_hull_faces_point_outward() {
+    let hull = ConvexPolyhedron::convex_hull(&unit_cube().vertices).unwrap();
+    let center = hull.centroid();
+
+    for face in &hull.faces {
+        let v0 = hull.vertices[face.v0];
+        let v1 = hull.vertices[face.v1];
+        let v2 = hull.vertices[face.v2];
+        let e1 = (v1.x - v0.x, v1.y - v0.y, v1.z - v0.z);
+        let e2 = (v2.x - v0.x, v2.y - v0.y, v2.z - v0.z);
+        let normal = (
+            e1.1 * e2.2 - e1.2 * e2.1,
+            e1.2 * e2.0 - e1.0 * e2.2,
+            e1.0 * e2.1 - e1.1 * e2.0,
+        );
+        let to_center = (center.x - v0.x, center.y - v0.y, center.z - v0.z);
+        let side = normal.0 * to_center.0 + normal.1 * to_center.1 + normal.2 * to_center.2;
+        assert!(side < 0.0, "Face {:?} should be counter-clockwise from outside", face);
+    }
+}
+
+#[test]
+fn test_convex_hull_rejects_degenerate_input() {
+    let coplanar = [
+        MunsellCartesian::new(0.0, 0.0, 1.0),
+        MunsellCartesian::new(1.0, 0.0, 1.0),
+        MunsellCartesian::new(0.0, 1.0, 1.0),
+        MunsellCartesian::new(1.0, 1.0, 1.0),
+    ];
+    assert!(ConvexPolyhedron::convex_hull(&coplanar).is_err());
+    assert!(ConvexPolyhedron::convex_hull(&coplanar[..3]).is_err());
+}
+
+#[test]
+fn test_convex_hull_reproduces_centore_region() {
+    let registry = crate::semantic_overlay_data::get_registry();
+
+    for overlay in registry.all() {
+        let hull = ConvexPolyhedron::convex_hull(&overlay.polyhedron.vertices).unwrap();
+        for vertex in &overlay.polyhedron.vertices {
+            assert!(hull.contains_point(vertex), "{} vertex should be on its hull", overlay.name);
+        }
+        assert!(hull.contains_point(&overlay.centroid.to_cartesian()));
+        assert!(hull.vertices.len() <= overlay.polyhedron.vertices.len());
+    }
+}
+
+// ========================================================================
+// Signed Distance Tests
+// ========================================================================
+
+#[test]
+fn test_signed_distance_unit_cube() {
+    let cube = unit_cube();
+
+    let center = cube.signed_distance(&MunsellCartesian::new(0.0, 0.0, 0.0));
+    assert!((center + 0.5).abs() < 1e-9);
+
+    let near_face = cube.signed_distance(&MunsellCartesian::new(0.4, 0.0, 0.0));
+    assert!((near_face + 0.1).abs() < 1e-9);
+
+    // Outside a face, an edge and a corner
+    let face = cube.signed_distance(&MunsellCartesian::new(1.5, 0.0, 0.0));
+    assert!((face - 1.0).abs() < 1e-9);
+    let edge = cube.signed_distance(&MunsellCartesian::new(1.5, 1.5, 0.0));
+    assert!((edge - 2.0_f64.sqrt()).abs() < 1e-9);
+    let corner = cube.signed_distance(&MunsellCartesian::new(1.5, 1.5, 1.5));
+    assert!((corner - 3.0_f64.sqrt()).abs() < 1e-9);
+}
+
+#[test]
+fn test_signed_distance_sign_matches_containment() {
+    let registry = crate::semantic_overlay_data::get_registry();
+    let probes = [
+        MunsellSpec::new(2.0, 4.0, 10.0),
+        MunsellSpec::new(22.0, 6.0, 4.0),
+        MunsellSpec::new(35.0, 3.0, 8.0),
+        MunsellSpec::neutral(5.0),
+    ];
+
+    for overlay in registry.all() {
+        for probe in probes.iter().chain(std::iter::once(&overlay.centroid)) {
+            let distance = overlay.signed_distance(probe);
+            assert_eq!(
+                overlay.contains(probe),
+                distance <= 1e-9,
+                "{} at {:?}: distance {}",
+                overlay.name,
+                probe,
+                distance
+            );
+        }
+    }
+}
+
+// ========================================================================
+// Geometry Tests
+// ========================================================================
+
+/// Unit cube translated along x.
+fn shifted_cube(dx: f64) -> ConvexPolyhedron {
+    let cube = unit_cube();
+    let vertices: Vec<(f64, f64, f64)> = cube.vertices.iter().map(|v| (v.x + dx, v.y, v.z)).collect();
+    let faces: Vec<(usize, usize, usize)> = cube.faces.iter().map(|f| (f.v0, f.v1, f.v2)).collect();
+    ConvexPolyhedron::from_arrays(&vertices, &faces)
+}
+
+#[test]
+fn test_cube_measurements() {
+    let cube = unit_cube();
+    assert!((cube.volume() - 1.0).abs() < 1e-9);
+    assert!((cube.surface_area() - 6.0).abs() < 1e-9);
+
+    let (min, max) = cube.bounding_box().unwrap();
+    assert_eq!((min.x, min.y, min.z), (-0.5, -0.5, -0.5));
+    assert_eq!((max.x, max.y, max.z), (0.5, 0.5, 0.5));
+
+    let center = cube.center_of_mass();
+    assert!(center.x.abs() < 1e-9 && center.y.abs() < 1e-9 && center.z.abs() < 1e-9);
+
+    // Regular tetrahedron with unit edges (coordinates rounded to 3 places)
+    let tetra = tetrahedron();
+    assert!((tetra.volume() - 2.0_f64.sqrt() / 12.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_intersection_and_overlap_ratio() {
+    let cube = unit_cube();
+    assert!((cube.intersection_volume(&cube) - 1.0).abs() < 1e-9);
+    assert!((cube.overlap_ratio(&cube) - 1.0).abs() < 1e-9);
+
+    // Half overlap: intersection 0.5, union 1.5
+    let half = shifted_cube(0.5);
+    assert!((cube.intersection_volume(&half) - 0.5).abs() < 1e-9);
+    assert!((cube.overlap_ratio(&half) - 1.0 / 3.0).abs() < 1e-9);
+
+    let disjoint = shifted_cube(2.0);
+    assert!(cube.intersection(&disjoint).is_none());
+    assert_eq!(cube.overlap_ratio(&disjoint), 0.0);
+
+    // Touching faces share no volume
+    assert_eq!(cube.intersection_volume(&shifted_cube(1.0)), 0.0);
+}
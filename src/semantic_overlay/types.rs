@@ -1,7 +1,12 @@
 //! Core types for Munsell Cartesian and specification representations.
 
 use std::f64::consts::PI;
+
+use crate::mathematical::MunsellSpecification;
+use crate::reverse_conversion::ReverseConverter;
+use crate::types::MunsellColor;
 use super::parsing::hue_number_to_string;
+use super::HUE_FAMILIES;
 
 /// Represents a point in 3D Munsell Cartesian space.
 ///
@@ -131,4 +136,85 @@ impl MunsellSpec {
     pub fn distance_from(&self, other: &MunsellSpec) -> f64 {
         self.to_cartesian().distance(&other.to_cartesian())
     }
+
+    /// Specification in the family-relative form used by the converters.
+    pub(crate) fn to_specification(self) -> MunsellSpecification {
+        let value = self.value.clamp(0.0, 10.0);
+        if self.chroma > 0.0 {
+            let (hue, family) = hue_within_family(self.hue_number);
+            MunsellSpecification {
+                hue,
+                family: family.to_string(),
+                value,
+                chroma: self.chroma,
+            }
+        } else {
+            MunsellSpecification {
+                hue: 0.0,
+                family: "N".to_string(),
+                value,
+                chroma: 0.0,
+            }
+        }
+    }
+
+    /// sRGB color, or `None` outside the renotation data.
+    pub(crate) fn to_srgb(self, reverse: &ReverseConverter) -> Option<[u8; 3]> {
+        reverse.munsell_to_srgb(&self.to_specification()).ok()
+    }
+
+    /// Round to the precision of Munsell notation: 0.1 hue step, value and
+    /// chroma.
+    pub(crate) fn rounded(self) -> MunsellSpec {
+        // 2.5 hue steps per hue number
+        let steps = (self.hue_number * 25.0).round() / 10.0;
+        MunsellSpec::new(
+            (steps / 2.5).rem_euclid(40.0),
+            (self.value * 10.0).round() / 10.0,
+            (self.chroma * 10.0).round() / 10.0,
+        )
+    }
+
+    /// Convert to a [`MunsellColor`]; zero chroma is neutral.
+    pub(crate) fn to_munsell_color(self) -> MunsellColor {
+        if self.chroma <= 0.0 {
+            MunsellColor::new_neutral(self.value)
+        } else {
+            let (hue, _) = hue_number_to_string(self.hue_number);
+            MunsellColor::new_chromatic(hue, self.value, self.chroma)
+        }
+    }
+
+    /// sRGB color for display.
+    ///
+    /// Colors outside the renotation data are moved toward neutral in steps
+    /// of 2 chroma until a color is found; a value-proportional gray is the
+    /// last resort.
+    pub(crate) fn display_srgb(&self, reverse: &ReverseConverter) -> [u8; 3] {
+        let mut spec = *self;
+        spec.chroma = spec.chroma.max(0.0);
+        loop {
+            if let Some(rgb) = spec.to_srgb(reverse) {
+                return rgb;
+            }
+            if spec.chroma <= 0.0 {
+                let level = (spec.value.clamp(0.0, 10.0) / 10.0 * 255.0).round() as u8;
+                return [level; 3];
+            }
+            spec.chroma = (spec.chroma - 2.0).max(0.0);
+        }
+    }
+}
+
+/// Split a 0-40 hue number into a 0-10 hue within its family.
+fn hue_within_family(hue_number: f64) -> (f64, &'static str) {
+    let normalized = hue_number.rem_euclid(40.0);
+    let index = (normalized / 4.0).floor() as usize % 10;
+    let within = (normalized - index as f64 * 4.0) * 2.5;
+    if within < 1e-9 {
+        // 0X is 10 of the previous family
+        (10.0, HUE_FAMILIES[(index + 9) % 10])
+    } else {
+        (within, HUE_FAMILIES[index])
+    }
 }
@@ -0,0 +1,27 @@
+//! 3D vector helpers shared by the polyhedron, hull and geometry code.
+
+use super::types::MunsellCartesian;
+
+/// Difference `a - b` of two points as a vector.
+pub(super) fn sub(a: &MunsellCartesian, b: &MunsellCartesian) -> (f64, f64, f64) {
+    (a.x - b.x, a.y - b.y, a.z - b.z)
+}
+
+/// Cross product of two 3D vectors.
+pub(super) fn cross(a: (f64, f64, f64), b: (f64, f64, f64)) -> (f64, f64, f64) {
+    (
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    )
+}
+
+/// Dot product of two 3D vectors.
+pub(super) fn dot(a: (f64, f64, f64), b: (f64, f64, f64)) -> f64 {
+    a.0 * b.0 + a.1 * b.1 + a.2 * b.2
+}
+
+/// Euclidean length of a 3D vector.
+pub(super) fn length(a: (f64, f64, f64)) -> f64 {
+    dot(a, a).sqrt()
+}
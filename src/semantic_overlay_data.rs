@@ -8,6 +8,7 @@
 //! (Journal of the International Colour Association, 25, 24-54).
 
 use crate::constants::centore_polyhedra::{get_polyhedron_data, get_sample_count};
+use crate::screen_correction::ScreenCorrector;
 use crate::semantic_overlay::{
     SemanticOverlay, SemanticOverlayRegistry, MunsellSpec, centroids,
 };
@@ -78,6 +79,22 @@ pub fn get_registry() -> &'static SemanticOverlayRegistry {
     REGISTRY.get_or_init(create_overlay_registry)
 }
 
+/// Create the registry of screen-referenced variants of the 30 overlays.
+///
+/// Each Centore polyhedron is mapped through the inverse of the default
+/// [`ScreenCorrector`], so colors converted from sRGB can be matched without
+/// first correcting them to physical references.
+pub fn create_screen_overlay_registry() -> SemanticOverlayRegistry {
+    get_registry().to_screen(&ScreenCorrector::new())
+}
+
+/// Get a lazily-initialized global registry of screen-referenced overlays.
+pub fn get_screen_registry() -> &'static SemanticOverlayRegistry {
+    use std::sync::OnceLock;
+    static SCREEN_REGISTRY: OnceLock<SemanticOverlayRegistry> = OnceLock::new();
+    SCREEN_REGISTRY.get_or_init(create_screen_overlay_registry)
+}
+
 #[cfg(test)]
 #[allow(deprecated)] // Tests verify deprecated functions still work
 mod tests {
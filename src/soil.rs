@@ -0,0 +1,537 @@
+//! Munsell Soil Color Charts.
+//!
+//! Soil colors are recorded against the Munsell Soil Color Charts rather
+//! than the full Munsell system. The charts have one page per hue (10R to
+//! 5Y) plus two gley pages for gray, greenish and bluish soils. Each page has
+//! a small value/chroma grid, and chips carry soil color names, such as
+//! "dark yellowish brown" or "reddish gray", that differ from ISCC-NBS.
+//!
+//! Chips on the hue pages are written as ordinary notation (`10YR 3/4`).
+//! Gley chips are written with the page, the value and the hue, the chroma
+//! being 1 for every chromatic gley chip (`GLEY1 5/10Y`, `GLEY2 4/5PB`,
+//! `GLEY1 6/N`).
+//!
+//! [`SoilChart`] snaps any color to the nearest chip by Munsell Cartesian
+//! distance.
+//!
+//! # Example
+//!
+//! ```rust
+//! use munsellspace::soil::{SoilChip, SoilPage};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let chip = SoilChip::from_notation("10YR 3/4")?;
+//! assert_eq!(chip.name, "dark yellowish brown");
+//! assert_eq!(chip.page, SoilPage::Yr10);
+//!
+//! let gley = SoilChip::from_notation("GLEY1 5/10Y")?;
+//! assert_eq!(gley.name, "greenish gray");
+//! assert_eq!(gley.to_string(), "GLEY1 5/10Y");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::converter::MunsellConverter;
+use crate::error::{MunsellError, Result};
+use crate::semantic_overlay::{parse_hue_to_number, MunsellSpec};
+use crate::types::notation::{GleyNotation, GLEY_CHROMA};
+use crate::types::MunsellColor;
+
+/// Name regions of the hue pages: page, values, chromas and soil name.
+///
+/// Every value and chroma combination of a region is a chip.
+const HUE_PAGE_NAMES: &[(SoilPage, &[f64], &[f64], &str)] = &[
+    (SoilPage::R10, &[8.0], &[1.0], "white"),
+    (SoilPage::R10, &[8.0], &[2.0], "pinkish white"),
+    (SoilPage::R10, &[8.0], &[3.0, 4.0], "pink"),
+    (SoilPage::R10, &[7.0], &[1.0, 2.0], "light reddish gray"),
+    (SoilPage::R10, &[7.0], &[3.0, 4.0], "pale red"),
+    (SoilPage::R10, &[7.0, 6.0], &[6.0, 8.0], "light red"),
+    (SoilPage::R10, &[6.0, 5.0], &[1.0], "reddish gray"),
+    (SoilPage::R10, &[6.0], &[2.0, 3.0, 4.0], "pale red"),
+    (SoilPage::R10, &[5.0, 4.0], &[2.0, 3.0, 4.0], "weak red"),
+    (SoilPage::R10, &[5.0, 4.0], &[6.0, 8.0], "red"),
+    (SoilPage::R10, &[4.0, 3.0], &[1.0], "dark reddish gray"),
+    (SoilPage::R10, &[3.0], &[2.0, 3.0, 4.0], "dusky red"),
+    (SoilPage::R10, &[3.0], &[6.0], "dark red"),
+    (SoilPage::R10, &[2.5], &[1.0], "reddish black"),
+    (SoilPage::R10, &[2.5], &[2.0], "very dusky red"),
+    (SoilPage::Yr2_5, &[8.0], &[2.0], "pinkish white"),
+    (SoilPage::Yr2_5, &[8.0, 7.0], &[3.0, 4.0], "pink"),
+    (SoilPage::Yr2_5, &[7.0], &[1.0, 2.0], "light reddish gray"),
+    (SoilPage::Yr2_5, &[7.0, 6.0], &[6.0, 8.0], "light red"),
+    (SoilPage::Yr2_5, &[6.0, 5.0], &[1.0], "reddish gray"),
+    (SoilPage::Yr2_5, &[6.0], &[2.0], "pale red"),
+    (SoilPage::Yr2_5, &[6.0], &[3.0, 4.0], "light reddish brown"),
+    (SoilPage::Yr2_5, &[5.0, 4.0], &[2.0], "weak red"),
+    (SoilPage::Yr2_5, &[5.0, 4.0], &[3.0, 4.0], "reddish brown"),
+    (SoilPage::Yr2_5, &[5.0, 4.0], &[6.0, 8.0], "red"),
+    (SoilPage::Yr2_5, &[4.0, 3.0], &[1.0], "dark reddish gray"),
+    (SoilPage::Yr2_5, &[3.0], &[2.0], "dusky red"),
+    (SoilPage::Yr2_5, &[3.0, 2.5], &[3.0, 4.0], "dark reddish brown"),
+    (SoilPage::Yr2_5, &[3.0], &[6.0], "dark red"),
+    (SoilPage::Yr2_5, &[2.5], &[1.0], "reddish black"),
+    (SoilPage::Yr2_5, &[2.5], &[2.0], "very dusky red"),
+    (SoilPage::Yr5, &[8.0], &[1.0], "white"),
+    (SoilPage::Yr5, &[8.0], &[2.0], "pinkish white"),
+    (SoilPage::Yr5, &[8.0, 7.0], &[3.0, 4.0], "pink"),
+    (SoilPage::Yr5, &[7.0], &[1.0], "light gray"),
+    (SoilPage::Yr5, &[7.0, 6.0], &[2.0], "pinkish gray"),
+    (SoilPage::Yr5, &[7.0, 6.0], &[6.0, 8.0], "reddish yellow"),
+    (SoilPage::Yr5, &[6.0, 5.0], &[1.0], "gray"),
+    (SoilPage::Yr5, &[6.0], &[3.0, 4.0], "light reddish brown"),
+    (SoilPage::Yr5, &[5.0], &[2.0], "reddish gray"),
+    (SoilPage::Yr5, &[5.0, 4.0], &[3.0, 4.0], "reddish brown"),
+    (SoilPage::Yr5, &[5.0], &[6.0, 8.0], "yellowish red"),
+    (SoilPage::Yr5, &[4.0], &[1.0], "dark gray"),
+    (SoilPage::Yr5, &[4.0], &[2.0], "dark reddish gray"),
+    (SoilPage::Yr5, &[4.0], &[6.0], "yellowish red"),
+    (SoilPage::Yr5, &[3.0], &[1.0], "very dark gray"),
+    (SoilPage::Yr5, &[3.0], &[2.0, 3.0, 4.0], "dark reddish brown"),
+    (SoilPage::Yr5, &[2.5], &[1.0], "black"),
+    (SoilPage::Yr5, &[2.5], &[2.0], "dark reddish brown"),
+    (SoilPage::Yr7_5, &[8.0], &[1.0], "white"),
+    (SoilPage::Yr7_5, &[8.0], &[2.0], "pinkish white"),
+    (SoilPage::Yr7_5, &[8.0, 7.0], &[3.0, 4.0], "pink"),
+    (SoilPage::Yr7_5, &[8.0], &[6.0], "reddish yellow"),
+    (SoilPage::Yr7_5, &[7.0], &[1.0], "light gray"),
+    (SoilPage::Yr7_5, &[7.0, 6.0], &[2.0], "pinkish gray"),
+    (SoilPage::Yr7_5, &[7.0, 6.0], &[6.0, 8.0], "reddish yellow"),
+    (SoilPage::Yr7_5, &[6.0, 5.0], &[1.0], "gray"),
+    (SoilPage::Yr7_5, &[6.0], &[3.0, 4.0], "light brown"),
+    (SoilPage::Yr7_5, &[5.0, 4.0], &[2.0, 3.0, 4.0], "brown"),
+    (SoilPage::Yr7_5, &[5.0], &[6.0, 8.0], "strong brown"),
+    (SoilPage::Yr7_5, &[4.0], &[1.0], "dark gray"),
+    (SoilPage::Yr7_5, &[4.0], &[6.0], "strong brown"),
+    (SoilPage::Yr7_5, &[3.0], &[1.0], "very dark gray"),
+    (SoilPage::Yr7_5, &[3.0], &[2.0, 3.0, 4.0], "dark brown"),
+    (SoilPage::Yr7_5, &[2.5], &[1.0], "black"),
+    (SoilPage::Yr7_5, &[2.5], &[2.0, 3.0], "very dark brown"),
+    (SoilPage::Yr10, &[8.0], &[1.0], "white"),
+    (SoilPage::Yr10, &[8.0], &[2.0, 3.0, 4.0], "very pale brown"),
+    (SoilPage::Yr10, &[8.0, 7.0], &[6.0, 8.0], "yellow"),
+    (SoilPage::Yr10, &[7.0], &[1.0, 2.0], "light gray"),
+    (SoilPage::Yr10, &[7.0], &[3.0, 4.0], "very pale brown"),
+    (SoilPage::Yr10, &[6.0, 5.0], &[1.0], "gray"),
+    (SoilPage::Yr10, &[6.0], &[2.0], "light brownish gray"),
+    (SoilPage::Yr10, &[6.0], &[3.0], "pale brown"),
+    (SoilPage::Yr10, &[6.0], &[4.0], "light yellowish brown"),
+    (SoilPage::Yr10, &[6.0], &[6.0, 8.0], "brownish yellow"),
+    (SoilPage::Yr10, &[5.0], &[2.0], "grayish brown"),
+    (SoilPage::Yr10, &[5.0, 4.0], &[3.0], "brown"),
+    (SoilPage::Yr10, &[5.0], &[4.0, 6.0, 8.0], "yellowish brown"),
+    (SoilPage::Yr10, &[4.0], &[1.0], "dark gray"),
+    (SoilPage::Yr10, &[4.0], &[2.0], "dark grayish brown"),
+    (SoilPage::Yr10, &[4.0, 3.0], &[4.0, 6.0], "dark yellowish brown"),
+    (SoilPage::Yr10, &[3.0], &[1.0], "very dark gray"),
+    (SoilPage::Yr10, &[3.0], &[2.0], "very dark grayish brown"),
+    (SoilPage::Yr10, &[3.0], &[3.0], "dark brown"),
+    (SoilPage::Yr10, &[2.0], &[1.0], "black"),
+    (SoilPage::Yr10, &[2.0], &[2.0], "very dark brown"),
+    (SoilPage::Y2_5, &[8.0], &[1.0], "white"),
+    (SoilPage::Y2_5, &[8.0], &[2.0, 3.0, 4.0], "pale yellow"),
+    (SoilPage::Y2_5, &[8.0, 7.0], &[6.0, 8.0], "yellow"),
+    (SoilPage::Y2_5, &[7.0], &[1.0, 2.0], "light gray"),
+    (SoilPage::Y2_5, &[7.0], &[3.0, 4.0], "pale yellow"),
+    (SoilPage::Y2_5, &[6.0, 5.0], &[1.0], "gray"),
+    (SoilPage::Y2_5, &[6.0], &[2.0], "light brownish gray"),
+    (SoilPage::Y2_5, &[6.0], &[3.0, 4.0], "light yellowish brown"),
+    (SoilPage::Y2_5, &[6.0], &[6.0, 8.0], "olive yellow"),
+    (SoilPage::Y2_5, &[5.0], &[2.0], "grayish brown"),
+    (SoilPage::Y2_5, &[5.0], &[3.0, 4.0, 6.0], "light olive brown"),
+    (SoilPage::Y2_5, &[4.0], &[1.0], "dark gray"),
+    (SoilPage::Y2_5, &[4.0], &[2.0], "dark grayish brown"),
+    (SoilPage::Y2_5, &[4.0], &[3.0, 4.0], "olive brown"),
+    (SoilPage::Y2_5, &[3.0], &[1.0], "very dark gray"),
+    (SoilPage::Y2_5, &[3.0], &[2.0], "very dark grayish brown"),
+    (SoilPage::Y2_5, &[3.0], &[3.0], "dark olive brown"),
+    (SoilPage::Y2_5, &[2.5], &[1.0], "black"),
+    (SoilPage::Y5, &[8.0], &[1.0], "white"),
+    (SoilPage::Y5, &[8.0], &[2.0, 3.0, 4.0], "pale yellow"),
+    (SoilPage::Y5, &[8.0, 7.0], &[6.0, 8.0], "yellow"),
+    (SoilPage::Y5, &[7.0], &[1.0, 2.0], "light gray"),
+    (SoilPage::Y5, &[7.0], &[3.0, 4.0], "pale yellow"),
+    (SoilPage::Y5, &[6.0, 5.0], &[1.0], "gray"),
+    (SoilPage::Y5, &[6.0], &[2.0], "light olive gray"),
+    (SoilPage::Y5, &[6.0], &[3.0, 4.0], "pale olive"),
+    (SoilPage::Y5, &[6.0], &[6.0, 8.0], "olive yellow"),
+    (SoilPage::Y5, &[5.0, 4.0], &[2.0], "olive gray"),
+    (SoilPage::Y5, &[5.0], &[3.0, 4.0, 6.0], "olive"),
+    (SoilPage::Y5, &[4.0], &[1.0], "dark gray"),
+    (SoilPage::Y5, &[4.0], &[3.0, 4.0], "olive"),
+    (SoilPage::Y5, &[3.0], &[1.0], "very dark gray"),
+    (SoilPage::Y5, &[3.0], &[2.0], "dark olive gray"),
+    (SoilPage::Y5, &[2.5], &[1.0, 2.0], "black"),
+];
+
+/// Hue columns of the gley pages; "N" is the neutral column.
+const GLEY1_HUES: [&str; 5] = ["N", "10Y", "5GY", "10GY", "5G"];
+const GLEY2_HUES: [&str; 6] = ["10G", "5BG", "10BG", "5B", "10B", "5PB"];
+
+/// Values of the gley pages with the neutral, greenish and bluish names.
+const GLEY_NAMES: [(f64, &str, &str, &str); 7] = [
+    (8.0, "white", "light greenish gray", "light bluish gray"),
+    (7.0, "light gray", "light greenish gray", "light bluish gray"),
+    (6.0, "gray", "greenish gray", "bluish gray"),
+    (5.0, "gray", "greenish gray", "bluish gray"),
+    (4.0, "dark gray", "dark greenish gray", "dark bluish gray"),
+    (3.0, "very dark gray", "very dark greenish gray", "very dark bluish gray"),
+    (2.5, "black", "greenish black", "bluish black"),
+];
+
+/// A page of the Munsell Soil Color Charts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SoilPage {
+    /// 10R
+    R10,
+    /// 2.5YR
+    Yr2_5,
+    /// 5YR
+    Yr5,
+    /// 7.5YR
+    Yr7_5,
+    /// 10YR
+    Yr10,
+    /// 2.5Y
+    Y2_5,
+    /// 5Y
+    Y5,
+    /// Gley 1: neutral, 10Y to 5G
+    Gley1,
+    /// Gley 2: 10G to 5PB
+    Gley2,
+}
+
+impl SoilPage {
+    /// All pages in chart order.
+    pub const ALL: [SoilPage; 9] = [
+        SoilPage::R10,
+        SoilPage::Yr2_5,
+        SoilPage::Yr5,
+        SoilPage::Yr7_5,
+        SoilPage::Yr10,
+        SoilPage::Y2_5,
+        SoilPage::Y5,
+        SoilPage::Gley1,
+        SoilPage::Gley2,
+    ];
+
+    /// Page title as printed, such as "10YR" or "Gley 1".
+    pub fn label(&self) -> &'static str {
+        match self {
+            SoilPage::R10 => "10R",
+            SoilPage::Yr2_5 => "2.5YR",
+            SoilPage::Yr5 => "5YR",
+            SoilPage::Yr7_5 => "7.5YR",
+            SoilPage::Yr10 => "10YR",
+            SoilPage::Y2_5 => "2.5Y",
+            SoilPage::Y5 => "5Y",
+            SoilPage::Gley1 => "Gley 1",
+            SoilPage::Gley2 => "Gley 2",
+        }
+    }
+
+    /// Whether this is one of the gley pages.
+    pub fn is_gley(&self) -> bool {
+        matches!(self, SoilPage::Gley1 | SoilPage::Gley2)
+    }
+
+    /// Internal: page prefix of gley notation.
+    fn gley_prefix(&self) -> Option<&'static str> {
+        match self {
+            SoilPage::Gley1 => Some("GLEY1"),
+            SoilPage::Gley2 => Some("GLEY2"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SoilPage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// A chip of the Munsell Soil Color Charts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoilChip {
+    /// Chart page
+    pub page: SoilPage,
+    /// Munsell hue, or "N" for the neutral gley column
+    pub hue: &'static str,
+    /// Munsell value
+    pub value: f64,
+    /// Munsell chroma; 0 for neutral chips
+    pub chroma: f64,
+    /// Soil color name, such as "dark yellowish brown"
+    pub name: &'static str,
+    spec: MunsellSpec,
+}
+
+impl SoilChip {
+    /// All chips of the charts, page by page.
+    pub fn all() -> &'static [SoilChip] {
+        static CHIPS: OnceLock<Vec<SoilChip>> = OnceLock::new();
+        CHIPS.get_or_init(build_chips)
+    }
+
+    /// Find the chip written by a soil notation.
+    ///
+    /// Accepts hue page notation (`10YR 3/4`, `10YR 3.0/4.0`), gley notation
+    /// (`GLEY1 5/10Y`, `Gley 2 4/5PB`, `GLEY1 6/N`) and the ordinary notation
+    /// of gley chips (`10Y 5/1`, `N 6/`).
+    ///
+    /// # Errors
+    /// Returns an error if the notation cannot be parsed or does not name a
+    /// chip of the charts.
+    pub fn from_notation(notation: &str) -> Result<SoilChip> {
+        let upper = notation.trim().to_uppercase();
+        let not_a_chip = || {
+            MunsellError::InvalidMunsellColor(format!(
+                "{} is not a Munsell soil chart chip",
+                notation.trim()
+            ))
+        };
+
+        if let Some(gley) = GleyNotation::parse(notation)? {
+            let page = if gley.page == 1 { SoilPage::Gley1 } else { SoilPage::Gley2 };
+            return Self::all()
+                .iter()
+                .find(|chip| chip.page == page && chip.hue == gley.hue && chip.value == gley.value)
+                .copied()
+                .ok_or_else(not_a_chip);
+        }
+
+        let color = MunsellColor::from_notation(&upper)?;
+        let hue = color.hue.as_deref().unwrap_or("N");
+        let chroma = color.chroma.unwrap_or(0.0);
+        Self::all()
+            .iter()
+            .find(|chip| chip.hue == hue && chip.value == color.value && chip.chroma == chroma)
+            .copied()
+            .ok_or_else(not_a_chip)
+    }
+
+    /// Soil notation: `10YR 3/4` on hue pages, `GLEY1 5/10Y` on gley pages.
+    pub fn notation(&self) -> String {
+        match self.page.gley_prefix() {
+            Some(prefix) => format!("{} {}/{}", prefix, self.value, self.hue),
+            None => format!("{} {}/{}", self.hue, self.value, self.chroma),
+        }
+    }
+
+    /// Munsell color of the chip.
+    pub fn munsell(&self) -> MunsellColor {
+        if self.chroma > 0.0 {
+            MunsellColor::new_chromatic(self.hue.to_string(), self.value, self.chroma)
+        } else {
+            MunsellColor::new_neutral(self.value)
+        }
+    }
+
+    /// Munsell specification with numeric hue.
+    pub fn spec(&self) -> MunsellSpec {
+        self.spec
+    }
+}
+
+impl fmt::Display for SoilChip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.notation())
+    }
+}
+
+/// Nearest soil chip to a color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoilMatch {
+    /// Nearest chip
+    pub chip: SoilChip,
+    /// Munsell Cartesian distance from the color to the chip
+    pub distance: f64,
+}
+
+/// Snaps colors to the nearest chip of the Munsell Soil Color Charts.
+pub struct SoilChart {
+    converter: MunsellConverter,
+}
+
+impl SoilChart {
+    /// Create a chart.
+    ///
+    /// # Errors
+    /// Returns an error if the converter cannot be created.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            converter: MunsellConverter::new()?,
+        })
+    }
+
+    /// Chips of one page.
+    pub fn page(&self, page: SoilPage) -> impl Iterator<Item = &'static SoilChip> {
+        SoilChip::all().iter().filter(move |chip| chip.page == page)
+    }
+
+    /// Nearest chip to a Munsell color.
+    ///
+    /// # Errors
+    /// Returns an error if the color's hue cannot be read.
+    pub fn nearest(&self, color: &MunsellColor) -> Result<SoilMatch> {
+        let spec = color.to_munsell_spec().ok_or_else(|| {
+            MunsellError::InvalidMunsellColor(format!("Cannot read {}", color.notation))
+        })?;
+        Ok(nearest_chip(spec))
+    }
+
+    /// Nearest chip to an sRGB color.
+    ///
+    /// # Errors
+    /// Returns an error if the color cannot be converted to Munsell.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::soil::SoilChart;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let chart = SoilChart::new()?;
+    /// let found = chart.nearest_srgb([110, 80, 50])?;
+    ///
+    /// println!("{} ({}): {}", found.chip, found.chip.page, found.chip.name);
+    /// assert!(found.chip.name.contains("brown"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn nearest_srgb(&self, rgb: [u8; 3]) -> Result<SoilMatch> {
+        let color = self.converter.srgb_to_munsell(rgb)?;
+        self.nearest(&color)
+    }
+}
+
+/// Internal: nearest chip to a specification.
+fn nearest_chip(spec: MunsellSpec) -> SoilMatch {
+    SoilChip::all()
+        .iter()
+        .map(|chip| SoilMatch {
+            chip: *chip,
+            distance: spec.distance_from(&chip.spec),
+        })
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
+        .expect("the soil charts have chips")
+}
+
+/// Internal: expand the name tables into chips.
+fn build_chips() -> Vec<SoilChip> {
+    let chip = |page, hue: &'static str, value, chroma, name| {
+        let spec = match parse_hue_to_number(hue) {
+            Some(hue_number) if chroma > 0.0 => MunsellSpec::new(hue_number, value, chroma),
+            _ => MunsellSpec::neutral(value),
+        };
+        SoilChip {
+            page,
+            hue,
+            value,
+            chroma,
+            name,
+            spec,
+        }
+    };
+
+    let mut chips = Vec::new();
+    for &(page, values, chromas, name) in HUE_PAGE_NAMES {
+        for &value in values {
+            for &chroma in chromas {
+                chips.push(chip(page, page.label(), value, chroma, name));
+            }
+        }
+    }
+    for &(value, neutral, greenish, _) in &GLEY_NAMES {
+        for hue in GLEY1_HUES {
+            let (chroma, name) = if hue == "N" { (0.0, neutral) } else { (GLEY_CHROMA, greenish) };
+            chips.push(chip(SoilPage::Gley1, hue, value, chroma, name));
+        }
+    }
+    for &(value, _, greenish, bluish) in &GLEY_NAMES {
+        for hue in GLEY2_HUES {
+            let name = if hue.ends_with('G') { greenish } else { bluish };
+            chips.push(chip(SoilPage::Gley2, hue, value, GLEY_CHROMA, name));
+        }
+    }
+    chips
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color_names::ColorClassifier;
+    use crate::reverse_conversion::parse_munsell_notation;
+
+    #[test]
+    fn test_notation_round_trip() {
+        for chip in SoilChip::all() {
+            let parsed = SoilChip::from_notation(&chip.notation()).unwrap();
+            assert_eq!(&parsed, chip, "{}", chip);
+        }
+
+        for notation in ["GLEY1 5/10Y", "GLEY1 6/N", "GLEY2 2.5/5PB", "10YR 3/4", "2.5YR 2.5/3"] {
+            assert_eq!(SoilChip::from_notation(notation).unwrap().to_string(), notation);
+        }
+
+        let chip = SoilChip::from_notation("gley 2 4/10b").unwrap();
+        assert_eq!((chip.page, chip.name), (SoilPage::Gley2, "dark bluish gray"));
+        assert_eq!(chip.munsell().notation, "10B 4.0/1.0");
+        assert_eq!(SoilChip::from_notation("5GY 5.0/1.0").unwrap().to_string(), "GLEY1 5/5GY");
+
+        assert!(SoilChip::from_notation("GLEY2 5/N").is_err());
+        assert!(SoilChip::from_notation("GLEY3 5/10Y").is_err());
+        assert!(SoilChip::from_notation("5R 4/14").is_err());
+    }
+
+    #[test]
+    fn test_gley_notation_in_core_parsers() {
+        let classifier = ColorClassifier::new().unwrap();
+        for chip in SoilChip::all().iter().filter(|chip| chip.page.is_gley()) {
+            let notation = chip.to_string();
+            let ordinary = chip.munsell().notation;
+
+            let color = MunsellColor::from_notation(&notation).unwrap();
+            assert_eq!(color, MunsellColor::from_notation(&ordinary).unwrap(), "{}", notation);
+            assert_eq!(
+                parse_munsell_notation(&notation).unwrap(),
+                parse_munsell_notation(&ordinary).unwrap(),
+                "{}",
+                notation
+            );
+            assert_eq!(
+                classifier.classify_munsell(&notation).unwrap().standard_descriptor(),
+                classifier.classify_munsell(&ordinary).unwrap().standard_descriptor(),
+                "{}",
+                notation
+            );
+        }
+
+        assert!(MunsellColor::from_notation("GLEY3 5/10Y").is_err());
+        assert!(MunsellColor::from_notation("GLEY1 5/10X").is_err());
+    }
+
+    #[test]
+    fn test_nearest_chip() {
+        let chart = SoilChart::new().unwrap();
+        assert_eq!(chart.page(SoilPage::Gley1).count(), 35);
+        assert!(SoilPage::ALL.iter().all(|&page| chart.page(page).count() > 0));
+
+        let color = MunsellColor::from_notation("9.2YR 3.1/3.8").unwrap();
+        let found = chart.nearest(&color).unwrap();
+        assert_eq!(found.chip.notation(), "10YR 3/4");
+        assert_eq!(found.chip.name, "dark yellowish brown");
+
+        let found = chart.nearest(&MunsellColor::from_notation("5R 5.2/1.1").unwrap()).unwrap();
+        assert_eq!(found.chip.name, "reddish gray");
+
+        let found = chart.nearest_srgb([128, 128, 128]).unwrap();
+        assert_eq!(found.chip.to_string(), "GLEY1 5/N");
+    }
+}
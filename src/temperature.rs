@@ -0,0 +1,294 @@
+//! Warm/cool temperature bias of colors within their hue family.
+//!
+//! Implements the temperature model of `future_development.md` section 1 in
+//! Munsell space. A chromatic color is scored by its signed hue deviation from
+//! the center of its family (5R, 5YR, ..., 5RP), oriented along a warm axis
+//! that points toward red-orange: a red leaning toward yellow-red is a warm
+//! red, one leaning toward red-purple is a cool red. The score is gated by
+//! chroma, because temperature judgments collapse near neutral.
+//!
+//! Near-neutral colors are handled separately: a gray's temperature is the
+//! projection of its residual chroma onto the warm axis, so "10YR 6/1" is a
+//! warm gray and "5B 6/1" a cool gray.
+//!
+//! # Example
+//!
+//! ```rust
+//! use munsellspace::MunsellColor;
+//! use munsellspace::temperature::TemperatureLabel;
+//!
+//! let orange_red = MunsellColor::new_chromatic("9R".to_string(), 5.0, 12.0);
+//! let purple_red = MunsellColor::new_chromatic("1R".to_string(), 5.0, 12.0);
+//!
+//! assert_eq!(orange_red.temperature().unwrap().label, TemperatureLabel::Warm);
+//! assert_eq!(purple_red.temperature().unwrap().label, TemperatureLabel::Cool);
+//! ```
+
+use std::f64::consts::PI;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::semantic_overlay::MunsellSpec;
+
+/// Categorical temperature label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TemperatureLabel {
+    /// Score at or below `-warm_threshold`
+    Cool,
+    /// Score between `-warm_threshold` and `-neutral_threshold`
+    SlightlyCool,
+    /// Score within `neutral_threshold` of zero, or an undetermined color
+    Neutral,
+    /// Score between `neutral_threshold` and `warm_threshold`
+    SlightlyWarm,
+    /// Score at or above `warm_threshold`
+    Warm,
+}
+
+impl TemperatureLabel {
+    /// Lowercase qualifier used in descriptions ("slightly warm").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemperatureLabel::Cool => "cool",
+            TemperatureLabel::SlightlyCool => "slightly cool",
+            TemperatureLabel::Neutral => "neutral",
+            TemperatureLabel::SlightlyWarm => "slightly warm",
+            TemperatureLabel::Warm => "warm",
+        }
+    }
+}
+
+impl fmt::Display for TemperatureLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parameters of the temperature model.
+///
+/// Hue positions use the 0-40 hue number scale of [`MunsellSpec`] and chroma
+/// values are Munsell chroma.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TemperatureConfig {
+    /// Hue number of the warmest direction (default 4.0, i.e. 10R)
+    pub warm_pole: f64,
+    /// Below this chroma a color is treated as a gray (default 1.5)
+    pub gray_chroma: f64,
+    /// Chroma at which the family deviation counts fully (default 4.0).
+    /// Between `gray_chroma` and this value the score ramps up linearly.
+    pub full_chroma: f64,
+    /// Scores with magnitude below this are neutral (default 0.2)
+    pub neutral_threshold: f64,
+    /// Scores with magnitude at or above this are warm or cool (default 0.6)
+    pub warm_threshold: f64,
+    /// Minimum warm-axis chroma for a gray to be warm or cool (default 0.3)
+    pub gray_threshold: f64,
+}
+
+impl Default for TemperatureConfig {
+    fn default() -> Self {
+        Self {
+            warm_pole: 4.0,
+            gray_chroma: 1.5,
+            full_chroma: 4.0,
+            neutral_threshold: 0.2,
+            warm_threshold: 0.6,
+            gray_threshold: 0.3,
+        }
+    }
+}
+
+impl TemperatureConfig {
+    /// Label a chromatic temperature score.
+    pub fn label(&self, score: f64) -> TemperatureLabel {
+        if score >= self.warm_threshold {
+            TemperatureLabel::Warm
+        } else if score <= -self.warm_threshold {
+            TemperatureLabel::Cool
+        } else if score >= self.neutral_threshold {
+            TemperatureLabel::SlightlyWarm
+        } else if score <= -self.neutral_threshold {
+            TemperatureLabel::SlightlyCool
+        } else {
+            TemperatureLabel::Neutral
+        }
+    }
+}
+
+/// Temperature of a color.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Temperature {
+    /// Continuous score in `[-1, 1]`; negative is cool, positive warm
+    pub score: f64,
+    /// Categorical label for the score
+    pub label: TemperatureLabel,
+    /// Whether the color was scored as a gray
+    pub achromatic: bool,
+}
+
+impl Temperature {
+    /// Qualifier to prefix to a color name, or `None` when neutral.
+    ///
+    /// Grays are only ever "warm" or "cool".
+    pub fn qualifier(&self) -> Option<&'static str> {
+        match self.label {
+            TemperatureLabel::Neutral => None,
+            label => Some(label.as_str()),
+        }
+    }
+}
+
+/// Compute the temperature of a Munsell color.
+///
+/// # Examples
+/// ```rust
+/// use munsellspace::MunsellSpec;
+/// use munsellspace::temperature::{temperature, TemperatureConfig, TemperatureLabel};
+///
+/// let config = TemperatureConfig::default();
+///
+/// // 10YR 6/1: a gray with a yellow-red cast
+/// let gray = temperature(&MunsellSpec::new(8.0, 6.0, 1.0), &config);
+/// assert!(gray.achromatic);
+/// assert_eq!(gray.label, TemperatureLabel::Warm);
+///
+/// // 5R 4/14 sits on its family center
+/// let red = temperature(&MunsellSpec::new(2.0, 4.0, 14.0), &config);
+/// assert_eq!(red.label, TemperatureLabel::Neutral);
+/// ```
+pub fn temperature(spec: &MunsellSpec, config: &TemperatureConfig) -> Temperature {
+    let chroma = spec.chroma.max(0.0);
+    let angle = hue_angle(spec.hue_number);
+    let warm_angle = hue_angle(config.warm_pole);
+
+    if chroma < config.gray_chroma {
+        // Residual chroma projected on the warm axis
+        let warmth = chroma * (angle - warm_angle).cos();
+        let label = if warmth > config.gray_threshold {
+            TemperatureLabel::Warm
+        } else if warmth < -config.gray_threshold {
+            TemperatureLabel::Cool
+        } else {
+            TemperatureLabel::Neutral
+        };
+        let score = if config.gray_chroma > 0.0 {
+            (warmth / config.gray_chroma).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        };
+        return Temperature {
+            score,
+            label,
+            achromatic: true,
+        };
+    }
+
+    // Family center: 5X sits at 4i + 2 for the family spanning (4i, 4i + 4]
+    let hue = spec.hue_number.rem_euclid(40.0);
+    let hue = if hue == 0.0 { 40.0 } else { hue };
+    let family = ((hue / 4.0).ceil() - 1.0).max(0.0);
+    let center = family * 4.0 + 2.0;
+    let deviation = (hue - center) / 2.0;
+
+    // Whether increasing hue moves toward the warm pole from this center;
+    // a center on the warm or cool pole has no preferred direction
+    let slope = (warm_angle - hue_angle(center)).sin();
+    let direction = if slope.abs() < 1e-12 { 0.0 } else { slope.signum() };
+
+    let gate = if config.full_chroma > config.gray_chroma {
+        ((chroma - config.gray_chroma) / (config.full_chroma - config.gray_chroma)).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+
+    let score = (direction * deviation * gate).clamp(-1.0, 1.0);
+    Temperature {
+        score,
+        label: config.label(score),
+        achromatic: false,
+    }
+}
+
+/// Hue angle in radians (9 degrees per hue number step).
+fn hue_angle(hue_number: f64) -> f64 {
+    hue_number * 9.0 * PI / 180.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::semantic_overlay::parse_hue_to_number;
+
+    fn score(hue: &str, chroma: f64) -> Temperature {
+        let spec = MunsellSpec::new(parse_hue_to_number(hue).unwrap(), 5.0, chroma);
+        temperature(&spec, &TemperatureConfig::default())
+    }
+
+    #[test]
+    fn test_family_centers_are_neutral() {
+        for family in crate::semantic_overlay::HUE_FAMILIES {
+            let t = score(&format!("5{}", family), 10.0);
+            assert_eq!(t.label, TemperatureLabel::Neutral, "5{}", family);
+            assert!(t.score.abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_warm_and_cool_directions() {
+        // Leaning toward red-orange is warm, toward blue-green is cool
+        assert_eq!(score("9R", 10.0).label, TemperatureLabel::Warm);
+        assert_eq!(score("1R", 10.0).label, TemperatureLabel::Cool);
+        assert_eq!(score("1.5Y", 10.0).label, TemperatureLabel::Warm);
+        assert_eq!(score("9Y", 10.0).label, TemperatureLabel::Cool);
+        assert_eq!(score("9B", 10.0).label, TemperatureLabel::Warm);
+        assert_eq!(score("1B", 10.0).label, TemperatureLabel::Cool);
+        assert_eq!(score("6.5R", 10.0).label, TemperatureLabel::SlightlyWarm);
+        assert_eq!(score("3.5R", 10.0).label, TemperatureLabel::SlightlyCool);
+    }
+
+    #[test]
+    fn test_score_is_continuous_across_warm_pole() {
+        let below = score("9.9R", 10.0).score;
+        let above = score("0.1YR", 10.0).score;
+        assert!(below > 0.9 && above > 0.9);
+    }
+
+    #[test]
+    fn test_chroma_gating() {
+        let low = score("9R", 2.0);
+        let high = score("9R", 10.0);
+        assert!(!low.achromatic);
+        assert!(low.score > 0.0 && low.score < high.score);
+        assert_eq!(score("9R", 4.0).score, high.score);
+    }
+
+    #[test]
+    fn test_gray_temperature() {
+        assert_eq!(score("10YR", 1.0).label, TemperatureLabel::Warm);
+        assert_eq!(score("5B", 1.0).label, TemperatureLabel::Cool);
+        // Perpendicular to the warm axis, and true neutrals
+        assert_eq!(score("5GY", 1.0).label, TemperatureLabel::Neutral);
+        let neutral = temperature(&MunsellSpec::neutral(5.0), &TemperatureConfig::default());
+        assert!(neutral.achromatic);
+        assert_eq!(neutral.label, TemperatureLabel::Neutral);
+        assert_eq!(neutral.qualifier(), None);
+    }
+
+    #[test]
+    fn test_configurable_thresholds() {
+        let spec = MunsellSpec::new(parse_hue_to_number("6.5R").unwrap(), 5.0, 10.0);
+        let strict = TemperatureConfig {
+            warm_threshold: 0.9,
+            neutral_threshold: 0.5,
+            ..TemperatureConfig::default()
+        };
+        assert_eq!(temperature(&spec, &strict).label, TemperatureLabel::Neutral);
+
+        let loose = TemperatureConfig {
+            warm_threshold: 0.1,
+            ..TemperatureConfig::default()
+        };
+        assert_eq!(temperature(&spec, &loose).label, TemperatureLabel::Warm);
+    }
+}
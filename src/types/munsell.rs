@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use crate::error::{MunsellError, Result};
 use crate::semantic_overlay::{self, MunsellSpec};
-use super::notation::{format_value, split_hue_from_value, is_valid_hue_format};
+use super::notation::{format_value, split_hue_from_value, is_valid_hue_format, GleyNotation};
 
 /// Represents a color in the Munsell color system.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -77,6 +77,9 @@ impl MunsellColor {
     /// # Arguments
     /// * `notation` - Munsell notation string (e.g., "5R 4.0/14.0" or "N 5.6/")
     ///
+    /// Soil chart gley notation is also accepted: "GLEY1 5/10Y" is the chip
+    /// "10Y 5.0/1.0", and "GLEY1 6/N" is "N 6.0".
+    ///
     /// # Returns
     /// Result containing the parsed MunsellColor or an error
     ///
@@ -91,10 +94,18 @@ impl MunsellColor {
     ///
     /// let gray = MunsellColor::from_notation("N 5.6/").unwrap();
     /// assert!(gray.is_neutral());
+    ///
+    /// let gley = MunsellColor::from_notation("GLEY1 5/10Y").unwrap();
+    /// assert_eq!(gley.notation, "10Y 5.0/1.0");
     /// ```
     pub fn from_notation(notation: &str) -> Result<Self> {
         let notation = notation.trim();
 
+        // Handle soil chart gley notation by its ordinary notation
+        if let Some(gley) = GleyNotation::parse(notation)? {
+            return Self::from_notation(&gley.to_munsell_notation());
+        }
+
         // Handle neutral colors: accept "N 5.6", "N5.6", "n 5.6", "N 5.6/", "N 5.6/0"
         let upper = notation.to_uppercase();
         if upper.starts_with('N') {
@@ -342,7 +353,7 @@ impl MunsellColor {
     pub fn semantic_overlay(&self) -> Option<&'static str> {
         let spec = self.to_munsell_spec()?;
         let registry = crate::semantic_overlay_data::get_registry();
-        registry.best_match(&spec).map(|o| o.name)
+        registry.best_match(&spec).map(|o| o.name.as_str())
     }
 
     /// Get all matching semantic overlay names for this color.
@@ -370,7 +381,7 @@ impl MunsellColor {
                 let registry = crate::semantic_overlay_data::get_registry();
                 registry.matching_overlays(&spec)
                     .into_iter()
-                    .map(|o| o.name)
+                    .map(|o| o.name.as_str())
                     .collect()
             }
             None => Vec::new(),
@@ -426,7 +437,37 @@ impl MunsellColor {
     pub fn closest_overlay(&self) -> Option<(&'static str, f64)> {
         let spec = self.to_munsell_spec()?;
         let registry = crate::semantic_overlay_data::get_registry();
-        registry.closest_overlay(&spec).map(|(o, d)| (o.name, d))
+        registry.closest_overlay(&spec).map(|(o, d)| (o.name.as_str(), d))
+    }
+
+    /// Get the warm/cool temperature of this color within its hue family.
+    ///
+    /// Uses the default [`TemperatureConfig`](crate::temperature::TemperatureConfig).
+    /// Grays are scored by the residual chroma along the warm axis.
+    ///
+    /// # Returns
+    /// The temperature score and label, or None if the hue cannot be parsed
+    ///
+    /// # Examples
+    /// ```
+    /// use munsellspace::MunsellColor;
+    ///
+    /// let gray = MunsellColor::new_chromatic("10YR".to_string(), 6.0, 1.0);
+    /// if let Some(temperature) = gray.temperature() {
+    ///     println!("{} gray (score {:.2})", temperature.label, temperature.score);
+    /// }
+    /// ```
+    pub fn temperature(&self) -> Option<crate::temperature::Temperature> {
+        self.temperature_with(&crate::temperature::TemperatureConfig::default())
+    }
+
+    /// Get the temperature of this color with custom thresholds.
+    pub fn temperature_with(
+        &self,
+        config: &crate::temperature::TemperatureConfig,
+    ) -> Option<crate::temperature::Temperature> {
+        let spec = self.to_munsell_spec()?;
+        Some(crate::temperature::temperature(&spec, config))
     }
 }
 
@@ -1,5 +1,68 @@
 //! Munsell notation parsing and validation helpers.
 
+use crate::error::{MunsellError, Result};
+
+/// Chroma of every chromatic chip on the soil chart gley pages.
+pub(crate) const GLEY_CHROMA: f64 = 1.0;
+
+/// Soil chart gley notation, such as "GLEY1 5/10Y" or "GLEY1 6/N".
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct GleyNotation {
+    /// Gley page, 1 or 2
+    pub page: u8,
+    /// Munsell value
+    pub value: f64,
+    /// Uppercase Munsell hue, or "N" for the neutral column
+    pub hue: String,
+}
+
+impl GleyNotation {
+    /// Parse gley notation; `Ok(None)` if the notation is not gley notation.
+    ///
+    /// Accepts "GLEY1 5/10Y", "Gley 2 4/5PB" and "GLEY1 6/N".
+    pub(crate) fn parse(notation: &str) -> Result<Option<Self>> {
+        let upper = notation.trim().to_uppercase();
+        let Some(rest) = upper.strip_prefix("GLEY") else {
+            return Ok(None);
+        };
+        let invalid = |reason: &str| MunsellError::InvalidNotation {
+            notation: notation.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let rest = rest.trim_start();
+        let (page, rest) = if let Some(rest) = rest.strip_prefix('1') {
+            (1, rest)
+        } else if let Some(rest) = rest.strip_prefix('2') {
+            (2, rest)
+        } else {
+            return Err(invalid("Expected GLEY1 or GLEY2"));
+        };
+        let (value, hue) = rest
+            .trim()
+            .split_once('/')
+            .ok_or_else(|| invalid("Expected format: 'GLEY1 VALUE/HUE'"))?;
+        let value = value
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| invalid("Invalid value component in gley color"))?;
+        let hue = hue.trim().to_string();
+        if hue != "N" && !is_valid_hue_format(&hue) {
+            return Err(invalid("Invalid hue in gley color. Expected 'N' or a hue like '10Y'"));
+        }
+        Ok(Some(Self { page, value, hue }))
+    }
+
+    /// Ordinary Munsell notation of the chip, such as "10Y 5/1" or "N 6".
+    pub(crate) fn to_munsell_notation(&self) -> String {
+        if self.hue == "N" {
+            format!("N {}", self.value)
+        } else {
+            format!("{} {}/{}", self.hue, self.value, GLEY_CHROMA)
+        }
+    }
+}
+
 /// Format a Munsell value, dropping the trailing `.0` when the value is an integer.
 pub(crate) fn format_value(v: f64) -> String {
     if v.fract() == 0.0 {
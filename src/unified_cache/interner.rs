@@ -0,0 +1,53 @@
+//! Strings and ISCC-NBS metadata shared between cache entries.
+//!
+//! A cached color repeats names that only take a few hundred distinct values:
+//! its ISCC-NBS metadata and the overlay and color names of its
+//! characterizations. Entries hold them behind an [`Arc`] from the cache's
+//! interner, so each distinct value is stored once however many colors use
+//! it.
+
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+use crate::iscc::ColorMetadata;
+
+/// Interned strings and ISCC-NBS metadata of a cache
+#[derive(Default)]
+pub(super) struct Interner {
+    strings: RwLock<HashSet<Arc<str>>>,
+    metadata: RwLock<HashSet<Arc<ColorMetadata>>>,
+}
+
+impl Interner {
+    /// Shared copy of a string.
+    pub(super) fn str(&self, value: &str) -> Arc<str> {
+        if let Some(shared) = self.strings.read().unwrap().get(value) {
+            return Arc::clone(shared);
+        }
+        let mut strings = self.strings.write().unwrap();
+        match strings.get(value) {
+            Some(shared) => Arc::clone(shared),
+            None => {
+                let shared: Arc<str> = Arc::from(value);
+                strings.insert(Arc::clone(&shared));
+                shared
+            }
+        }
+    }
+
+    /// Shared copy of ISCC-NBS metadata.
+    pub(super) fn metadata(&self, metadata: &ColorMetadata) -> Arc<ColorMetadata> {
+        if let Some(shared) = self.metadata.read().unwrap().get(metadata) {
+            return Arc::clone(shared);
+        }
+        let mut all = self.metadata.write().unwrap();
+        match all.get(metadata) {
+            Some(shared) => Arc::clone(shared),
+            None => {
+                let shared = Arc::new(metadata.clone());
+                all.insert(Arc::clone(&shared));
+                shared
+            }
+        }
+    }
+}
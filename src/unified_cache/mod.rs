@@ -0,0 +1,826 @@
+//! Thread-safe unified color conversion cache with LRU eviction
+//!
+//! This module provides a centralized caching system for color conversions
+//! that normalizes all inputs to RGB [u8; 3] format to ensure cache hits
+//! regardless of input format (hex, RGB, Lab, HSL, HSV).
+//!
+//! Entries are spread over independently locked shards so parallel workloads
+//! rarely contend. Each shard is a least-recently-used cache: a hash map
+//! indexes a doubly-linked list threaded through a slab of entries, so
+//! lookups, promotions, insertions and evictions are all O(1). Small caches
+//! use a single shard and therefore evict in exact LRU order; larger caches
+//! evict the least recently used entry of the shard a color hashes to.
+//!
+//! Entries can optionally expire after a time to live, and [`CacheStats`]
+//! reports hits, misses, evictions and expirations.
+//!
+//! A cache can be shared with [`MunsellConverter::with_cache`](crate::MunsellConverter::with_cache) and
+//! [`ColorClassifier::with_cache`](crate::ColorClassifier::with_cache). An
+//! entry then memoizes the whole pipeline for its color: the Munsell
+//! conversion, the ISCC-NBS classification and the semantic overlay
+//! characterization, so repeated colors skip both the solver and the polygon
+//! tests.
+//!
+//! Caches can be saved to and loaded from cache files, and cache files can be
+//! precomputed for a color list or the whole sRGB cube so services start
+//! warm; see [`UnifiedColorCache::save`] and
+//! [`ColorClassifier::precompute_cache_file`](crate::ColorClassifier::precompute_cache_file).
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use crate::color_names::{ColorCharacterization, ColorModifier};
+use crate::semantic_overlay::MunsellSpec;
+use crate::temperature::Temperature;
+use crate::{MunsellColor, ColorMetadata, MunsellError};
+
+mod interner;
+mod persistence;
+
+use interner::Interner;
+
+pub use persistence::{dataset_fingerprint, srgb_cube, CacheFileHeader};
+pub(crate) use persistence::naming_profile;
+
+/// Maximum number of cached entries (LRU eviction when exceeded)
+const CACHE_SIZE: usize = 500;
+
+/// Maximum number of shards
+const MAX_SHARDS: usize = 16;
+
+/// Smallest capacity worth giving its own shard
+const MIN_SHARD_CAPACITY: usize = 64;
+
+/// End of a shard's recency list
+const NIL: usize = usize::MAX;
+
+/// Complete cached result for a color conversion
+#[derive(Clone, Debug)]
+pub struct CachedColorResult {
+    /// The RGB value (canonical form)
+    pub rgb: [u8; 3],
+    /// Munsell notation result
+    pub munsell: MunsellColor,
+    /// ISCC-NBS classification result (if applicable)
+    pub iscc_nbs: Option<ColorMetadata>,
+}
+
+/// Thread-safe cache for color conversions with sharded LRU eviction
+#[derive(Clone)]
+pub struct UnifiedColorCache {
+    /// Independently locked LRU shards
+    shards: Arc<[Mutex<Shard>]>,
+    /// Maximum cache size, summed over shards
+    max_size: usize,
+    /// Age after which entries expire
+    ttl: Option<Duration>,
+    /// Metadata and names shared by the entries
+    interner: Arc<Interner>,
+}
+
+impl UnifiedColorCache {
+    /// Create a new cache with default size (500 entries)
+    pub fn new() -> Self {
+        Self::with_capacity(CACHE_SIZE)
+    }
+    
+    /// Create a new cache with specified capacity
+    ///
+    /// The capacity is split over up to 16 shards of at least 64 entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let shard_count = (capacity / MIN_SHARD_CAPACITY).clamp(1, MAX_SHARDS);
+        // A power of two, so shards are picked by masking the hash
+        let shard_count = 1 << shard_count.ilog2();
+
+        let shards = (0..shard_count)
+            .map(|i| {
+                let share = capacity / shard_count + usize::from(i < capacity % shard_count);
+                Mutex::new(Shard::new(share))
+            })
+            .collect();
+        Self {
+            shards,
+            max_size: capacity,
+            ttl: None,
+            interner: Arc::default(),
+        }
+    }
+
+    /// Expire entries once they are older than `ttl`.
+    ///
+    /// Age is counted from when the entry was created or its result last
+    /// replaced; reading an entry, or storing what it already holds, does
+    /// not renew it.
+    /// Expired entries are dropped when next looked up, or evicted as the
+    /// least recently used.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use std::time::Duration;
+    /// use munsellspace::UnifiedColorCache;
+    ///
+    /// let cache = UnifiedColorCache::with_capacity(4096).with_ttl(Duration::from_secs(300));
+    /// assert_eq!(cache.ttl(), Some(Duration::from_secs(300)));
+    /// ```
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Time to live of entries, if they expire.
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+    
+    /// Look up a color by RGB value, marking it as most recently used
+    ///
+    /// Entries stored by a [`MunsellConverter`] alone have no ISCC-NBS
+    /// classification yet and are not returned.
+    pub fn get(&self, rgb: &[u8; 3]) -> Option<CachedColorResult> {
+        self.lookup(rgb, |entry| entry.iscc_known.then(|| entry.result(*rgb)))
+    }
+    
+    /// Store a color conversion result
+    ///
+    /// When the shard is full, its least recently used entry is evicted.
+    pub fn insert(&self, rgb: [u8; 3], result: CachedColorResult) {
+        let iscc_nbs = self.intern_metadata(&result);
+        self.store(
+            rgb,
+            |entry| {
+                *entry = Entry::new(result.munsell.clone(), iscc_nbs.clone(), true);
+                true
+            },
+            || Entry::new(result.munsell.clone(), iscc_nbs.clone(), true),
+        );
+    }
+
+    /// Internal: cached Munsell conversion of a color, complete or not.
+    pub(crate) fn get_munsell(&self, rgb: &[u8; 3]) -> Option<MunsellColor> {
+        self.lookup(rgb, |entry| Some(entry.munsell.clone()))
+    }
+
+    /// Internal: store a Munsell conversion without disturbing a more
+    /// complete entry for the same color.
+    pub(crate) fn insert_munsell(&self, rgb: [u8; 3], munsell: MunsellColor) {
+        self.store(
+            rgb,
+            |_| false,
+            || Entry::new(munsell, None, false),
+        );
+    }
+
+    /// Internal: cached characterization made by a classifier with the given
+    /// naming profile.
+    ///
+    /// Counts as a single lookup. On a miss, a cached Munsell conversion of
+    /// the color is returned when there is one, so the solver can be skipped.
+    pub(crate) fn get_characterization(
+        &self,
+        rgb: &[u8; 3],
+        profile: u64,
+    ) -> std::result::Result<ColorCharacterization, Option<MunsellColor>> {
+        let mut shard = self.shard(rgb).lock().unwrap();
+        let found = match shard.entry(rgb, self.ttl, Instant::now()) {
+            Some(entry) => entry
+                .characterizations
+                .iter()
+                .find(|(p, _)| *p == profile)
+                .map(|(_, characterization)| characterization.to_characterization())
+                .ok_or_else(|| Some(entry.munsell.clone())),
+            None => Err(None),
+        };
+        if found.is_ok() {
+            shard.hits += 1;
+        } else {
+            shard.misses += 1;
+        }
+        found
+    }
+
+    /// Internal: store the full result of the naming pipeline for a color.
+    ///
+    /// Characterizations made with other profiles are kept, up to
+    /// [`MAX_PROFILES_PER_ENTRY`]; the oldest is dropped beyond that.
+    pub(crate) fn insert_characterization(
+        &self,
+        result: CachedColorResult,
+        profile: u64,
+        characterization: &ColorCharacterization,
+    ) {
+        let iscc_nbs = self.intern_metadata(&result);
+        let characterization = CachedCharacterization::new(characterization, &self.interner);
+        self.store(
+            result.rgb,
+            |entry| {
+                let replaced = !entry.iscc_known;
+                if replaced {
+                    *entry = Entry {
+                        characterizations: std::mem::take(&mut entry.characterizations),
+                        ..Entry::new(result.munsell.clone(), iscc_nbs.clone(), true)
+                    };
+                }
+                entry.add_characterization(profile, characterization.clone());
+                replaced
+            },
+            || {
+                let mut entry = Entry::new(result.munsell.clone(), iscc_nbs.clone(), true);
+                entry.add_characterization(profile, characterization.clone());
+                entry
+            },
+        );
+    }
+
+    /// Internal: shared copy of the ISCC-NBS metadata of a result.
+    fn intern_metadata(&self, result: &CachedColorResult) -> Option<Arc<ColorMetadata>> {
+        result
+            .iscc_nbs
+            .as_ref()
+            .map(|metadata| self.interner.metadata(metadata))
+    }
+
+    /// Internal: apply `read` to the entry of a color, counting a hit when it
+    /// returns a value.
+    fn lookup<T>(&self, rgb: &[u8; 3], read: impl FnOnce(&Entry) -> Option<T>) -> Option<T> {
+        let mut shard = self.shard(rgb).lock().unwrap();
+        let value = shard.entry(rgb, self.ttl, Instant::now()).and_then(read);
+        if value.is_some() {
+            shard.hits += 1;
+        } else {
+            shard.misses += 1;
+        }
+        value
+    }
+
+    /// Internal: update the entry of a color, or create it; `update` returns
+    /// whether it replaced the cached result.
+    fn store(&self, rgb: [u8; 3], update: impl FnOnce(&mut Entry) -> bool, create: impl FnOnce() -> Entry) {
+        self.shard(&rgb).lock().unwrap().store(rgb, update, create, Instant::now());
+    }
+    
+    /// Clear all cached entries; statistics are kept
+    pub fn clear(&self) {
+        for shard in self.shards.iter() {
+            shard.lock().unwrap().clear();
+        }
+    }
+    
+    /// Get the current number of cached entries
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|shard| shard.lock().unwrap().map.len()).sum()
+    }
+    
+    /// Check if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.shards.iter().all(|shard| shard.lock().unwrap().map.is_empty())
+    }
+    
+    /// Get cache statistics
+    pub fn stats(&self) -> CacheStats {
+        let mut stats = CacheStats {
+            current_size: 0,
+            max_size: self.max_size,
+            capacity: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            expirations: 0,
+            shards: self.shards.len(),
+        };
+        for shard in self.shards.iter() {
+            let shard = shard.lock().unwrap();
+            stats.current_size += shard.map.len();
+            stats.capacity += shard.map.capacity();
+            stats.hits += shard.hits;
+            stats.misses += shard.misses;
+            stats.evictions += shard.evictions;
+            stats.expirations += shard.expirations;
+        }
+        stats
+    }
+
+    /// Internal: shard holding a color.
+    fn shard(&self, rgb: &[u8; 3]) -> &Mutex<Shard> {
+        &self.shards[self.shard_index(rgb)]
+    }
+
+    /// Internal: index of the shard a color belongs to.
+    fn shard_index(&self, rgb: &[u8; 3]) -> usize {
+        let key = u32::from_be_bytes([0, rgb[0], rgb[1], rgb[2]]);
+        // Fibonacci hashing spreads neighbouring colors over shards
+        let hash = key.wrapping_mul(0x9E37_79B1) >> 16;
+        hash as usize & (self.shards.len() - 1)
+    }
+}
+
+impl Default for UnifiedColorCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Cache statistics
+#[derive(Debug, Clone)]
+pub struct CacheStats {
+    /// Current number of entries in cache
+    pub current_size: usize,
+    /// Maximum allowed entries
+    pub max_size: usize,
+    /// Current allocated capacity
+    pub capacity: usize,
+    /// Lookups that found an entry
+    pub hits: u64,
+    /// Lookups that found no entry, including expired ones
+    pub misses: u64,
+    /// Entries evicted to make room
+    pub evictions: u64,
+    /// Entries dropped because they outlived the time to live
+    pub expirations: u64,
+    /// Number of independently locked shards
+    pub shards: usize,
+}
+
+impl CacheStats {
+    /// Fraction of lookups that hit, or 0 before any lookup
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// Characterizations kept per color for classifiers with different naming
+/// settings
+const MAX_PROFILES_PER_ENTRY: usize = 4;
+
+/// Everything cached for one color; the color itself is the shard key
+struct Entry {
+    munsell: MunsellColor,
+    /// Whether `iscc_nbs` has been computed
+    iscc_known: bool,
+    iscc_nbs: Option<Arc<ColorMetadata>>,
+    /// Characterizations keyed by the naming profile of the classifier that
+    /// made them, oldest first
+    characterizations: Vec<(u64, CachedCharacterization)>,
+}
+
+impl Entry {
+    fn new(munsell: MunsellColor, iscc_nbs: Option<Arc<ColorMetadata>>, iscc_known: bool) -> Self {
+        Self {
+            munsell,
+            iscc_known,
+            iscc_nbs,
+            characterizations: Vec::new(),
+        }
+    }
+
+    fn result(&self, rgb: [u8; 3]) -> CachedColorResult {
+        CachedColorResult {
+            rgb,
+            munsell: self.munsell.clone(),
+            iscc_nbs: self.iscc_nbs.as_deref().cloned(),
+        }
+    }
+
+    /// Replace the characterization of a profile, dropping the oldest one
+    /// beyond [`MAX_PROFILES_PER_ENTRY`].
+    fn add_characterization(&mut self, profile: u64, characterization: CachedCharacterization) {
+        self.characterizations.retain(|(p, _)| *p != profile);
+        if self.characterizations.len() >= MAX_PROFILES_PER_ENTRY {
+            self.characterizations.remove(0);
+        }
+        // Most colors are characterized by a single profile
+        self.characterizations.reserve_exact(1);
+        self.characterizations.push((profile, characterization));
+    }
+}
+
+/// A [`ColorCharacterization`] whose names are shared through the cache's
+/// interner
+#[derive(Clone)]
+struct CachedCharacterization {
+    munsell: MunsellSpec,
+    iscc_nbs_number: u16,
+    ambiguous: bool,
+    iscc_base_color: Arc<str>,
+    iscc_extended_name: Arc<str>,
+    modifier: ColorModifier,
+    semantic_matches: Box<[Arc<str>]>,
+    nearest_semantic: Option<(Arc<str>, f64)>,
+    shade: Arc<str>,
+    temperature: Temperature,
+}
+
+impl CachedCharacterization {
+    fn new(characterization: &ColorCharacterization, interner: &Interner) -> Self {
+        Self {
+            munsell: characterization.munsell,
+            iscc_nbs_number: characterization.iscc_nbs_number,
+            ambiguous: characterization.ambiguous,
+            iscc_base_color: interner.str(&characterization.iscc_base_color),
+            iscc_extended_name: interner.str(&characterization.iscc_extended_name),
+            modifier: characterization.modifier,
+            semantic_matches: characterization.semantic_matches.iter().map(|name| interner.str(name)).collect(),
+            nearest_semantic: characterization
+                .nearest_semantic
+                .as_ref()
+                .map(|(name, distance)| (interner.str(name), *distance)),
+            shade: interner.str(&characterization.shade),
+            temperature: characterization.temperature,
+        }
+    }
+
+    fn to_characterization(&self) -> ColorCharacterization {
+        ColorCharacterization {
+            munsell: self.munsell,
+            iscc_nbs_number: self.iscc_nbs_number,
+            ambiguous: self.ambiguous,
+            iscc_base_color: self.iscc_base_color.to_string(),
+            iscc_extended_name: self.iscc_extended_name.to_string(),
+            modifier: self.modifier,
+            semantic_matches: self.semantic_matches.iter().map(|name| name.to_string()).collect(),
+            nearest_semantic: self.nearest_semantic.as_ref().map(|(name, distance)| (name.to_string(), *distance)),
+            shade: self.shade.to_string(),
+            temperature: self.temperature,
+        }
+    }
+}
+
+/// Entry of a shard, linked into its recency list
+struct Node {
+    key: [u8; 3],
+    value: Entry,
+    inserted: Instant,
+    /// Next more recently used entry
+    prev: usize,
+    /// Next less recently used entry
+    next: usize,
+}
+
+/// One LRU shard: a map from colors to slab slots, and a recency list
+/// through the slab from `head` (most recent) to `tail` (least recent)
+struct Shard {
+    map: HashMap<[u8; 3], usize>,
+    nodes: Vec<Node>,
+    free: Vec<usize>,
+    head: usize,
+    tail: usize,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    expirations: u64,
+}
+
+impl Shard {
+    fn new(capacity: usize) -> Self {
+        Self {
+            map: HashMap::new(),
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: NIL,
+            tail: NIL,
+            capacity,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            expirations: 0,
+        }
+    }
+
+    /// Live entry of a color at time `now`, promoted to most recently used;
+    /// expired entries are dropped.
+    fn entry(&mut self, rgb: &[u8; 3], ttl: Option<Duration>, now: Instant) -> Option<&Entry> {
+        let index = *self.map.get(rgb)?;
+
+        if ttl.is_some_and(|ttl| now.saturating_duration_since(self.nodes[index].inserted) > ttl) {
+            self.unlink(index);
+            self.map.remove(rgb);
+            self.free.push(index);
+            self.expirations += 1;
+            return None;
+        }
+
+        self.unlink(index);
+        self.push_front(index);
+        Some(&self.nodes[index].value)
+    }
+
+    /// Update the entry of a color in place, or insert a new one, at time
+    /// `now`. The age of an existing entry restarts only when `update`
+    /// reports that it replaced the result.
+    fn store(
+        &mut self,
+        rgb: [u8; 3],
+        update: impl FnOnce(&mut Entry) -> bool,
+        create: impl FnOnce() -> Entry,
+        now: Instant,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if let Some(&index) = self.map.get(&rgb) {
+            let node = &mut self.nodes[index];
+            if update(&mut node.value) {
+                node.inserted = now;
+            }
+            self.unlink(index);
+            self.push_front(index);
+            return;
+        }
+
+        let result = create();
+
+        let index = if self.map.len() >= self.capacity {
+            // Reuse the least recently used slot
+            let index = self.tail;
+            self.unlink(index);
+            self.map.remove(&self.nodes[index].key);
+            self.evictions += 1;
+            let node = &mut self.nodes[index];
+            node.key = rgb;
+            node.value = result;
+            node.inserted = now;
+            index
+        } else if let Some(index) = self.free.pop() {
+            let node = &mut self.nodes[index];
+            node.key = rgb;
+            node.value = result;
+            node.inserted = now;
+            index
+        } else {
+            self.nodes.push(Node {
+                key: rgb,
+                value: result,
+                inserted: now,
+                prev: NIL,
+                next: NIL,
+            });
+            self.nodes.len() - 1
+        };
+
+        self.map.insert(rgb, index);
+        self.push_front(index);
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+        self.nodes.clear();
+        self.free.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+
+    /// Detach an entry from the recency list.
+    fn unlink(&mut self, index: usize) {
+        let (prev, next) = (self.nodes[index].prev, self.nodes[index].next);
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.nodes[prev].next = next;
+        }
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.nodes[next].prev = prev;
+        }
+    }
+
+    /// Attach a detached entry as the most recently used.
+    fn push_front(&mut self, index: usize) {
+        self.nodes[index].prev = NIL;
+        self.nodes[index].next = self.head;
+        if self.head == NIL {
+            self.tail = index;
+        } else {
+            self.nodes[self.head].prev = index;
+        }
+        self.head = index;
+    }
+}
+
+/// Parse hex color string to RGB
+/// Handles formats: #RGB, #RRGGBB, RGB, RRGGBB (case insensitive)
+pub fn hex_to_rgb(hex: &str) -> Result<[u8; 3], MunsellError> {
+    let hex = hex.trim().trim_start_matches('#').to_uppercase();
+    
+    let rgb = if hex.len() == 3 {
+        // Short form: "F00" -> [255, 0, 0]
+        let r = u8::from_str_radix(&hex[0..1].repeat(2), 16)
+            .map_err(|_| MunsellError::ConversionError { 
+                message: format!("Invalid hex color: {}", hex) 
+            })?;
+        let g = u8::from_str_radix(&hex[1..2].repeat(2), 16)
+            .map_err(|_| MunsellError::ConversionError { 
+                message: format!("Invalid hex color: {}", hex) 
+            })?;
+        let b = u8::from_str_radix(&hex[2..3].repeat(2), 16)
+            .map_err(|_| MunsellError::ConversionError { 
+                message: format!("Invalid hex color: {}", hex) 
+            })?;
+        [r, g, b]
+    } else if hex.len() == 6 {
+        // Long form: "FF0000" -> [255, 0, 0]
+        let r = u8::from_str_radix(&hex[0..2], 16)
+            .map_err(|_| MunsellError::ConversionError { 
+                message: format!("Invalid hex color: {}", hex) 
+            })?;
+        let g = u8::from_str_radix(&hex[2..4], 16)
+            .map_err(|_| MunsellError::ConversionError { 
+                message: format!("Invalid hex color: {}", hex) 
+            })?;
+        let b = u8::from_str_radix(&hex[4..6], 16)
+            .map_err(|_| MunsellError::ConversionError { 
+                message: format!("Invalid hex color: {}", hex) 
+            })?;
+        [r, g, b]
+    } else {
+        return Err(MunsellError::ConversionError { 
+            message: format!("Invalid hex color length: expected 3 or 6 characters, got {}", hex.len()) 
+        });
+    };
+    
+    Ok(rgb)
+}
+
+/// Convert Lab to sRGB [0-255] using palette crate
+/// Uses D65 white point
+pub fn lab_to_rgb(lab: [f64; 3]) -> Result<[u8; 3], MunsellError> {
+    use palette::{Lab, Srgb, white_point::D65, convert::IntoColor};
+    
+    let lab_color = Lab::<D65, f64>::new(lab[0], lab[1], lab[2]);
+    let srgb: Srgb<f64> = lab_color.into_color();
+    
+    Ok([
+        (srgb.red * 255.0).round().clamp(0.0, 255.0) as u8,
+        (srgb.green * 255.0).round().clamp(0.0, 255.0) as u8,
+        (srgb.blue * 255.0).round().clamp(0.0, 255.0) as u8,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_hex_parsing() {
+        // All these should produce the same RGB
+        assert_eq!(hex_to_rgb("#FF0000").unwrap(), [255, 0, 0]);
+        assert_eq!(hex_to_rgb("#ff0000").unwrap(), [255, 0, 0]);
+        assert_eq!(hex_to_rgb("FF0000").unwrap(), [255, 0, 0]);
+        assert_eq!(hex_to_rgb("ff0000").unwrap(), [255, 0, 0]);
+        assert_eq!(hex_to_rgb("#F00").unwrap(), [255, 0, 0]);
+        assert_eq!(hex_to_rgb("#f00").unwrap(), [255, 0, 0]);
+        assert_eq!(hex_to_rgb("F00").unwrap(), [255, 0, 0]);
+        assert_eq!(hex_to_rgb("f00").unwrap(), [255, 0, 0]);
+    }
+    
+    #[test]
+    fn test_cache_fifo_eviction() {
+        let cache = UnifiedColorCache::with_capacity(3);
+        
+        // Create dummy results
+        let result1 = CachedColorResult {
+            rgb: [255, 0, 0],
+            munsell: MunsellColor {
+                hue: Some("5R".to_string()),
+                value: 5.0,
+                chroma: Some(10.0),
+                notation: "5R 5.0/10.0".to_string(),
+            },
+            iscc_nbs: None,
+        };
+        
+        let result2 = result1.clone();
+        let result3 = result1.clone();
+        let result4 = result1.clone();
+        
+        // Fill cache to capacity
+        cache.insert([1, 0, 0], result1.clone());
+        cache.insert([2, 0, 0], result2.clone());
+        cache.insert([3, 0, 0], result3.clone());
+        assert_eq!(cache.len(), 3);
+        
+        // Add one more - should evict the first
+        cache.insert([4, 0, 0], result4.clone());
+        assert_eq!(cache.len(), 3);
+        
+        // First entry should be gone
+        assert!(cache.get(&[1, 0, 0]).is_none());
+        // Others should still be there
+        assert!(cache.get(&[2, 0, 0]).is_some());
+        assert!(cache.get(&[3, 0, 0]).is_some());
+        assert!(cache.get(&[4, 0, 0]).is_some());
+    }
+    
+    #[test]
+    fn test_cache_thread_safety() {
+        use std::thread;
+        
+        let cache = Arc::new(UnifiedColorCache::with_capacity(100));
+        let mut handles = vec![];
+        
+        // Spawn multiple threads that write to cache
+        for i in 0..10 {
+            let cache_clone = Arc::clone(&cache);
+            let handle = thread::spawn(move || {
+                let result = CachedColorResult {
+                    rgb: [i as u8, 0, 0],
+                    munsell: MunsellColor {
+                        hue: Some(format!("{}R", i)),
+                        value: i as f64,
+                        chroma: Some(i as f64),
+                        notation: format!("{}R {}.0/{}.0", i, i, i),
+                    },
+                    iscc_nbs: None,
+                };
+                
+                for j in 0..10 {
+                    let rgb = [(i * 10 + j) as u8, 0, 0];
+                    cache_clone.insert(rgb, result.clone());
+                }
+            });
+            handles.push(handle);
+        }
+        
+        // Wait for all threads to complete
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        
+        // Cache should have entries (exact count depends on timing)
+        assert!(cache.len() > 0);
+        assert!(cache.len() <= 100);
+    }
+    
+    fn dummy_result() -> CachedColorResult {
+        CachedColorResult {
+            rgb: [255, 0, 0],
+            munsell: MunsellColor::from_notation("5R 5.0/10.0").unwrap(),
+            iscc_nbs: None,
+        }
+    }
+
+    #[test]
+    fn test_cache_lru_promotion_and_stats() {
+        let cache = UnifiedColorCache::with_capacity(3);
+        for i in 1..=3 {
+            cache.insert([i, 0, 0], dummy_result());
+        }
+
+        // Reading the oldest entry protects it from the next eviction
+        assert!(cache.get(&[1, 0, 0]).is_some());
+        cache.insert([4, 0, 0], dummy_result());
+        assert!(cache.get(&[1, 0, 0]).is_some());
+        assert!(cache.get(&[2, 0, 0]).is_none());
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (2, 1, 1));
+        assert_eq!((stats.current_size, stats.shards), (3, 1));
+        assert!((stats.hit_rate() - 2.0 / 3.0).abs() < 1e-12);
+
+        // Large caches are sharded but never exceed their capacity
+        let cache = UnifiedColorCache::with_capacity(1000);
+        assert_eq!(cache.stats().shards, 8);
+        for i in 0..=255u8 {
+            for j in 0..8u8 {
+                cache.insert([i, j, 0], dummy_result());
+            }
+        }
+        assert_eq!(cache.len(), 1000);
+        assert_eq!(cache.stats().evictions, 2048 - 1000);
+        assert!(cache.get(&[255, 7, 0]).is_some());
+    }
+
+    #[test]
+    fn test_cache_ttl() {
+        let ttl = Some(Duration::from_secs(60));
+        let start = Instant::now();
+        let later = |secs| start + Duration::from_secs(secs);
+        let munsell_only = || Entry::new(dummy_result().munsell, None, false);
+
+        let mut shard = Shard::new(10);
+        shard.store([1, 2, 3], |_| false, munsell_only, start);
+        assert!(shard.entry(&[1, 2, 3], ttl, later(30)).is_some());
+
+        // Storing what the entry already holds does not renew it
+        shard.store([1, 2, 3], |_| false, munsell_only, later(50));
+        assert!(shard.entry(&[1, 2, 3], ttl, later(61)).is_none());
+        assert!(shard.map.is_empty());
+        assert_eq!(shard.expirations, 1);
+
+        // Replacing the result does
+        shard.store([4, 5, 6], |_| false, munsell_only, start);
+        shard.store([4, 5, 6], |_| true, munsell_only, later(50));
+        assert!(shard.entry(&[4, 5, 6], ttl, later(100)).is_some());
+        assert!(shard.entry(&[4, 5, 6], ttl, later(111)).is_none());
+
+        // Freed slots are reused
+        shard.store([7, 8, 9], |_| false, munsell_only, start);
+        assert_eq!((shard.map.len(), shard.nodes.len()), (1, 1));
+    }
+}
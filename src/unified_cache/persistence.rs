@@ -0,0 +1,946 @@
+//! Cache files: saving caches to disk and precomputing them.
+//!
+//! A cache file starts with a header naming the file format, the crate
+//! version and a fingerprint of the embedded datasets, and ends with a
+//! checksum. Files written by another crate version or from other data are
+//! rejected, so a service never starts warm on stale results.
+//!
+//! Entries are stored compactly: the RGB key and Munsell notation, with
+//! ISCC-NBS metadata and overlay names written the first time they appear
+//! and referenced by index afterwards. Characterizations are kept for
+//! classifiers with the default naming settings, the only ones that are the
+//! same in every process; others are recomputed on demand.
+//!
+//! # Layout
+//!
+//! Integers are little endian and strings are a `u16` byte length followed
+//! by UTF-8.
+//!
+//! | Part    | Contents                                                    |
+//! |---------|-------------------------------------------------------------|
+//! | Header  | `MUNSCACH`, format `u16`, crate version, dataset fingerprint `u64` |
+//! | Entries | `1`, then RGB, notation, ISCC-NBS reference, characterizations |
+//! | Trailer | `0`, entry count `u64`, FNV-1a checksum `u64` of all preceding bytes |
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+
+use rayon::prelude::*;
+
+use super::{
+    CachedCharacterization, Entry, Interner, UnifiedColorCache, CACHE_SIZE, MAX_SHARDS,
+    MIN_SHARD_CAPACITY, NIL,
+};
+use crate::color_names::ColorClassifier;
+use crate::constants::iscc_nbs_colors::ISCC_NBS_COLORS;
+use crate::constants::iscc_nbs_polygons::get_polygon_definitions;
+use crate::constants::maximum_chromas_data::MAXIMUM_CHROMAS;
+use crate::constants::MUNSELL_RENOTATION_DATA;
+use crate::converter::REFERENCE_CSV;
+use crate::error::{MunsellError, Result};
+use crate::iscc::ColorMetadata;
+use crate::screen_correction::ScreenCorrector;
+use crate::semantic_overlay::{OverlayContext, SemanticOverlayRegistry};
+use crate::semantic_overlay_data::get_registry;
+use crate::temperature::TemperatureConfig;
+use crate::types::MunsellColor;
+
+/// First bytes of every cache file
+const MAGIC: &[u8; 8] = b"MUNSCACH";
+
+/// Version of the file layout
+const FORMAT_VERSION: u16 = 1;
+
+/// Marks an entry
+const ENTRY_TAG: u8 = 1;
+
+/// Marks the trailer
+const END_TAG: u8 = 0;
+
+/// ISCC-NBS reference of an entry that has not been classified
+const ISCC_UNKNOWN: u16 = u16::MAX;
+
+/// ISCC-NBS reference of a color without ISCC-NBS metadata
+const ISCC_NONE: u16 = u16::MAX - 1;
+
+/// Overlay name reference of a missing nearest overlay
+const NO_NAME: u16 = u16::MAX;
+
+/// Cache profiles that mean the same naming settings in every process
+const PORTABLE_PROFILES: u64 = 2;
+
+/// Colors classified in parallel between writes when precomputing
+const PRECOMPUTE_CHUNK: usize = 1 << 16;
+
+/// Header of a cache file.
+///
+/// # Examples
+/// ```rust
+/// use munsellspace::unified_cache::CacheFileHeader;
+///
+/// let header = CacheFileHeader::current();
+/// assert!(header.is_current());
+/// assert_eq!(header.crate_version, env!("CARGO_PKG_VERSION"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheFileHeader {
+    /// Version of the file layout
+    pub format_version: u16,
+    /// Version of the crate that wrote the file
+    pub crate_version: String,
+    /// [`dataset_fingerprint`] of the crate that wrote the file
+    pub dataset_fingerprint: u64,
+}
+
+impl CacheFileHeader {
+    /// Header of files written by this build.
+    pub fn current() -> Self {
+        Self {
+            format_version: FORMAT_VERSION,
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            dataset_fingerprint: dataset_fingerprint(),
+        }
+    }
+
+    /// Read the header of a cache file without loading its entries.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not a cache file.
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self> {
+        CacheReader::new(BufReader::new(File::open(path)?)).header()
+    }
+
+    /// Whether this build can load the file: same layout, crate version and
+    /// datasets.
+    pub fn is_current(&self) -> bool {
+        *self == Self::current()
+    }
+}
+
+/// Fingerprint of the embedded datasets that conversions and names depend
+/// on.
+///
+/// Covers the Munsell renotation and maximum chroma data, the sRGB reference
+/// dataset, the ISCC-NBS polygons and names, the Centore semantic overlays
+/// and the default screen correction. Cache files written with another
+/// fingerprint are rejected.
+pub fn dataset_fingerprint() -> u64 {
+    static FINGERPRINT: OnceLock<u64> = OnceLock::new();
+    *FINGERPRINT.get_or_init(|| {
+        let mut hash = Fnv1a::new();
+
+        for ((hue, value, chroma), (x, y, big_y)) in MUNSELL_RENOTATION_DATA {
+            hash.str(hue);
+            for v in [value, chroma, x, y, big_y] {
+                hash.f64(*v);
+            }
+        }
+        for ((hue, value, code), chroma) in MAXIMUM_CHROMAS {
+            hash.f64(*hue);
+            hash.f64(*value);
+            hash.update(&[*code]);
+            hash.f64(*chroma);
+        }
+        hash.str(REFERENCE_CSV);
+
+        for polygon in get_polygon_definitions() {
+            hash.update(&polygon.color_number.to_le_bytes());
+            hash.update(&[polygon.polygon_group]);
+            hash.str(polygon.hue1);
+            hash.str(polygon.hue2);
+            for point in polygon.points {
+                hash.f64(point.chroma);
+                hash.f64(point.value);
+            }
+        }
+        for color in ISCC_NBS_COLORS {
+            hash.update(&color.color_number.to_le_bytes());
+            hash.str(color.iscc_nbs_color_name);
+            hash.str(color.iscc_nbs_formatter.unwrap_or(""));
+            hash.str(color.alt_color_name);
+            hash.str(color.color_shade);
+        }
+
+        for overlay in get_registry().all() {
+            hash.str(&overlay.name);
+            for vertex in &overlay.polyhedron.vertices {
+                hash.f64(vertex.x);
+                hash.f64(vertex.y);
+                hash.f64(vertex.z);
+            }
+        }
+        let corrector = ScreenCorrector::new();
+        for v in [corrector.value_correction, corrector.chroma_correction] {
+            hash.f64(v);
+        }
+        for c in corrector.hue_coefficients.iter() {
+            hash.f64(*c);
+        }
+
+        hash.finish()
+    })
+}
+
+/// Cache profile of a classifier's naming settings.
+///
+/// The default settings have the portable profiles 0 (physical overlay
+/// context) and 1 (screen overlay context). Other settings are identified by
+/// a hash of everything that changes a characterization, so classifiers
+/// with equal settings share cached characterizations.
+pub(crate) fn naming_profile(
+    overlays: Option<&SemanticOverlayRegistry>,
+    context: OverlayContext,
+    temperature: &TemperatureConfig,
+) -> u64 {
+    if overlays.is_none() && *temperature == TemperatureConfig::default() {
+        return match context {
+            OverlayContext::Physical => 0,
+            OverlayContext::Screen => 1,
+        };
+    }
+
+    let mut hash = Fnv1a::new();
+    hash.update(&[context as u8]);
+    // No registry means the built-in overlays, which the datasets fix
+    hash.update(&[u8::from(overlays.is_some())]);
+    for overlay in overlays.map_or(&[][..], SemanticOverlayRegistry::all) {
+        hash.str(&overlay.name);
+        for vertex in &overlay.polyhedron.vertices {
+            hash.f64(vertex.x);
+            hash.f64(vertex.y);
+            hash.f64(vertex.z);
+        }
+        for v in [overlay.centroid.hue_number, overlay.centroid.value, overlay.centroid.chroma] {
+            hash.f64(v);
+        }
+    }
+    let TemperatureConfig {
+        warm_pole,
+        gray_chroma,
+        full_chroma,
+        neutral_threshold,
+        warm_threshold,
+        gray_threshold,
+    } = *temperature;
+    for v in [warm_pole, gray_chroma, full_chroma, neutral_threshold, warm_threshold, gray_threshold] {
+        hash.f64(v);
+    }
+    hash.finish().max(PORTABLE_PROFILES)
+}
+
+/// Every 24-bit sRGB color, red-major: 16,777,216 colors.
+///
+/// Pass it to [`ColorClassifier::precompute_cache_file`] to precompute the
+/// whole cube. The cache file takes about 650 MB, but a loaded cache holds
+/// about 420 bytes per color, some 7 GB for the cube; services with less
+/// memory should precompute the colors they actually serve.
+pub fn srgb_cube() -> impl Iterator<Item = [u8; 3]> {
+    (0..=0xFF_FFFFu32).map(|i| {
+        let [_, r, g, b] = i.to_be_bytes();
+        [r, g, b]
+    })
+}
+
+impl UnifiedColorCache {
+    /// Save the cache to a file, returning the number of entries written.
+    ///
+    /// Entries are written shard by shard, each shard from least to most
+    /// recently used, so loading the file restores the recency that eviction
+    /// uses; shards evict independently, so there is no order between them.
+    /// Expired entries are skipped.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::{ColorClassifier, UnifiedColorCache};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cache = UnifiedColorCache::new();
+    /// let classifier = ColorClassifier::new()?.with_cache(cache.clone());
+    /// classifier.classify_srgb([180, 80, 60])?;
+    ///
+    /// let path = std::env::temp_dir().join("munsellspace-doc-save.cache");
+    /// assert_eq!(cache.save(&path)?, 1);
+    ///
+    /// // A later process starts warm
+    /// let restored = UnifiedColorCache::load(&path)?;
+    /// let classifier = ColorClassifier::new()?.with_cache(restored);
+    /// classifier.classify_srgb([180, 80, 60])?;
+    /// assert_eq!(classifier.cache_stats().unwrap().hits, 1);
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<usize> {
+        self.write_to(File::create(path)?)
+    }
+
+    /// Write the cache in the cache file format; see [`save`](Self::save).
+    ///
+    /// # Errors
+    /// Returns an error if writing fails.
+    pub fn write_to<W: Write>(&self, writer: W) -> Result<usize> {
+        let mut writer = CacheWriter::new(BufWriter::new(writer))?;
+
+        // Hold every shard so the snapshot is consistent
+        let shards: Vec<_> = self.shards.iter().map(|shard| shard.lock().unwrap()).collect();
+        let now = Instant::now();
+        for shard in &shards {
+            let mut index = shard.tail;
+            while index != NIL {
+                let node = &shard.nodes[index];
+                index = node.prev;
+                if self.ttl.is_some_and(|ttl| now.saturating_duration_since(node.inserted) > ttl) {
+                    continue;
+                }
+                writer.entry(node.key, &node.value)?;
+            }
+        }
+        drop(shards);
+
+        writer.finish()
+    }
+
+    /// Load a cache saved with [`save`](Self::save) or
+    /// [`ColorClassifier::precompute_cache_file`].
+    ///
+    /// The cache holds every entry of the file, and at least the default 500
+    /// entries. Statistics start at zero.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, is corrupt, or was
+    /// written by another crate version or from other datasets; see
+    /// [`CacheFileHeader::is_current`].
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::read_from(File::open(path)?)
+    }
+
+    /// Read a cache in the cache file format; see [`load`](Self::load).
+    ///
+    /// # Errors
+    /// Returns an error if reading fails or the data is not a current cache
+    /// file.
+    pub fn read_from<R: Read>(reader: R) -> Result<Self> {
+        let mut reader = CacheReader::new(BufReader::new(reader));
+        let header = reader.header()?;
+        if !header.is_current() {
+            let current = CacheFileHeader::current();
+            return Err(invalid(format!(
+                "written by munsellspace {} (format {}, datasets {:016x}), \
+                 expected {} (format {}, datasets {:016x})",
+                header.crate_version,
+                header.format_version,
+                header.dataset_fingerprint,
+                current.crate_version,
+                current.format_version,
+                current.dataset_fingerprint,
+            )));
+        }
+
+        // Buffer entries until there are enough for every shard, then stream
+        // them into unbounded shards; the count is only known at the trailer
+        let interner = Arc::new(Interner::default());
+        let mut buffered = Vec::new();
+        let mut streaming: Option<Self> = None;
+        let mut entries = 0;
+        while let Some((rgb, entry)) = reader.entry(&interner)? {
+            entries += 1;
+            match &streaming {
+                Some(cache) => cache.store(rgb, |_| false, || entry),
+                None => {
+                    buffered.push((rgb, entry));
+                    if buffered.len() == MAX_SHARDS * MIN_SHARD_CAPACITY {
+                        let cache = Self::with_capacity(usize::MAX);
+                        for (rgb, entry) in buffered.drain(..) {
+                            cache.store(rgb, |_| false, || entry);
+                        }
+                        streaming = Some(cache);
+                    }
+                }
+            }
+        }
+        reader.finish(entries)?;
+
+        let capacity = (entries as usize).max(CACHE_SIZE);
+        let mut cache = streaming.unwrap_or_else(|| Self::with_capacity(capacity));
+        for (rgb, entry) in buffered {
+            cache.store(rgb, |_| false, || entry);
+        }
+
+        // Colors do not hash evenly, so make room in fuller shards
+        let sized = Self::with_capacity(capacity);
+        for (shard, share) in cache.shards.iter().zip(sized.shards.iter()) {
+            let mut shard = shard.lock().unwrap();
+            shard.capacity = share.lock().unwrap().capacity.max(shard.map.len());
+            // Drop the slack left by growing while streaming
+            shard.nodes.shrink_to_fit();
+            shard.map.shrink_to_fit();
+        }
+        cache.max_size = cache.shards.iter().map(|shard| shard.lock().unwrap().capacity).sum();
+        cache.interner = interner;
+        Ok(cache)
+    }
+}
+
+impl ColorClassifier {
+    /// Precompute a cache file for a list of colors.
+    ///
+    /// Colors are converted and classified in parallel and streamed to the
+    /// file, so even the whole [`srgb_cube`] can be precomputed without
+    /// holding it in memory. Load the file with [`UnifiedColorCache::load`].
+    ///
+    /// Characterizations are stored only for classifiers with default
+    /// overlays and temperature thresholds; with custom settings the file
+    /// still saves the Munsell conversions and ISCC-NBS classifications.
+    /// Colors that cannot be converted are skipped. Returns the number of
+    /// entries written.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::{ColorClassifier, UnifiedColorCache};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let classifier = ColorClassifier::new()?;
+    /// let palette = [[180, 80, 60], [0, 0, 128], [250, 240, 230]];
+    ///
+    /// let path = std::env::temp_dir().join("munsellspace-doc-precompute.cache");
+    /// assert_eq!(classifier.precompute_cache_file(&path, palette)?, 3);
+    ///
+    /// let cache = UnifiedColorCache::load(&path)?;
+    /// assert_eq!(cache.len(), 3);
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn precompute_cache_file<P, I>(&self, path: P, colors: I) -> Result<usize>
+    where
+        P: AsRef<Path>,
+        I: IntoIterator<Item = [u8; 3]>,
+    {
+        let mut writer = CacheWriter::new(BufWriter::new(File::create(path)?))?;
+        let profile = self.cache_profile();
+        let interner = Interner::default();
+
+        let mut colors = colors.into_iter();
+        loop {
+            let chunk: Vec<[u8; 3]> = colors.by_ref().take(PRECOMPUTE_CHUNK).collect();
+            if chunk.is_empty() {
+                break;
+            }
+            let results: Vec<_> = chunk
+                .par_iter()
+                .map(|&rgb| (rgb, self.characterize_srgb_uncached(rgb)))
+                .collect();
+
+            for (rgb, result) in results {
+                let Ok((munsell, naming)) = result else {
+                    continue;
+                };
+                let entry = match naming {
+                    Some((metadata, characterization)) => {
+                        let metadata = interner.metadata(&metadata);
+                        let mut entry = Entry::new(munsell, Some(metadata), true);
+                        let characterization = CachedCharacterization::new(&characterization, &interner);
+                        entry.add_characterization(profile, characterization);
+                        entry
+                    }
+                    None => Entry::new(munsell, None, true),
+                };
+                writer.entry(rgb, &entry)?;
+            }
+        }
+
+        writer.finish()
+    }
+}
+
+/// Error for a malformed or incompatible cache file.
+fn invalid(message: impl std::fmt::Display) -> MunsellError {
+    MunsellError::IoError {
+        message: format!("Invalid cache file: {}", message),
+    }
+}
+
+/// 64-bit FNV-1a hash
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn f64(&mut self, value: f64) {
+        self.update(&value.to_bits().to_le_bytes());
+    }
+
+    fn str(&mut self, value: &str) {
+        self.update(&(value.len() as u64).to_le_bytes());
+        self.update(value.as_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Key of interned ISCC-NBS metadata
+type MetadataKey = (String, Option<String>, String, String);
+
+fn metadata_key(metadata: &ColorMetadata) -> MetadataKey {
+    (
+        metadata.iscc_nbs_color_name.clone(),
+        metadata.iscc_nbs_formatter.clone(),
+        metadata.alt_color_name.clone(),
+        metadata.color_shade.clone(),
+    )
+}
+
+/// Streaming writer of the cache file format
+struct CacheWriter<W: Write> {
+    out: W,
+    checksum: Fnv1a,
+    metadata: HashMap<MetadataKey, u16>,
+    names: HashMap<String, u16>,
+    entries: u64,
+}
+
+impl<W: Write> CacheWriter<W> {
+    fn new(out: W) -> Result<Self> {
+        let mut writer = Self {
+            out,
+            checksum: Fnv1a::new(),
+            metadata: HashMap::new(),
+            names: HashMap::new(),
+            entries: 0,
+        };
+        let header = CacheFileHeader::current();
+        writer.bytes(MAGIC)?;
+        writer.bytes(&header.format_version.to_le_bytes())?;
+        writer.str(&header.crate_version)?;
+        writer.bytes(&header.dataset_fingerprint.to_le_bytes())?;
+        Ok(writer)
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        self.checksum.update(bytes);
+        self.out.write_all(bytes)?;
+        Ok(())
+    }
+
+    fn str(&mut self, value: &str) -> Result<()> {
+        let len = u16::try_from(value.len()).map_err(|_| invalid("string too long"))?;
+        self.bytes(&len.to_le_bytes())?;
+        self.bytes(value.as_bytes())
+    }
+
+    fn entry(&mut self, rgb: [u8; 3], entry: &Entry) -> Result<()> {
+        self.bytes(&[ENTRY_TAG])?;
+        self.bytes(&rgb)?;
+        self.str(&entry.munsell.notation)?;
+
+        match (&entry.iscc_nbs, entry.iscc_known) {
+            (_, false) => self.bytes(&ISCC_UNKNOWN.to_le_bytes())?,
+            (None, true) => self.bytes(&ISCC_NONE.to_le_bytes())?,
+            (Some(metadata), true) => self.metadata(metadata)?,
+        }
+
+        // Characterizations are rebuilt from the metadata when loading
+        let portable: Vec<_> = entry
+            .characterizations
+            .iter()
+            .filter(|(profile, _)| *profile < PORTABLE_PROFILES && entry.iscc_nbs.is_some())
+            .collect();
+        self.bytes(&[portable.len() as u8])?;
+        for (profile, characterization) in portable {
+            self.bytes(&[*profile as u8])?;
+            self.bytes(&characterization.iscc_nbs_number.to_le_bytes())?;
+            self.bytes(&[u8::from(characterization.ambiguous)])?;
+            self.bytes(&[characterization.semantic_matches.len() as u8])?;
+            for name in characterization.semantic_matches.iter() {
+                self.name(name)?;
+            }
+            match &characterization.nearest_semantic {
+                Some((name, distance)) => {
+                    self.name(name)?;
+                    self.bytes(&distance.to_le_bytes())?;
+                }
+                None => self.bytes(&NO_NAME.to_le_bytes())?,
+            }
+        }
+
+        self.entries += 1;
+        Ok(())
+    }
+
+    /// Reference ISCC-NBS metadata, defining it on first use.
+    fn metadata(&mut self, metadata: &ColorMetadata) -> Result<()> {
+        let key = metadata_key(metadata);
+        if let Some(&index) = self.metadata.get(&key) {
+            return self.bytes(&index.to_le_bytes());
+        }
+
+        let index = u16::try_from(self.metadata.len())
+            .ok()
+            .filter(|&index| index < ISCC_NONE)
+            .ok_or_else(|| invalid("too many distinct ISCC-NBS categories"))?;
+        self.bytes(&index.to_le_bytes())?;
+        self.str(&metadata.iscc_nbs_color_name)?;
+        match &metadata.iscc_nbs_formatter {
+            Some(formatter) => {
+                self.bytes(&[1])?;
+                self.str(formatter)?;
+            }
+            None => self.bytes(&[0])?,
+        }
+        self.str(&metadata.alt_color_name)?;
+        self.str(&metadata.color_shade)?;
+        self.metadata.insert(key, index);
+        Ok(())
+    }
+
+    /// Reference an overlay name, defining it on first use.
+    fn name(&mut self, name: &str) -> Result<()> {
+        if let Some(&index) = self.names.get(name) {
+            return self.bytes(&index.to_le_bytes());
+        }
+
+        let index = u16::try_from(self.names.len())
+            .ok()
+            .filter(|&index| index < NO_NAME)
+            .ok_or_else(|| invalid("too many distinct overlay names"))?;
+        self.bytes(&index.to_le_bytes())?;
+        self.str(name)?;
+        self.names.insert(name.to_string(), index);
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<usize> {
+        self.bytes(&[END_TAG])?;
+        let entries = self.entries;
+        self.bytes(&entries.to_le_bytes())?;
+        let checksum = self.checksum.finish();
+        self.out.write_all(&checksum.to_le_bytes())?;
+        self.out.flush()?;
+        Ok(entries as usize)
+    }
+}
+
+/// Reader of the cache file format
+///
+/// Metadata and overlay names are decoded once per definition and shared by
+/// the entries referencing them.
+struct CacheReader<R: Read> {
+    input: R,
+    checksum: Fnv1a,
+    metadata: Vec<Arc<ColorMetadata>>,
+    names: Vec<String>,
+}
+
+impl<R: Read> CacheReader<R> {
+    fn new(input: R) -> Self {
+        Self {
+            input,
+            checksum: Fnv1a::new(),
+            metadata: Vec::new(),
+            names: Vec::new(),
+        }
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut buf = [0; N];
+        self.input.read_exact(&mut buf).map_err(|e| invalid(format!("truncated ({})", e)))?;
+        self.checksum.update(&buf);
+        Ok(buf)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.bytes()?))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.bytes()?))
+    }
+
+    fn str(&mut self) -> Result<String> {
+        let len = self.u16()? as usize;
+        let mut buf = vec![0; len];
+        self.input.read_exact(&mut buf).map_err(|e| invalid(format!("truncated ({})", e)))?;
+        self.checksum.update(&buf);
+        String::from_utf8(buf).map_err(|_| invalid("string is not UTF-8"))
+    }
+
+    fn header(&mut self) -> Result<CacheFileHeader> {
+        if &self.bytes::<8>()? != MAGIC {
+            return Err(invalid("not a MunsellSpace cache file"));
+        }
+        let format_version = self.u16()?;
+        if format_version != FORMAT_VERSION {
+            return Err(invalid(format!("unsupported format version {}", format_version)));
+        }
+        Ok(CacheFileHeader {
+            format_version,
+            crate_version: self.str()?,
+            dataset_fingerprint: self.u64()?,
+        })
+    }
+
+    /// Next color and entry, or `None` at the trailer; shared values come
+    /// from `interner`.
+    fn entry(&mut self, interner: &Interner) -> Result<Option<([u8; 3], Entry)>> {
+        match self.u8()? {
+            END_TAG => return Ok(None),
+            ENTRY_TAG => {}
+            tag => return Err(invalid(format!("unexpected tag {}", tag))),
+        }
+
+        let rgb = self.bytes::<3>()?;
+        let notation = self.str()?;
+        let mut munsell = MunsellColor::from_notation(&notation).map_err(invalid)?;
+        munsell.notation = notation;
+
+        let (iscc_known, iscc_nbs) = match self.u16()? {
+            ISCC_UNKNOWN => (false, None),
+            ISCC_NONE => (true, None),
+            index => (true, Some(self.metadata(index, interner)?)),
+        };
+
+        let count = self.u8()?;
+        let mut characterizations = Vec::with_capacity(count.into());
+        for _ in 0..count {
+            let profile = u64::from(self.u8()?);
+            let iscc_nbs_number = self.u16()?;
+            let ambiguous = self.u8()? != 0;
+            let semantic_matches = (0..self.u8()?)
+                .map(|_| {
+                    let index = self.u16()?;
+                    self.name(index)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let nearest = match self.u16()? {
+                NO_NAME => None,
+                index => {
+                    let name = self.name(index)?;
+                    Some((name, f64::from_le_bytes(self.bytes()?)))
+                }
+            };
+
+            let metadata = iscc_nbs
+                .as_ref()
+                .ok_or_else(|| invalid("characterization without ISCC-NBS metadata"))?;
+            let characterization = ColorClassifier::assemble_characterization(
+                &munsell,
+                iscc_nbs_number,
+                metadata,
+                ambiguous,
+                semantic_matches,
+                nearest,
+                &TemperatureConfig::default(),
+            );
+            characterizations.push((profile, CachedCharacterization::new(&characterization, interner)));
+        }
+
+        let mut entry = Entry::new(munsell, iscc_nbs, iscc_known);
+        entry.characterizations = characterizations;
+        Ok(Some((rgb, entry)))
+    }
+
+    /// Resolve a metadata reference, reading its definition on first use.
+    fn metadata(&mut self, index: u16, interner: &Interner) -> Result<Arc<ColorMetadata>> {
+        let index = index as usize;
+        if index == self.metadata.len() {
+            let iscc_nbs_color_name = self.str()?;
+            let iscc_nbs_formatter = match self.u8()? {
+                0 => None,
+                _ => Some(self.str()?),
+            };
+            let alt_color_name = self.str()?;
+            let color_shade = self.str()?;
+            self.metadata.push(interner.metadata(&ColorMetadata {
+                iscc_nbs_color_name,
+                iscc_nbs_formatter,
+                alt_color_name,
+                color_shade,
+            }));
+        }
+        self.metadata
+            .get(index)
+            .cloned()
+            .ok_or_else(|| invalid(format!("undefined ISCC-NBS reference {}", index)))
+    }
+
+    /// Resolve an overlay name reference, reading its definition on first use.
+    fn name(&mut self, index: u16) -> Result<String> {
+        let index = index as usize;
+        if index == self.names.len() {
+            let name = self.str()?;
+            self.names.push(name);
+        }
+        self.names
+            .get(index)
+            .cloned()
+            .ok_or_else(|| invalid(format!("undefined overlay reference {}", index)))
+    }
+
+    /// Check the entry count and checksum of the trailer.
+    fn finish(mut self, entries: u64) -> Result<()> {
+        let count = self.u64()?;
+        let expected = self.checksum.finish();
+        let mut checksum = [0; 8];
+        self.input
+            .read_exact(&mut checksum)
+            .map_err(|e| invalid(format!("truncated ({})", e)))?;
+
+        if u64::from_le_bytes(checksum) != expected {
+            return Err(invalid("checksum mismatch"));
+        }
+        if count != entries {
+            return Err(invalid(format!("expected {} entries, found {}", count, entries)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("munsellspace-{}-{}.cache", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_cache_file_round_trip() {
+        let cache = UnifiedColorCache::new();
+        let classifier = ColorClassifier::new().unwrap().with_cache(cache.clone());
+        let converter = crate::MunsellConverter::new().unwrap().with_cache(cache.clone());
+
+        let named = [[180, 80, 60], [0, 0, 128], [128, 128, 128], [255, 255, 255]];
+        for rgb in named {
+            classifier.characterize_srgb(rgb).unwrap();
+        }
+        converter.srgb_to_munsell([10, 200, 90]).unwrap();
+
+        let mut bytes = Vec::new();
+        assert_eq!(cache.write_to(&mut bytes).unwrap(), 5);
+        let restored = UnifiedColorCache::read_from(bytes.as_slice()).unwrap();
+        assert_eq!(restored.len(), 5);
+        assert!(restored.get(&[10, 200, 90]).is_none());
+        assert!(restored.get_munsell(&[10, 200, 90]).is_some());
+
+        // Restored characterizations equal freshly computed ones
+        let plain = ColorClassifier::new().unwrap();
+        for rgb in named {
+            let loaded = restored.get_characterization(&rgb, 0).unwrap();
+            let fresh = plain.characterize_srgb(rgb).unwrap();
+            assert_eq!(loaded.munsell, fresh.munsell);
+            assert_eq!(loaded.iscc_nbs_number, fresh.iscc_nbs_number);
+            assert_eq!(loaded.ambiguous, fresh.ambiguous);
+            assert_eq!(loaded.modifier, fresh.modifier);
+            assert_eq!(loaded.semantic_matches, fresh.semantic_matches);
+            assert_eq!(loaded.nearest_semantic, fresh.nearest_semantic);
+            assert_eq!(loaded.temperature, fresh.temperature);
+            assert_eq!(loaded.describe(&Default::default()), fresh.describe(&Default::default()));
+        }
+        assert_eq!(
+            restored.get(&[255, 255, 255]).unwrap().munsell,
+            cache.get(&[255, 255, 255]).unwrap().munsell
+        );
+
+        // Corruption and stale headers are rejected
+        let mut corrupt = bytes.clone();
+        let middle = corrupt.len() / 2;
+        corrupt[middle] ^= 0x40;
+        assert!(UnifiedColorCache::read_from(corrupt.as_slice()).is_err());
+        assert!(UnifiedColorCache::read_from(&bytes[..bytes.len() - 4]).is_err());
+
+        let mut stale = bytes.clone();
+        stale[10 + 2] ^= 1; // first byte of the crate version
+        let Err(error) = UnifiedColorCache::read_from(stale.as_slice()) else {
+            panic!("stale cache file was accepted");
+        };
+        assert!(error.to_string().contains("written by munsellspace"), "{}", error);
+    }
+
+    #[test]
+    fn test_load_rejects_damaged_files() {
+        let path = temp_path("damaged");
+        let cache = UnifiedColorCache::new();
+        let classifier = ColorClassifier::new().unwrap().with_cache(cache.clone());
+        for rgb in [[180, 80, 60], [0, 0, 128], [128, 128, 128]] {
+            classifier.characterize_srgb(rgb).unwrap();
+        }
+        cache.save(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(UnifiedColorCache::load(&path).unwrap().len(), 3);
+
+        let load_error = |bytes: &[u8]| {
+            std::fs::write(&path, bytes).unwrap();
+            match UnifiedColorCache::load(&path) {
+                Ok(_) => panic!("damaged cache file was accepted"),
+                Err(error) => error.to_string(),
+            }
+        };
+
+        let error = load_error(&bytes[..bytes.len() / 2]);
+        assert!(error.contains("truncated"), "{}", error);
+
+        let mut corrupt = bytes.clone();
+        corrupt[bytes.len() - 20] ^= 0x40;
+        assert!(load_error(&corrupt).contains("Invalid cache file"));
+
+        // Files from other datasets are rejected even when intact
+        let mut other_data = bytes.clone();
+        let fingerprint = MAGIC.len() + 2 + 2 + env!("CARGO_PKG_VERSION").len();
+        other_data[fingerprint] ^= 1;
+        let error = load_error(&other_data);
+        assert!(error.contains("datasets"), "{}", error);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_precompute_cache_file() {
+        let path = temp_path("precompute");
+        let classifier = ColorClassifier::new().unwrap();
+        let colors: Vec<[u8; 3]> = srgb_cube().step_by(0x10_3050).collect();
+
+        let written = classifier.precompute_cache_file(&path, colors.iter().copied()).unwrap();
+        assert_eq!(written, colors.len());
+        assert!(CacheFileHeader::read(&path).unwrap().is_current());
+
+        let cache = UnifiedColorCache::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let warm = ColorClassifier::new().unwrap().with_cache(cache);
+        for &rgb in &colors {
+            assert_eq!(
+                warm.classify_srgb(rgb).unwrap().standard_descriptor(),
+                classifier.classify_srgb(rgb).unwrap().standard_descriptor()
+            );
+        }
+        let stats = warm.cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses), (colors.len() as u64, 0));
+
+        assert_eq!(srgb_cube().count(), 1 << 24);
+        assert_eq!(srgb_cube().nth(0x01_02_03), Some([1, 2, 3]));
+    }
+}
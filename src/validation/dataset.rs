@@ -0,0 +1,229 @@
+//! Validation datasets and their CSV and JSON formats.
+
+use std::io::Read;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::converter::REFERENCE_CSV;
+use crate::error::{MunsellError, Result};
+use crate::types::MunsellColor;
+use crate::unified_cache::hex_to_rgb;
+
+/// One validation sample: an input color and the expected results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationSample {
+    /// Input sRGB color
+    pub rgb: [u8; 3],
+    /// Expected Munsell notation
+    pub munsell: String,
+    /// Expected ISCC-NBS name with its modifier, such as `"vivid red"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iscc_nbs_name: Option<String>,
+}
+
+/// Colors with their expected Munsell notation and, optionally, their
+/// expected ISCC-NBS name.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ValidationDataset {
+    /// Samples, in file order
+    pub samples: Vec<ValidationSample>,
+}
+
+/// JSON sample, with the color given as `rgb` or `hex`.
+#[derive(Deserialize)]
+struct JsonSample {
+    #[serde(default)]
+    rgb: Option<[u8; 3]>,
+    #[serde(default)]
+    hex: Option<String>,
+    munsell: String,
+    #[serde(default)]
+    iscc_nbs_name: Option<String>,
+}
+
+/// CSV row, with the color given as `R`, `G`, `B` columns or a `hex` column.
+#[derive(Deserialize)]
+struct CsvSample {
+    #[serde(default, alias = "R")]
+    r: Option<u8>,
+    #[serde(default, alias = "G")]
+    g: Option<u8>,
+    #[serde(default, alias = "B")]
+    b: Option<u8>,
+    #[serde(default)]
+    hex: Option<String>,
+    #[serde(alias = "Munsell", alias = "Munsell Colour", alias = "Munsell Color")]
+    munsell: String,
+    #[serde(default)]
+    iscc_nbs_name: Option<String>,
+}
+
+impl ValidationDataset {
+    /// Create a dataset from samples.
+    pub fn new(samples: Vec<ValidationSample>) -> Self {
+        Self { samples }
+    }
+
+    /// The embedded sRGB to Munsell reference dataset, without names.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::ReferenceDataError`] if the embedded data is
+    /// invalid.
+    pub fn reference() -> Result<Self> {
+        Self::from_csv_reader(REFERENCE_CSV.as_bytes())
+    }
+
+    /// Number of samples.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether the dataset has no samples.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Load a dataset from a JSON array of samples.
+    ///
+    /// Each sample gives its color as `rgb` (`[r, g, b]`) or `hex`, the
+    /// expected `munsell` notation and an optional `iscc_nbs_name`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::validation::ValidationDataset;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dataset = ValidationDataset::from_json_str(r##"[
+    ///     {"rgb": [255, 0, 0], "munsell": "7.9R 5.2/20.5", "iscc_nbs_name": "vivid red"},
+    ///     {"hex": "#808080", "munsell": "N 5.4"}
+    /// ]"##)?;
+    /// assert_eq!(dataset.len(), 2);
+    /// assert_eq!(dataset.samples[1].rgb, [128, 128, 128]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::ReferenceDataError`] for invalid JSON, a
+    /// sample without exactly one of `rgb` and `hex`, or an invalid notation.
+    pub fn from_json_str(json: &str) -> Result<Self> {
+        let samples: Vec<JsonSample> = serde_json::from_str(json).map_err(json_error)?;
+        Self::from_json_samples(samples)
+    }
+
+    /// Load a dataset in the JSON format from a reader.
+    ///
+    /// # Errors
+    /// As [`from_json_str`](Self::from_json_str).
+    pub fn from_json_reader<R: Read>(reader: R) -> Result<Self> {
+        let samples: Vec<JsonSample> = serde_json::from_reader(reader).map_err(json_error)?;
+        Self::from_json_samples(samples)
+    }
+
+    /// Load a dataset in the CSV format from a reader.
+    ///
+    /// The header names the columns: `R`, `G` and `B` or `hex` for the
+    /// color, `Munsell` (or `munsell`, `Munsell Colour`) for the expected
+    /// notation, and an optional `iscc_nbs_name`. The embedded reference
+    /// dataset is in this format.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::ReferenceDataError`] for an invalid row.
+    pub fn from_csv_reader<R: Read>(reader: R) -> Result<Self> {
+        let mut csv_reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader);
+        let mut samples = Vec::new();
+        for (line, row) in csv_reader.deserialize::<CsvSample>().enumerate() {
+            let row = row.map_err(|e| MunsellError::ReferenceDataError {
+                message: format!("Validation CSV row {}: {}", line + 2, e),
+            })?;
+            let rgb = match (row.r, row.g, row.b) {
+                (Some(r), Some(g), Some(b)) => Some([r, g, b]),
+                _ => None,
+            };
+            let sample = to_sample(rgb, row.hex, row.munsell, row.iscc_nbs_name)
+                .map_err(|reason| MunsellError::ReferenceDataError {
+                    message: format!("Validation CSV row {}: {}", line + 2, reason),
+                })?;
+            samples.push(sample);
+        }
+        Ok(Self::new(samples))
+    }
+
+    /// Load a dataset from a `.json` or `.csv` file, chosen by extension.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::IoError`] if the file cannot be read, or
+    /// [`MunsellError::ReferenceDataError`] for an unsupported extension or
+    /// invalid data.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_lowercase);
+        let file = std::fs::File::open(path)?;
+
+        match extension.as_deref() {
+            Some("json") => Self::from_json_reader(std::io::BufReader::new(file)),
+            Some("csv") => Self::from_csv_reader(file),
+            _ => Err(MunsellError::ReferenceDataError {
+                message: format!(
+                    "Unsupported validation file '{}': expected .json or .csv",
+                    path.display()
+                ),
+            }),
+        }
+    }
+
+    /// Internal: check and convert JSON samples.
+    fn from_json_samples(samples: Vec<JsonSample>) -> Result<Self> {
+        samples
+            .into_iter()
+            .enumerate()
+            .map(|(index, sample)| {
+                to_sample(sample.rgb, sample.hex, sample.munsell, sample.iscc_nbs_name).map_err(
+                    |reason| MunsellError::ReferenceDataError {
+                        message: format!("Validation sample {}: {}", index, reason),
+                    },
+                )
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Self::new)
+    }
+}
+
+/// Build a sample, checking that the color is given once and the notation
+/// parses.
+fn to_sample(
+    rgb: Option<[u8; 3]>,
+    hex: Option<String>,
+    munsell: String,
+    iscc_nbs_name: Option<String>,
+) -> std::result::Result<ValidationSample, String> {
+    let rgb = match (rgb, hex.filter(|h| !h.is_empty())) {
+        (Some(rgb), None) => rgb,
+        (None, Some(hex)) => {
+            hex_to_rgb(&hex).map_err(|_| format!("invalid hex color '{}'", hex))?
+        }
+        (Some(_), Some(_)) => return Err("give the color as RGB or hex, not both".to_string()),
+        (None, None) => return Err("missing color".to_string()),
+    };
+    let munsell = munsell.trim().to_string();
+    MunsellColor::from_notation(&munsell)
+        .map_err(|e| format!("invalid Munsell notation '{}': {}", munsell, e))?;
+
+    Ok(ValidationSample {
+        rgb,
+        munsell,
+        iscc_nbs_name: iscc_nbs_name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty()),
+    })
+}
+
+fn json_error(error: serde_json::Error) -> MunsellError {
+    MunsellError::ReferenceDataError {
+        message: format!("Invalid validation JSON: {}", error),
+    }
+}
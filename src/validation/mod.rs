@@ -0,0 +1,384 @@
+//! Accuracy validation against user-supplied datasets.
+//!
+//! A [`ValidationDataset`] lists input colors with their expected Munsell
+//! notation and, optionally, their expected ISCC-NBS name. It is loaded from
+//! CSV or JSON; [`ValidationDataset::reference`] is the embedded reference
+//! dataset. [`AccuracyValidator`] converts every sample and reports the
+//! distributions of hue, value, chroma and CIEDE2000 errors, the worst cases,
+//! the samples that failed, and which names were confused.
+//!
+//! Reports are serializable, so accuracy on a reference set can be tracked
+//! across releases.
+//!
+//! # Example
+//!
+//! ```rust
+//! use munsellspace::validation::{AccuracyValidator, ValidationDataset};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let dataset = ValidationDataset::from_json_str(r##"[
+//!     {"rgb": [255, 0, 0], "munsell": "7.9R 5.2/20.5", "iscc_nbs_name": "vivid reddish orange"},
+//!     {"hex": "#4682B4", "munsell": "2.5PB 5.0/8.0", "iscc_nbs_name": "moderate blue"},
+//!     {"rgb": [0, 0, 255], "munsell": "7.5PB 2.5/20.0", "iscc_nbs_name": "vivid blue"}
+//! ]"##)?;
+//!
+//! let report = AccuracyValidator::new()?.validate(&dataset);
+//! assert_eq!(report.converted, 3);
+//! assert_eq!(report.exact_matches, 1);
+//! assert!(report.delta_e_2000.max < 10.0);
+//!
+//! let naming = report.naming.as_ref().unwrap();
+//! assert_eq!(naming.correct, 2);
+//! assert_eq!(naming.confusions[0].actual.as_deref(), Some("vivid purplish blue"));
+//! println!("{}", report.to_json_string()?);
+//! # Ok(())
+//! # }
+//! ```
+
+mod dataset;
+mod report;
+
+pub use dataset::{ValidationDataset, ValidationSample};
+pub use report::{
+    ErrorDistribution, NameConfusion, NamingSummary, ValidationCase, ValidationFailure,
+    ValidationReport,
+};
+
+use std::collections::HashMap;
+
+use palette::color_difference::Ciede2000;
+use palette::white_point::C;
+use palette::Lab;
+use rayon::prelude::*;
+
+use crate::color_names::ColorClassifier;
+use crate::color_notation_parser::munsell_colour_to_munsell_specification;
+use crate::converter::MunsellConverter;
+use crate::error::Result;
+use crate::lab_color_space::{xyy_to_xyz, xyz_to_lab};
+use crate::munsell_color_science::munsell_specification_to_xyy;
+use crate::types::MunsellColor;
+
+use report::percentage;
+
+/// Options for [`AccuracyValidator`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationOptions {
+    /// Number of worst cases to report
+    pub worst_cases: usize,
+    /// Bypass the embedded reference lookup and measure only the
+    /// algorithmic conversion
+    pub algorithmic: bool,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            worst_cases: 10,
+            algorithmic: false,
+        }
+    }
+}
+
+/// Validates conversion and naming against a [`ValidationDataset`].
+pub struct AccuracyValidator {
+    converter: MunsellConverter,
+    classifier: ColorClassifier,
+    options: ValidationOptions,
+}
+
+/// Outcome of one sample.
+enum SampleOutcome {
+    Converted {
+        case: ValidationCase,
+        exact: bool,
+        close: bool,
+    },
+    Failed(ValidationFailure),
+}
+
+impl AccuracyValidator {
+    /// Create a validator with the default converter, classifier and options.
+    ///
+    /// # Errors
+    /// Returns an error if the converter or classifier cannot be created.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            converter: MunsellConverter::new()?,
+            classifier: ColorClassifier::new()?,
+            options: ValidationOptions::default(),
+        })
+    }
+
+    /// Name colors with a configured classifier.
+    pub fn with_classifier(mut self, classifier: ColorClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// Set the validation options.
+    pub fn with_options(mut self, options: ValidationOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Convert every sample of the dataset and report the errors.
+    ///
+    /// Samples are converted in parallel. Conversion failures are reported
+    /// in [`ValidationReport::failures`] rather than returned.
+    pub fn validate(&self, dataset: &ValidationDataset) -> ValidationReport {
+        let outcomes: Vec<SampleOutcome> = dataset
+            .samples
+            .par_iter()
+            .map(|sample| self.validate_sample(sample))
+            .collect();
+
+        let (mut exact_matches, mut close_matches) = (0, 0);
+        let mut cases = Vec::new();
+        let mut failures = Vec::new();
+        for outcome in outcomes {
+            match outcome {
+                SampleOutcome::Converted { case, exact, close } => {
+                    exact_matches += usize::from(exact);
+                    close_matches += usize::from(close);
+                    cases.push(case);
+                }
+                SampleOutcome::Failed(failure) => failures.push(failure),
+            }
+        }
+
+        let distribution = |errors: Vec<f64>| ErrorDistribution::from_errors(&errors);
+        let hue = distribution(cases.iter().filter_map(|c| c.hue_error).collect());
+        let value = distribution(cases.iter().map(|c| c.value_error).collect());
+        let chroma = distribution(cases.iter().map(|c| c.chroma_error).collect());
+        let delta_e_2000 = distribution(cases.iter().filter_map(|c| c.delta_e_2000).collect());
+        let naming = naming_summary(&cases);
+
+        let worst_key = |case: &ValidationCase| case.delta_e_2000.unwrap_or(f64::NEG_INFINITY);
+        cases.sort_by(|a, b| worst_key(b).total_cmp(&worst_key(a)));
+        cases.truncate(self.options.worst_cases);
+
+        ValidationReport {
+            crate_version: crate::VERSION.to_string(),
+            algorithmic: self.options.algorithmic,
+            total_samples: dataset.len(),
+            converted: dataset.len() - failures.len(),
+            exact_matches,
+            close_matches,
+            hue,
+            value,
+            chroma,
+            delta_e_2000,
+            worst_cases: cases,
+            failures,
+            naming,
+        }
+    }
+
+    /// Internal: convert, compare and name one sample.
+    fn validate_sample(&self, sample: &ValidationSample) -> SampleOutcome {
+        let converted = if self.options.algorithmic {
+            self.converter.algorithmic_srgb_to_munsell(sample.rgb)
+        } else {
+            self.converter.srgb_to_munsell(sample.rgb)
+        };
+        let compared = converted.and_then(|actual| {
+            MunsellColor::from_notation(&sample.munsell).map(|expected| (actual, expected))
+        });
+        let (actual, expected) = match compared {
+            Ok(colors) => colors,
+            Err(error) => {
+                return SampleOutcome::Failed(ValidationFailure {
+                    rgb: sample.rgb,
+                    expected: sample.munsell.clone(),
+                    code: error.code().as_str().to_string(),
+                    message: error.to_string(),
+                })
+            }
+        };
+
+        let exact = same_color(&actual, &expected);
+        let close = !exact && self.converter.is_close_match(&actual.notation, &sample.munsell);
+        let actual_name = sample.iscc_nbs_name.as_ref().and_then(|_| {
+            self.classifier
+                .classify_munsell_color(&actual)
+                .ok()
+                .map(|descriptor| descriptor.standard_descriptor())
+        });
+
+        let case = ValidationCase {
+            rgb: sample.rgb,
+            expected: sample.munsell.clone(),
+            hue_error: hue_error(&actual, &expected),
+            value_error: actual.value - expected.value,
+            chroma_error: actual.chroma.unwrap_or(0.0) - expected.chroma.unwrap_or(0.0),
+            delta_e_2000: notation_to_lab(&actual.notation)
+                .zip(notation_to_lab(&sample.munsell))
+                .map(|(actual, expected)| actual.difference(expected)),
+            actual: actual.notation,
+            expected_name: sample.iscc_nbs_name.clone(),
+            actual_name,
+        };
+        SampleOutcome::Converted { case, exact, close }
+    }
+}
+
+/// Whether two colors have the same hue, value and chroma, however their
+/// notations are formatted.
+fn same_color(actual: &MunsellColor, expected: &MunsellColor) -> bool {
+    const TOLERANCE: f64 = 1e-9;
+    let (Some(actual), Some(expected)) = (actual.to_munsell_spec(), expected.to_munsell_spec()) else {
+        return false;
+    };
+    let hue = (actual.hue_number - expected.hue_number).rem_euclid(40.0);
+    let same_hue = actual.chroma.max(expected.chroma) < TOLERANCE || hue.min(40.0 - hue) < TOLERANCE;
+    same_hue
+        && (actual.value - expected.value).abs() < TOLERANCE
+        && (actual.chroma - expected.chroma).abs() < TOLERANCE
+}
+
+/// Signed hue difference in Munsell hue steps, when both colors are
+/// chromatic.
+fn hue_error(actual: &MunsellColor, expected: &MunsellColor) -> Option<f64> {
+    let actual = actual.to_munsell_spec().filter(|s| s.chroma > 0.0)?;
+    let expected = expected.to_munsell_spec().filter(|s| s.chroma > 0.0)?;
+    // Hue numbers run 0-40 around the circle, 2.5 hue steps apart
+    let difference = (actual.hue_number - expected.hue_number).rem_euclid(40.0);
+    let wrapped = if difference > 20.0 { difference - 40.0 } else { difference };
+    Some(wrapped * 2.5)
+}
+
+/// CIELAB under Illuminant C of a color within the renotation data.
+fn notation_to_lab(notation: &str) -> Option<Lab<C, f64>> {
+    let specification = munsell_colour_to_munsell_specification(notation).ok()?;
+    let xyy = munsell_specification_to_xyy(&specification).ok()?;
+    let [l, a, b] = xyz_to_lab(xyy_to_xyz(xyy), "C");
+    Some(Lab::new(l, a, b))
+}
+
+/// Naming accuracy of the cases with an expected name.
+fn naming_summary(cases: &[ValidationCase]) -> Option<NamingSummary> {
+    let mut evaluated = 0;
+    let mut correct = 0;
+    let mut confusions: HashMap<(String, Option<String>), usize> = HashMap::new();
+    for case in cases {
+        let Some(expected) = &case.expected_name else {
+            continue;
+        };
+        evaluated += 1;
+        match &case.actual_name {
+            Some(actual) if actual.eq_ignore_ascii_case(expected) => correct += 1,
+            actual => {
+                let key = (expected.to_lowercase(), actual.as_ref().map(|a| a.to_lowercase()));
+                *confusions.entry(key).or_default() += 1;
+            }
+        }
+    }
+    if evaluated == 0 {
+        return None;
+    }
+
+    let mut confusions: Vec<NameConfusion> = confusions
+        .into_iter()
+        .map(|((expected, actual), count)| NameConfusion {
+            expected,
+            actual,
+            count,
+        })
+        .collect();
+    confusions.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.expected.cmp(&b.expected))
+            .then_with(|| a.actual.cmp(&b.actual))
+    });
+
+    Some(NamingSummary {
+        evaluated,
+        correct,
+        accuracy_percentage: percentage(correct, evaluated),
+        confusions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_dataset_report() {
+        let dataset = ValidationDataset::reference().unwrap();
+        let sampled = ValidationDataset::new(dataset.samples.into_iter().step_by(25).collect());
+
+        let validator = AccuracyValidator::new().unwrap();
+        let lookup = validator.validate(&sampled);
+        assert_eq!(lookup.exact_matches, sampled.len());
+        assert_eq!(lookup.delta_e_2000.max, 0.0);
+        assert!(lookup.naming.is_none());
+
+        let validator = validator.with_options(ValidationOptions {
+            worst_cases: 5,
+            algorithmic: true,
+        });
+        let report = validator.validate(&sampled);
+        assert!(report.algorithmic);
+        assert_eq!(report.converted + report.failures.len(), sampled.len());
+        assert!(report.exact_match_percentage() > 50.0);
+        assert!(report.close_match_percentage() >= report.exact_match_percentage());
+        assert!(report.delta_e_2000.p50 <= report.delta_e_2000.p95);
+        assert!(report.delta_e_2000.p95 <= report.delta_e_2000.max);
+
+        assert_eq!(report.worst_cases.len(), 5);
+        let worst: Vec<f64> = report.worst_cases.iter().filter_map(|c| c.delta_e_2000).collect();
+        assert!(worst.windows(2).all(|w| w[0] >= w[1]));
+
+        let json = report.to_json_string().unwrap();
+        let restored = ValidationReport::from_json_str(&json).unwrap();
+        assert_eq!(restored.exact_matches, report.exact_matches);
+        assert_eq!(restored.worst_cases[0].rgb, report.worst_cases[0].rgb);
+        assert!((restored.delta_e_2000.rms - report.delta_e_2000.rms).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_naming_confusions() {
+        let csv = "R,G,B,Munsell,iscc_nbs_name\n\
+                   255,0,0,7.9R 5.2/20.5,Vivid Reddish Orange\n\
+                   70,130,180,2.5PB 5.0/8.0,moderate blue\n\
+                   0,0,255,7.5PB 2.5/20,vivid blue\n\
+                   0,0,254,7.5PB 2.5/20,vivid blue\n\
+                   10,10,10,N 0.5,\n\
+                   0,34,17,1.4G 1/3.8,\n";
+        let dataset = ValidationDataset::from_csv_reader(csv.as_bytes()).unwrap();
+        assert_eq!(dataset.samples[4].iscc_nbs_name, None);
+
+        let report = AccuracyValidator::new().unwrap().validate(&dataset);
+        let naming = report.naming.unwrap();
+        assert_eq!(naming.evaluated, 4);
+        assert_eq!(naming.correct, 2);
+        assert_eq!(
+            naming.confusions,
+            vec![NameConfusion {
+                expected: "vivid blue".to_string(),
+                actual: Some("vivid purplish blue".to_string()),
+                count: 2,
+            }]
+        );
+        assert_eq!(report.hue.count, 5);
+        assert_eq!(report.value.count, 6);
+        // "1.4G 1/3.8" matches the converter's "1.4G 1.0/3.8" exactly
+        assert_eq!(report.exact_matches, 2);
+    }
+
+    #[test]
+    fn test_dataset_errors() {
+        let missing = ValidationDataset::from_json_str(r#"[{"munsell": "5R 4/14"}]"#);
+        assert!(missing.is_err());
+        let both = ValidationDataset::from_json_str(
+            r##"[{"rgb": [1, 2, 3], "hex": "#010203", "munsell": "5R 4/14"}]"##,
+        );
+        assert!(both.is_err());
+        let notation = ValidationDataset::from_csv_reader("hex,munsell\n#FF0000,5XY 4/14\n".as_bytes());
+        assert!(notation.unwrap_err().to_string().contains("row 2"));
+        assert!(ValidationDataset::from_file("dataset.txt").is_err());
+    }
+}
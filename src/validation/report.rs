@@ -0,0 +1,210 @@
+//! Validation reports and error statistics.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{MunsellError, Result};
+
+/// Distribution of one error component over a dataset.
+///
+/// `mean` is signed and shows systematic bias; the other statistics are
+/// computed on absolute errors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ErrorDistribution {
+    /// Number of samples with this component
+    pub count: usize,
+    /// Mean signed error
+    pub mean: f64,
+    /// Mean absolute error
+    pub mean_absolute: f64,
+    /// Root mean square error
+    pub rms: f64,
+    /// Median absolute error
+    pub p50: f64,
+    /// 90th percentile of absolute errors
+    pub p90: f64,
+    /// 95th percentile of absolute errors
+    pub p95: f64,
+    /// 99th percentile of absolute errors
+    pub p99: f64,
+    /// Largest absolute error
+    pub max: f64,
+}
+
+impl ErrorDistribution {
+    /// Summarize signed errors. An empty slice gives all zeros.
+    ///
+    /// Percentiles interpolate linearly between the closest ranks.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use munsellspace::validation::ErrorDistribution;
+    ///
+    /// let errors = ErrorDistribution::from_errors(&[-1.0, 0.5, 2.0, -0.5, 1.0]);
+    /// assert_eq!(errors.count, 5);
+    /// assert_eq!(errors.mean, 0.4);
+    /// assert_eq!(errors.p50, 1.0);
+    /// assert_eq!(errors.max, 2.0);
+    /// ```
+    pub fn from_errors(errors: &[f64]) -> Self {
+        if errors.is_empty() {
+            return Self::default();
+        }
+        let count = errors.len();
+        let mut absolute: Vec<f64> = errors.iter().map(|e| e.abs()).collect();
+        absolute.sort_by(f64::total_cmp);
+
+        let percentile = |p: f64| {
+            let rank = p / 100.0 * (count - 1) as f64;
+            let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+            absolute[lower] + (absolute[upper] - absolute[lower]) * (rank - lower as f64)
+        };
+
+        Self {
+            count,
+            mean: errors.iter().sum::<f64>() / count as f64,
+            mean_absolute: absolute.iter().sum::<f64>() / count as f64,
+            rms: (errors.iter().map(|e| e * e).sum::<f64>() / count as f64).sqrt(),
+            p50: percentile(50.0),
+            p90: percentile(90.0),
+            p95: percentile(95.0),
+            p99: percentile(99.0),
+            max: absolute[count - 1],
+        }
+    }
+}
+
+/// Result of one converted sample.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationCase {
+    /// Input sRGB color
+    pub rgb: [u8; 3],
+    /// Expected Munsell notation
+    pub expected: String,
+    /// Converted Munsell notation
+    pub actual: String,
+    /// Hue error in Munsell hue steps (100 per circle), when both colors
+    /// are chromatic
+    pub hue_error: Option<f64>,
+    /// Value error
+    pub value_error: f64,
+    /// Chroma error
+    pub chroma_error: f64,
+    /// CIEDE2000 difference between the expected and converted colors under
+    /// Illuminant C, when both lie within the renotation data
+    pub delta_e_2000: Option<f64>,
+    /// Expected ISCC-NBS name
+    pub expected_name: Option<String>,
+    /// ISCC-NBS name of the converted color, when a name was expected
+    pub actual_name: Option<String>,
+}
+
+/// Sample that could not be converted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationFailure {
+    /// Input sRGB color
+    pub rgb: [u8; 3],
+    /// Expected Munsell notation
+    pub expected: String,
+    /// Machine-readable error code; see [`ErrorCode`](crate::error::ErrorCode)
+    pub code: String,
+    /// Error message
+    pub message: String,
+}
+
+/// How often an expected ISCC-NBS name was given another name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NameConfusion {
+    /// Expected name
+    pub expected: String,
+    /// Name given, or `None` when the color could not be classified
+    pub actual: Option<String>,
+    /// Number of samples
+    pub count: usize,
+}
+
+/// Naming accuracy over the samples with an expected ISCC-NBS name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamingSummary {
+    /// Converted samples with an expected name
+    pub evaluated: usize,
+    /// Samples given their expected name (case-insensitive)
+    pub correct: usize,
+    /// Percentage of correct names
+    pub accuracy_percentage: f64,
+    /// Wrong names, most frequent first
+    pub confusions: Vec<NameConfusion>,
+}
+
+/// Accuracy of the converter and classifier on a validation dataset.
+///
+/// Reports serialize to JSON, so accuracy on a reference set can be tracked
+/// across releases.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// Version of the crate that produced the report
+    pub crate_version: String,
+    /// Whether the embedded reference lookup was bypassed
+    pub algorithmic: bool,
+    /// Samples in the dataset
+    pub total_samples: usize,
+    /// Samples converted without error
+    pub converted: usize,
+    /// Samples converted to exactly the expected notation
+    pub exact_matches: usize,
+    /// Other samples in the expected hue family within 1 value and 2 chroma
+    pub close_matches: usize,
+    /// Hue errors in Munsell hue steps (100 per circle)
+    pub hue: ErrorDistribution,
+    /// Value errors
+    pub value: ErrorDistribution,
+    /// Chroma errors
+    pub chroma: ErrorDistribution,
+    /// CIEDE2000 differences
+    pub delta_e_2000: ErrorDistribution,
+    /// Samples with the largest CIEDE2000 differences, largest first;
+    /// samples without a difference come last
+    pub worst_cases: Vec<ValidationCase>,
+    /// Samples that could not be converted
+    pub failures: Vec<ValidationFailure>,
+    /// Naming accuracy, when the dataset has expected names
+    pub naming: Option<NamingSummary>,
+}
+
+impl ValidationReport {
+    /// Percentage of samples converted to exactly the expected notation.
+    pub fn exact_match_percentage(&self) -> f64 {
+        percentage(self.exact_matches, self.total_samples)
+    }
+
+    /// Percentage of samples converted to an exact or close match.
+    pub fn close_match_percentage(&self) -> f64 {
+        percentage(self.exact_matches + self.close_matches, self.total_samples)
+    }
+
+    /// Serialize the report to pretty-printed JSON.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Load a report from JSON.
+    ///
+    /// # Errors
+    /// Returns [`MunsellError::ReferenceDataError`] for invalid JSON.
+    pub fn from_json_str(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|e| MunsellError::ReferenceDataError {
+            message: format!("Invalid validation report JSON: {}", e),
+        })
+    }
+}
+
+/// Percentage of `part` in `total`, 0 for an empty total.
+pub(super) fn percentage(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64 * 100.0
+    }
+}